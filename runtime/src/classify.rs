@@ -0,0 +1,47 @@
+//! Classification of the test cases given with the `classify` argument.
+
+use std::collections::BTreeMap;
+use std::sync::{Mutex, PoisonError};
+
+/// The number of passed test cases for each label.
+#[derive(Debug, Default)]
+pub struct Classification {
+    labels: Mutex<BTreeMap<String, u32>>,
+}
+
+impl Classification {
+    /// No cases counted yet.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Count a passed case with `label`.
+    pub fn record(&self, label: String) {
+        let mut labels = self.labels.lock().unwrap_or_else(PoisonError::into_inner);
+        *labels.entry(label).or_insert(0) += 1;
+    }
+
+    /// The number of passed cases for each label.
+    pub fn counts(&self) -> BTreeMap<String, u32> {
+        self.labels
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Print the share of the passed test cases for each label, most frequent first.
+    pub fn report(&self, test_name: &str) {
+        let mut labels: Vec<_> = self.counts().into_iter().collect();
+        let total: u32 = labels.iter().map(|(_, count)| count).sum();
+        labels.sort_by(|(_, a), (_, b)| b.cmp(a));
+        eprintln!("{}: classification of {} passed cases", test_name, total);
+        for (label, count) in labels {
+            eprintln!(
+                "{:>7.2}% {} ({})",
+                f64::from(count) * 100.0 / f64::from(total),
+                label,
+                count,
+            );
+        }
+    }
+}
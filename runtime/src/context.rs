@@ -0,0 +1,64 @@
+//! The context of a test case passed to the test with `with_context`.
+
+use std::fmt;
+use std::panic;
+
+/// What the test knows about the case being run, taken by the last argument of a test with
+/// `with_context`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Context {
+    case: u32,
+    seed: Option<String>,
+}
+
+impl Context {
+    /// The context of the case with the given index in a run with the given seed.
+    pub fn new(case: u32, seed: Option<&str>) -> Self {
+        Self {
+            case,
+            seed: seed.map(String::from),
+        }
+    }
+
+    /// The index of the case, counting from 0 the cases run by the test (or by the subprocess
+    /// running them with `fork`), including the rejected cases and the shrinking iterations.
+    pub fn case(&self) -> u32 {
+        self.case
+    }
+
+    /// The seed of the run, written the same way as the `seed` argument of the attribute, or
+    /// `None` if the RNG cannot be seeded, e.g. when the runner is given with `runner`.
+    pub fn seed(&self) -> Option<&str> {
+        self.seed.as_deref()
+    }
+
+    /// Reject the case with `reason`, like `prop_assume!` does. The case does not count towards
+    /// the cases that have to pass, and too many rejections fail the test.
+    ///
+    /// The rejection unwinds the test without calling the panic hook, and the attribute turns it
+    /// into `TestCaseError::Reject`.
+    pub fn reject(&self, reason: impl Into<String>) -> ! {
+        panic::resume_unwind(Box::new(Rejection {
+            reason: reason.into(),
+        }))
+    }
+}
+
+/// The payload of the unwinding started by [`Context::reject`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rejection {
+    reason: String,
+}
+
+impl Rejection {
+    /// The reason the case was rejected for.
+    pub fn into_reason(self) -> String {
+        self.reason
+    }
+}
+
+impl fmt::Display for Rejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "rejected: {}", self.reason)
+    }
+}
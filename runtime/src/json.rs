@@ -0,0 +1,21 @@
+//! Writing JSON without a serialization library.
+
+/// `text` written as a JSON string literal.
+pub(crate) fn escape(text: &str) -> String {
+    let mut escaped = String::from("\"");
+    for c in text.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", u32::from(c))),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+/// The optional `text` written as a JSON string literal or `null`.
+pub(crate) fn escape_or_null(text: Option<&str>) -> String {
+    text.map_or_else(|| String::from("null"), escape)
+}
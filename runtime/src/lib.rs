@@ -0,0 +1,33 @@
+//! Support library for the code generated by the `proptest` attribute of
+//! [`proptest-attr`](https://crates.io/crates/proptest-attr).
+//!
+//! The attribute calls into this crate for the features that would otherwise expand to a lot of
+//! code in every test: the statistics and the reports written with `stats_out` and `json_report`,
+//! the labels counted with `classify`, the watchdog of `total_timeout` and the context of the
+//! cases taken by a test with `with_context`. Add it to the `dev-dependencies` of a crate using
+//! these arguments:
+//!
+//! ```toml
+//! [dev-dependencies]
+//! proptest-attr-runtime = "0.1"
+//! ```
+//!
+//! The hooks given with `before_all`, `before_each` and `after_each` do not go through this crate:
+//! they expand to a single call of the given function, so they work without this dependency, and
+//! `before_each` and `after_each` without `std`.
+//!
+//! The items of this crate are meant to be used by the generated code only, and may change
+//! together with the attribute.
+
+#![forbid(unsafe_code)]
+
+mod classify;
+mod context;
+mod json;
+mod stats;
+mod watchdog;
+
+pub use classify::Classification;
+pub use context::{Context, Rejection};
+pub use stats::{Outcome, Stats};
+pub use watchdog::guard;
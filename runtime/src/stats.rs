@@ -0,0 +1,169 @@
+//! Statistics of the generated values written as JSON with the `stats_out` argument, and the
+//! records of the runs appended with the `json_report` argument.
+
+use crate::classify::Classification;
+use crate::json::{escape, escape_or_null};
+use std::collections::BTreeMap;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::Path;
+use std::sync::{Mutex, PoisonError};
+
+/// How a test case ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Passed,
+    /// Rejected by the test, such as with `prop_assume!`.
+    Rejected,
+    /// Failed with an error or a panic.
+    Failed,
+}
+
+#[derive(Debug, Default)]
+struct Counts {
+    cases: u32,
+    passed: u32,
+    rejected: u32,
+    failed: u32,
+    shrink_iterations: u32,
+    /// The number of cases by the length of the `Debug` representation of their values, rounded
+    /// down to a power of two.
+    sizes: BTreeMap<usize, u32>,
+}
+
+/// The statistics of a run.
+#[derive(Debug, Default)]
+pub struct Stats {
+    counts: Mutex<Counts>,
+}
+
+impl Stats {
+    /// No cases recorded yet.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a test case with values of the given `size` (the length of their `Debug`
+    /// representation). The cases run after the first failure are the shrinking iterations.
+    pub fn record(&self, size: usize, outcome: Outcome) {
+        let mut counts = self.counts.lock().unwrap_or_else(PoisonError::into_inner);
+        counts.cases += 1;
+        if counts.failed > 0 {
+            counts.shrink_iterations += 1;
+        }
+        let bucket = match size {
+            0 => 0,
+            size => 1 << (usize::BITS - 1 - size.leading_zeros()),
+        };
+        *counts.sizes.entry(bucket).or_insert(0) += 1;
+        match outcome {
+            Outcome::Passed => counts.passed += 1,
+            Outcome::Rejected => counts.rejected += 1,
+            Outcome::Failed => counts.failed += 1,
+        }
+    }
+
+    /// Write the statistics of the run of `test` to `path` as JSON, with the counts of the labels
+    /// of `classification` if it is given.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the file cannot be written.
+    pub fn write(
+        &self,
+        path: &Path,
+        test: &str,
+        passed: bool,
+        classification: Option<&Classification>,
+    ) {
+        let counts = self.counts.lock().unwrap_or_else(PoisonError::into_inner);
+        let sizes: Vec<_> = counts
+            .sizes
+            .iter()
+            .map(|(bucket, count)| match bucket {
+                0 | 1 => format!("\"{}\": {}", bucket, count),
+                bucket => format!("\"{}-{}\": {}", bucket, bucket * 2 - 1, count),
+            })
+            .collect();
+        let mut json = format!(
+            "{{\n  \"test\": {},\n  \"result\": \"{}\",\n  \"cases\": {},\n  \"passed\": {},\n  \
+             \"rejected\": {},\n  \"failed\": {},\n  \"shrink_iterations\": {},\n  \
+             \"sizes\": {{{}}}",
+            escape(test),
+            result(passed),
+            counts.cases,
+            counts.passed,
+            counts.rejected,
+            counts.failed,
+            counts.shrink_iterations,
+            sizes.join(", "),
+        );
+        if let Some(classification) = classification {
+            let labels: Vec<_> = classification
+                .counts()
+                .iter()
+                .map(|(label, count)| format!("{}: {}", escape(label), count))
+                .collect();
+            json.push_str(&format!(
+                ",\n  \"classification\": {{{}}}",
+                labels.join(", ")
+            ));
+        }
+        json.push_str("\n}\n");
+        if let Err(err) = create_parent(path).and_then(|()| fs::write(path, json)) {
+            panic!("cannot write the statistics to {}: {}", path.display(), err);
+        }
+    }
+
+    /// Append a record of the run of `test` to `path` as a line of JSON: the `seed` of the run,
+    /// the numbers of cases, rejected cases and shrinking iterations, and the `error` and the
+    /// minimal failing `input` if it fails.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the file cannot be written.
+    pub fn append_report(
+        &self,
+        path: &Path,
+        test: &str,
+        passed: bool,
+        seed: Option<&str>,
+        error: Option<&str>,
+        input: Option<&str>,
+    ) {
+        let counts = self.counts.lock().unwrap_or_else(PoisonError::into_inner);
+        let record = format!(
+            "{{\"test\": {}, \"result\": \"{}\", \"seed\": {}, \"cases\": {}, \"rejects\": {}, \
+             \"shrink_steps\": {}, \"error\": {}, \"failing_input\": {}}}\n",
+            escape(test),
+            result(passed),
+            escape_or_null(seed),
+            counts.cases - counts.shrink_iterations,
+            counts.rejected,
+            counts.shrink_iterations,
+            escape_or_null(error),
+            escape_or_null(input),
+        );
+        let written = create_parent(path)
+            .and_then(|()| OpenOptions::new().create(true).append(true).open(path))
+            .and_then(|mut file| file.write_all(record.as_bytes()));
+        if let Err(err) = written {
+            panic!("cannot write the report to {}: {}", path.display(), err);
+        }
+    }
+}
+
+fn result(passed: bool) -> &'static str {
+    if passed {
+        "passed"
+    } else {
+        "failed"
+    }
+}
+
+fn create_parent(path: &Path) -> io::Result<()> {
+    match path.parent() {
+        Some(dir) => fs::create_dir_all(dir),
+        None => Ok(()),
+    }
+}
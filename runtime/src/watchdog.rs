@@ -0,0 +1,50 @@
+//! Limiting the duration of the whole test with `total_timeout`.
+
+use std::io::Write;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::Duration;
+
+/// How long the run may take to finish once the timeout is exceeded and the remaining cases are
+/// skipped, before it is considered stuck.
+const GRACE_PERIOD: Duration = Duration::from_secs(10);
+
+/// Call `run` while a watchdog thread waits for it to finish. If it takes longer than `timeout`
+/// (written as `text` in the attribute), the watchdog sets `timed_out`, so that the remaining cases
+/// are skipped and the test fails once the run returns. If the run does not return within a grace
+/// period, the watchdog reports the number of the cases executed so far and exits the process,
+/// since neither a stuck case nor shrinking can be interrupted otherwise.
+pub fn guard<R>(
+    test_name: &str,
+    timeout: Duration,
+    text: &str,
+    cases_run: &AtomicU32,
+    timed_out: &AtomicBool,
+    run: impl FnOnce() -> R,
+) -> R {
+    let (stop, stopped) = mpsc::channel::<()>();
+    thread::scope(|scope| {
+        scope.spawn(move || {
+            match stopped.recv_timeout(timeout) {
+                Err(RecvTimeoutError::Timeout) => timed_out.store(true, Ordering::Relaxed),
+                _ => return,
+            }
+            if let Err(RecvTimeoutError::Timeout) = stopped.recv_timeout(GRACE_PERIOD) {
+                let cases_run = cases_run.load(Ordering::Relaxed);
+                // The output captured by the test harness is lost when the process exits
+                let _ = writeln!(
+                    std::io::stderr(),
+                    "{}: exceeded the total timeout of {} and did not stop\ncases executed: {}",
+                    test_name,
+                    text,
+                    cases_run,
+                );
+                std::process::exit(101);
+            }
+        });
+        // The watchdog stops once the sender is dropped, even if the run panics
+        let _stop = stop;
+        run()
+    })
+}
@@ -0,0 +1,79 @@
+//! Generation of AFL++ harnesses feeding the property with the values generated from the input of
+//! the fuzzer.
+
+use crate::args::ProptestArgs;
+use crate::async_runtime::AsyncRuntime;
+use crate::bench::property_call;
+use crate::inputs::Input;
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{Ident, ItemFn};
+
+/// A function, compiled only with the `afl` feature of the crate, running the property with
+/// `afl::fuzz!`. The input of the fuzzer is the source of randomness of `strategy`, so that the
+/// strategies and the filters of the test are used, and a failing case panics, which AFL reports
+/// as a crash. `callee` is the property function if one is generated next to the test.
+pub(crate) fn harness(
+    args: &ProptestArgs,
+    input: &ItemFn,
+    inputs: &[Input],
+    strategy: &TokenStream,
+    name: &Ident,
+    callee: Option<&TokenStream>,
+    unit_output: bool,
+) -> syn::Result<TokenStream> {
+    let krate = args.krate();
+    let (nested_property_fn, values_pat, call) = property_call(args, input, inputs, callee)?;
+    let async_setup = args.async_runtime.as_ref().map(AsyncRuntime::setup);
+    let check = if unit_output {
+        quote! { let () = #call; }
+    } else {
+        let result = crate::output::convert(call, Span::call_site());
+        let conversion_items = crate::output::conversion_items(&krate);
+        quote! {
+            #conversion_items
+            if let ::core::result::Result::Err(
+                #krate::test_runner::TestCaseError::Fail(reason)
+            ) = #result {
+                ::core::panic!(
+                    "Test failed: {}; input: {:?}",
+                    reason,
+                    #krate::strategy::ValueTree::current(&tree),
+                );
+            }
+        }
+    };
+    let rng = crate::pass_through::rng(&krate, quote! { input });
+
+    let attrs = crate::propagated_attrs(&input.attrs);
+    let vis = &input.vis;
+    let setup = &args.setup;
+
+    Ok(quote! {
+        #(#attrs)*
+        #[cfg(feature = "afl")]
+        #vis fn #name() {
+            #nested_property_fn
+
+            #setup
+            let strategy = #strategy;
+            #async_setup
+            // AFL reports the panics of the harness as crashes, nothing observed after a panic is
+            // used by the next input
+            let harness = ::std::panic::AssertUnwindSafe(|input: &[u8]| {
+                let mut runner = #krate::test_runner::TestRunner::new_with_rng(
+                    ::core::default::Default::default(),
+                    #rng,
+                );
+                // Inputs that cannot produce a value are not interesting, like rejected cases
+                let tree = match #krate::strategy::Strategy::new_tree(&strategy, &mut runner) {
+                    ::core::result::Result::Ok(tree) => tree,
+                    ::core::result::Result::Err(_) => return,
+                };
+                let #values_pat = #krate::strategy::ValueTree::current(&tree);
+                #check
+            });
+            ::afl::fuzz!(|input: &[u8]| (harness.0)(input));
+        }
+    })
+}
@@ -0,0 +1,174 @@
+//! Collecting several distinct failing inputs with `continue_on_failure = N`, or counting the
+//! failing cases among all of them with `fail_fast = false`, before failing.
+
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use syn::Path;
+
+/// The variable holding the minimal failing inputs found so far.
+pub(crate) fn setup() -> TokenStream {
+    quote! {
+        let failing_inputs = ::core::cell::RefCell::new(::std::vec::Vec::<::std::string::String>::new());
+    }
+}
+
+/// Pass the test case without running `block` if its `values` are one of the failing inputs found
+/// so far, so that shrinking the next failure ends at a different input.
+pub(crate) fn skip_found(values: &[Ident], block: TokenStream) -> TokenStream {
+    let values = match values {
+        [value] => quote! { &#value },
+        values => quote! { ( #(&#values,)* ) },
+    };
+    quote! {
+        {
+            let input = ::std::format!("{:#?}", #values);
+            if failing_inputs.borrow().contains(&input) {
+                ::core::result::Result::Ok(())
+            } else {
+                #block
+            }
+        }
+    }
+}
+
+/// Run `test` until it passes or `limit` failing inputs are found. The runner keeps counting the
+/// successful cases across the runs, so all of them together run the configured number of cases.
+/// The result has the failures found combined into one error message.
+pub(crate) fn run(krate: &Path, test_name: &Ident, limit: u32, test: TokenStream) -> TokenStream {
+    let test_name = test_name.to_string();
+    quote! {
+        let test = #test;
+        let mut failures = ::std::vec::Vec::new();
+        let result = loop {
+            match runner.run(&strategy, &test) {
+                ::core::result::Result::Err(#krate::test_runner::TestError::Fail(reason, value)) => {
+                    let input = ::std::format!("{:#?}", value);
+                    failures.push(::std::format!(
+                        "{}\nminimal failing input: {}",
+                        reason,
+                        input,
+                    ));
+                    failing_inputs.borrow_mut().push(input);
+                    if failures.len() >= #limit as usize {
+                        break ::core::result::Result::Ok(());
+                    }
+                }
+                result => break result,
+            }
+        };
+        let result = if failures.is_empty() {
+            result.map_err(|error| ::std::string::ToString::to_string(&error))
+        } else {
+            let mut message = ::std::format!(
+                "`{}` failed for {} distinct input{}",
+                #test_name,
+                failures.len(),
+                if failures.len() == 1 { "" } else { "s" },
+            );
+            if failures.len() >= #limit as usize {
+                message.push_str(" (the limit set by `continue_on_failure`)");
+            }
+            if let ::core::result::Result::Err(error) = result {
+                message.push_str(&::std::format!("\nthe last run stopped early: {}", error));
+            }
+            for (i, failure) in failures.iter().enumerate() {
+                message.push_str(&::std::format!("\n\nfailure {}: {}", i + 1, failure));
+            }
+            // Separated from the context of the whole run that follows
+            message.push('\n');
+            ::core::result::Result::Err(message)
+        };
+    }
+}
+
+/// The variable holding the counts of the cases run with `fail_fast = false` and the first failure.
+pub(crate) fn failures_setup() -> TokenStream {
+    quote! {
+        #[derive(Default)]
+        struct ProptestFailures {
+            cases: u32,
+            failed: u32,
+            /// The reason and the input of the first failure.
+            first: ::core::option::Option<(::std::string::String, ::std::string::String)>,
+        }
+        let failures = ::core::cell::RefCell::new(
+            <ProptestFailures as ::core::default::Default>::default(),
+        );
+    }
+}
+
+/// Record the outcome of `block` run with `values`, and pass the case if it fails or panics, so
+/// that the runner goes on with the remaining cases. Rejected cases are passed to the runner as is.
+pub(crate) fn record_failure(krate: &Path, values: &[Ident], block: TokenStream) -> TokenStream {
+    let values = match values {
+        [value] => quote! { &#value },
+        values => quote! { ( #(&#values,)* ) },
+    };
+    quote! {
+        {
+            let input = ::std::format!("{:#?}", #values);
+            let record = |reason: ::std::string::String| {
+                let mut failures = failures.borrow_mut();
+                failures.cases += 1;
+                failures.failed += 1;
+                if failures.first.is_none() {
+                    failures.first = ::core::option::Option::Some((reason, input));
+                }
+                ::core::result::Result::Ok(())
+            };
+            let outcome = ::std::panic::catch_unwind(::std::panic::AssertUnwindSafe(|| #block));
+            match outcome {
+                ::core::result::Result::Ok(::core::result::Result::Err(
+                    #krate::test_runner::TestCaseError::Fail(reason),
+                )) => record(::std::string::ToString::to_string(&reason)),
+                ::core::result::Result::Ok(result) => {
+                    if result.is_ok() {
+                        failures.borrow_mut().cases += 1;
+                    }
+                    result
+                }
+                ::core::result::Result::Err(payload) => record(
+                    payload
+                        .downcast_ref::<&str>()
+                        .map(|message| ::std::string::ToString::to_string(message))
+                        .or_else(|| payload.downcast_ref::<::std::string::String>().cloned())
+                        .unwrap_or_else(|| ::std::string::String::from("<non-string panic payload>")),
+                ),
+            }
+        }
+    }
+}
+
+/// Fail the `result` of a run with `fail_fast = false` if any of its cases failed, with the share
+/// of the failing cases and the first failure. The input of the failure is not shrunk.
+pub(crate) fn failures_result(test_name: &Ident) -> TokenStream {
+    let test_name = test_name.to_string();
+    quote! {
+        let result = {
+            let failures = failures.borrow();
+            match &failures.first {
+                ::core::option::Option::Some((reason, input)) => {
+                    let mut message = ::std::format!(
+                        "`{}` failed for {} of {} cases ({:.1}%)",
+                        #test_name,
+                        failures.failed,
+                        failures.cases,
+                        f64::from(failures.failed) * 100.0 / f64::from(failures.cases),
+                    );
+                    if let ::core::result::Result::Err(error) = result {
+                        message.push_str(&::std::format!("\nthe run stopped early: {}", error));
+                    }
+                    message.push_str(&::std::format!(
+                        "\nfirst failure: {}\nfailing input (not shrunk): {}\n",
+                        reason,
+                        input,
+                    ));
+                    ::core::result::Result::Err(message)
+                }
+                ::core::option::Option::None => {
+                    result.map_err(|error| ::std::string::ToString::to_string(&error))
+                }
+            }
+        };
+    }
+}
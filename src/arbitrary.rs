@@ -0,0 +1,154 @@
+//! The `#[proptest_arbitrary]` attribute implementing `Arbitrary` for structs and enums from
+//! per-field strategies.
+
+use crate::args::{Arg, ProptestArgs};
+use crate::inputs::Input;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{
+    Attribute, Error, Fields, FnArg, Generics, Ident, Item, Pat, PatIdent, PatType, Token, Variant,
+};
+
+/// Arguments of `#[proptest]` that also make sense for `Arbitrary` implementations.
+const SUPPORTED_ARGS: &[&str] = &["crate", "filter"];
+
+pub(crate) fn expand(args: Vec<Arg>, mut input: Item) -> syn::Result<TokenStream> {
+    if let Some(arg) = args
+        .iter()
+        .find(|arg| !SUPPORTED_ARGS.iter().any(|name| arg.name == name))
+    {
+        return Err(Error::new_spanned(
+            arg,
+            "unknown argument for `proptest_arbitrary`",
+        ));
+    }
+    let args = ProptestArgs::parse(args)?;
+    let krate = args.krate();
+
+    let (name, strategy) = match &mut input {
+        Item::Struct(item) => {
+            check_generics(&item.generics)?;
+            let name = item.ident.clone();
+            let strategy = fields_strategy(&args, quote! { #name }, &mut item.fields)?;
+            (name, strategy)
+        }
+        Item::Enum(item) => {
+            check_generics(&item.generics)?;
+            if let Some(filter) = &args.filter {
+                return Err(Error::new(
+                    filter.span,
+                    "`filter` cannot be specified for an enum, filter its fields instead",
+                ));
+            }
+            if item.variants.is_empty() {
+                return Err(Error::new_spanned(
+                    &item.ident,
+                    "`proptest_arbitrary` requires at least one variant",
+                ));
+            }
+            let name = item.ident.clone();
+            let strategies = item
+                .variants
+                .iter_mut()
+                .map(|Variant { ident, fields, .. }| {
+                    let strategy = fields_strategy(&args, quote! { #name::#ident }, fields)?;
+                    Ok(quote! { #krate::strategy::Strategy::boxed(#strategy) })
+                })
+                .collect::<syn::Result<Vec<_>>>()?;
+            let strategy = quote! {
+                #krate::strategy::Union::new([#(#strategies),*])
+            };
+            (name, strategy)
+        }
+        item => {
+            return Err(Error::new_spanned(
+                item,
+                "`proptest_arbitrary` can only be applied to structs and enums",
+            ))
+        }
+    };
+    Ok(quote! {
+        #input
+
+        impl #krate::arbitrary::Arbitrary for #name {
+            type Parameters = ();
+            type Strategy = #krate::strategy::BoxedStrategy<Self>;
+
+            fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+                #krate::strategy::Strategy::boxed(#strategy)
+            }
+        }
+    })
+}
+
+fn check_generics(generics: &Generics) -> syn::Result<()> {
+    if generics.params.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::new_spanned(
+            generics,
+            "generic types are not supported by `proptest_arbitrary`",
+        ))
+    }
+}
+
+/// A strategy constructing `path` (a struct or an enum variant) from generated `fields`. The
+/// `#[strategy]`, `#[range]`, `#[len]`, `#[map]`, `#[filter]`, `#[no_shrink]` and
+/// `#[shrink_with]` attributes are removed from the fields.
+fn fields_strategy(
+    args: &ProptestArgs,
+    path: TokenStream,
+    fields: &mut Fields,
+) -> syn::Result<TokenStream> {
+    let krate = args.krate();
+    let mut inputs = Vec::with_capacity(fields.len());
+    let mut bindings = Vec::with_capacity(fields.len());
+    for (i, field) in fields.iter_mut().enumerate() {
+        let binding = match &field.ident {
+            Some(ident) => ident.clone(),
+            None => format_ident!("field{}", i),
+        };
+        let (attrs, other_attrs) = field
+            .attrs
+            .drain(..)
+            .partition::<Vec<Attribute>, _>(crate::inputs::is_arg_attr);
+        field.attrs = other_attrs;
+        inputs.push(Input::from_fn_arg(
+            FnArg::Typed(PatType {
+                attrs,
+                pat: Box::new(Pat::Ident(PatIdent {
+                    attrs: Vec::new(),
+                    by_ref: None,
+                    mutability: None,
+                    ident: binding.clone(),
+                    subpat: None,
+                })),
+                colon_token: <Token![:]>::default(),
+                ty: Box::new(field.ty.clone()),
+            }),
+            &krate,
+        )?);
+        bindings.push(binding);
+    }
+
+    let strategy = crate::strategy::strategy(args, &inputs)?;
+    let tys = inputs.iter().map(|input| &input.ty);
+    let values = match bindings.len() {
+        0 => quote! { (): () },
+        1 => quote! { #(#bindings)*: #(#tys)* },
+        _ => quote! { ( #(#bindings),* ): ( #(#tys),* ) },
+    };
+    let value = construct(path, fields, &bindings);
+
+    let krate = args.krate();
+    Ok(quote! { #krate::strategy::Strategy::prop_map(#strategy, |#values| #value) })
+}
+
+/// The expression constructing `path` from the bindings of its fields.
+fn construct(path: TokenStream, fields: &Fields, bindings: &[Ident]) -> TokenStream {
+    match fields {
+        Fields::Named(_) => quote! { #path { #(#bindings),* } },
+        Fields::Unnamed(_) => quote! { #path ( #(#bindings),* ) },
+        Fields::Unit => path,
+    }
+}
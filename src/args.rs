@@ -0,0 +1,1932 @@
+//! Parsing of the `#[proptest(...)]` attribute arguments.
+
+use crate::async_runtime::{AsyncRuntime, Flavor};
+use proc_macro2::{Span, TokenStream, TokenTree};
+use quote::{format_ident, quote, quote_spanned, ToTokens};
+use std::collections::HashMap;
+use syn::{
+    ext::IdentExt,
+    parse::{Parse, ParseStream},
+    parse_quote, parse_quote_spanned,
+    punctuated::Punctuated,
+    spanned::Spanned,
+    Block, Error, Expr, ExprLit, ExprParen, ExprPath, Ident, Lit, LitInt, LitStr, Meta, NestedMeta,
+    Path, ReturnType, Stmt, Token, Type,
+};
+
+/// A single `name` or `name = value` attribute argument.
+#[derive(Clone)]
+pub(crate) struct Arg {
+    pub(crate) name: Ident,
+    pub(crate) value: Option<(Token![=], Expr)>,
+}
+
+impl Parse for Arg {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        // Allow keywords like `async` as argument names
+        let name = Ident::parse_any(input)?;
+        let value = if input.peek(Token![=]) {
+            let eq = input.parse()?;
+            // Some arguments take types, which are not always valid expressions
+            let fork = input.fork();
+            let value = if fork.parse::<Expr>().is_ok() && (fork.is_empty() || fork.peek(Token![,]))
+            {
+                input.parse()?
+            } else {
+                Expr::Verbatim(input.parse::<Type>()?.into_token_stream())
+            };
+            Some((eq, value))
+        } else {
+            None
+        };
+        Ok(Arg { name, value })
+    }
+}
+
+impl ToTokens for Arg {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.name.to_tokens(tokens);
+        if let Some((eq, value)) = &self.value {
+            eq.to_tokens(tokens);
+            value.to_tokens(tokens);
+        }
+    }
+}
+
+/// Parse comma-separated attribute arguments.
+pub(crate) fn parse_args(input: ParseStream) -> syn::Result<Vec<Arg>> {
+    let args = Punctuated::<Arg, Token![,]>::parse_terminated(input)?;
+    Ok(args.into_iter().collect())
+}
+
+/// An expression given as an attribute argument together with its span.
+pub(crate) struct SpannedExpr {
+    pub(crate) expr: Expr,
+    pub(crate) span: Span,
+}
+
+impl SpannedExpr {
+    /// Parse an expression from a string literal. `name` is used in error messages. A strategy
+    /// written as `"@name"` refers to a strategy defined with `proptest_strategies!`.
+    pub(crate) fn from_lit_str(name: &str, lit: &LitStr) -> syn::Result<Self> {
+        let is_strategy = name == "strategy" || name == "strategies" || name == "oneof";
+        if let (true, Some(path)) = (is_strategy, lit.value().trim().strip_prefix('@')) {
+            return match LitStr::new(path, lit.span()).parse::<Path>() {
+                Ok(path) => Ok(SpannedExpr {
+                    expr: parse_quote_spanned!(lit.span()=> #path()),
+                    span: lit.span(),
+                }),
+                Err(_) => Err(Error::new_spanned(
+                    lit,
+                    "`@` must be followed by the name of a strategy defined with \
+                     `proptest_strategies!`",
+                )),
+            };
+        }
+        match lit.parse::<Expr>() {
+            Ok(expr) => Ok(SpannedExpr {
+                expr,
+                span: lit.span(),
+            }),
+            Err(err) => Err(Error::new_spanned(
+                lit,
+                format!("{} is not a valid Rust expression: {}", name, err),
+            )),
+        }
+    }
+
+    /// Take an expression written either as is or enclosed in quotes. A string literal in
+    /// parentheses is taken as is (e.g. to use a regex as a strategy).
+    pub(crate) fn from_expr(name: &str, expr: &Expr) -> syn::Result<Self> {
+        match expr {
+            Expr::Lit(ExprLit {
+                lit: Lit::Str(lit), ..
+            }) => SpannedExpr::from_lit_str(name, lit),
+            Expr::Paren(ExprParen { expr, .. }) if matches!(&**expr, Expr::Lit(_)) => {
+                Ok(SpannedExpr {
+                    expr: (**expr).clone(),
+                    span: expr.span(),
+                })
+            }
+            expr => Ok(SpannedExpr {
+                expr: expr.clone(),
+                span: expr.span(),
+            }),
+        }
+    }
+}
+
+impl ToTokens for SpannedExpr {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let expr = &self.expr;
+        tokens.extend(quote_spanned!(self.span=> #expr));
+    }
+}
+
+/// A fixed seed for the test runner RNG.
+pub(crate) struct Seed {
+    chacha: bool,
+    bytes: Vec<u8>,
+}
+
+impl Seed {
+    /// Parse a seed written as hexadecimal digits optionally prefixed with the RNG algorithm (e.g.
+    /// `"cc 0123..."`), or a XorShift seed written as four decimal dwords the same way `proptest`
+    /// writes it to the regression files (e.g. `"xs 1 2 3 4"`).
+    pub(crate) fn from_expr(value: &Expr) -> syn::Result<Self> {
+        let error = || {
+            Error::new_spanned(
+                value,
+                "invalid seed: expected 32 (XorShift) or 64 (ChaCha) hexadecimal digits, \
+                 optionally prefixed with `xs` or `cc`, or `xs` followed by four decimal dwords",
+            )
+        };
+
+        let value = match value {
+            Expr::Lit(ExprLit {
+                lit: Lit::Str(lit), ..
+            }) => lit.value(),
+            _ => return Err(error()),
+        };
+
+        if let ["xs", dwords @ ..] = value.split_whitespace().collect::<Vec<_>>().as_slice() {
+            if dwords.len() == 4 {
+                let mut bytes = Vec::with_capacity(16);
+                for dword in dwords {
+                    let dword = dword.parse::<u32>().map_err(|_| error())?;
+                    bytes.extend_from_slice(&dword.to_le_bytes());
+                }
+                return Ok(Seed {
+                    chacha: false,
+                    bytes,
+                });
+            }
+        }
+
+        let (algorithm, digits) = match value.trim().split_once(' ') {
+            Some((algorithm, digits)) => (Some(algorithm), digits.trim()),
+            None => (None, value.trim()),
+        };
+
+        if digits.len() % 2 != 0 || !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(error());
+        }
+        let bytes = (0..digits.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&digits[i..i + 2], 16).unwrap())
+            .collect::<Vec<_>>();
+
+        let chacha = match (algorithm, bytes.len()) {
+            (None, 16) | (Some("xs"), 16) => false,
+            (None, 32) | (Some("cc"), 32) => true,
+            _ => return Err(error()),
+        };
+
+        Ok(Seed { chacha, bytes })
+    }
+}
+
+impl Seed {
+    /// The seeded `TestRng`.
+    pub(crate) fn rng(&self, krate: &Path) -> TokenStream {
+        let algorithm = if self.chacha {
+            quote! { #krate::test_runner::RngAlgorithm::ChaCha }
+        } else {
+            quote! { #krate::test_runner::RngAlgorithm::XorShift }
+        };
+        let bytes = &self.bytes;
+        quote! {
+            #krate::test_runner::TestRng::from_seed(#algorithm, &[#(#bytes),*])
+        }
+    }
+    /// The seed written the same way as it is parsed, e.g. `"cc 0123..."`.
+    pub(crate) fn text(&self) -> String {
+        let algorithm = if self.chacha { "cc" } else { "xs" };
+        let digits: String = self
+            .bytes
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect();
+        format!("{} {}", algorithm, digits)
+    }
+}
+
+/// The algorithm of the test runner RNG, given with the `rng` argument.
+pub(crate) struct RngAlgorithm(Ident);
+
+impl RngAlgorithm {
+    const ALGORITHMS: &'static [&'static str] = &["XorShift", "ChaCha", "PassThrough", "Recorder"];
+
+    pub(crate) fn from_expr(value: &Expr) -> syn::Result<Self> {
+        match value {
+            Expr::Lit(ExprLit {
+                lit: Lit::Str(lit), ..
+            }) if Self::ALGORITHMS.contains(&lit.value().as_str()) => {
+                Ok(RngAlgorithm(Ident::new(&lit.value(), lit.span())))
+            }
+            _ => Err(Error::new_spanned(
+                value,
+                "invalid rng: expected \"XorShift\", \"ChaCha\", \"PassThrough\" or \"Recorder\"",
+            )),
+        }
+    }
+
+    /// Whether the RNG returns the bytes given with `rng_bytes` instead of random ones.
+    pub(crate) fn is_pass_through(&self) -> bool {
+        self.0 == "PassThrough"
+    }
+
+    pub(crate) fn path(&self, krate: &Path) -> TokenStream {
+        let algorithm = &self.0;
+        quote! { #krate::test_runner::RngAlgorithm::#algorithm }
+    }
+}
+
+/// The platform other than the host the test is built for.
+pub(crate) enum Target {
+    /// `wasm32`, running the tests with `wasm-bindgen-test`.
+    Wasm,
+}
+
+impl Target {
+    pub(crate) fn from_expr(value: &Expr) -> syn::Result<Self> {
+        match value {
+            Expr::Lit(ExprLit {
+                lit: Lit::Str(lit), ..
+            }) if lit.value() == "wasm" => Ok(Target::Wasm),
+            _ => Err(Error::new_spanned(
+                value,
+                "invalid target: expected \"wasm\"",
+            )),
+        }
+    }
+}
+
+/// The engine running the test cases, given with the `engine` argument.
+pub(crate) enum Engine {
+    /// A `bolero` harness, running under any of its engines (e.g. libFuzzer or Kani).
+    Bolero,
+}
+
+impl Engine {
+    pub(crate) fn from_expr(value: &Expr) -> syn::Result<Self> {
+        match value {
+            Expr::Lit(ExprLit {
+                lit: Lit::Str(lit), ..
+            }) if lit.value() == "bolero" => Ok(Engine::Bolero),
+            _ => Err(Error::new_spanned(
+                value,
+                "invalid engine: expected \"bolero\"",
+            )),
+        }
+    }
+}
+
+/// The condition of the `ignore_if` argument.
+pub(crate) enum IgnoreIf {
+    /// A `cfg(...)` predicate, ignoring the test at compile time.
+    Cfg(NestedMeta),
+    /// Any other boolean expression, skipping the test when it starts.
+    Expr(SpannedExpr),
+}
+
+impl IgnoreIf {
+    pub(crate) fn from_expr(value: &Expr) -> syn::Result<Self> {
+        let expr = SpannedExpr::from_expr("ignore_if", value)?;
+        let is_cfg = matches!(
+            &expr.expr,
+            Expr::Call(call) if matches!(&*call.func, Expr::Path(path) if path.path.is_ident("cfg"))
+        );
+        if !is_cfg {
+            return Ok(IgnoreIf::Expr(expr));
+        }
+        match syn::parse2::<Meta>(expr.expr.to_token_stream()) {
+            Ok(Meta::List(mut list)) if list.nested.len() == 1 => {
+                Ok(IgnoreIf::Cfg(list.nested.pop().unwrap().into_value()))
+            }
+            _ => Err(Error::new_spanned(
+                value,
+                "invalid ignore_if: `cfg` takes a single predicate",
+            )),
+        }
+    }
+}
+
+/// The limit of the duration of the whole test given with `total_timeout`, such as `"60s"`.
+pub(crate) struct TotalTimeout {
+    pub(crate) millis: u64,
+    /// The limit as written in the attribute, to be shown when it is exceeded.
+    pub(crate) text: String,
+}
+
+impl TotalTimeout {
+    pub(crate) fn from_expr(value: &Expr) -> syn::Result<Self> {
+        let invalid = || {
+            Error::new_spanned(
+                value,
+                "invalid total_timeout: expected a duration such as \"60s\", \"500ms\" or \"5m\"",
+            )
+        };
+        let text = match value {
+            Expr::Lit(ExprLit {
+                lit: Lit::Str(lit), ..
+            }) => lit.value(),
+            _ => return Err(invalid()),
+        };
+        let unit_start = text
+            .find(|c: char| !c.is_ascii_digit())
+            .ok_or_else(invalid)?;
+        let (number, unit) = text.split_at(unit_start);
+        let multiplier = match unit {
+            "ms" => 1,
+            "s" => 1_000,
+            "m" => 60_000,
+            "h" => 3_600_000,
+            _ => return Err(invalid()),
+        };
+        let millis = number
+            .parse::<u64>()
+            .ok()
+            .and_then(|number| number.checked_mul(multiplier))
+            .filter(|millis| *millis > 0)
+            .ok_or_else(invalid)?;
+        Ok(TotalTimeout { millis, text })
+    }
+}
+
+/// The size of the collections and strings of the inferred strategies given with `size`, such as
+/// `"0..256"`.
+pub(crate) struct SizeHint {
+    pub(crate) min: usize,
+    /// The inclusive upper bound.
+    pub(crate) max: usize,
+}
+
+impl SizeHint {
+    pub(crate) fn from_expr(value: &Expr) -> syn::Result<Self> {
+        let invalid = || {
+            Error::new_spanned(
+                value,
+                "invalid size: expected a range of integers such as \"0..256\" or \"1..=8\", or \
+                 a single integer",
+            )
+        };
+        let text = match value {
+            Expr::Lit(ExprLit {
+                lit: Lit::Str(lit), ..
+            }) => lit.value(),
+            _ => return Err(invalid()),
+        };
+        let parse = |number: &str| number.trim().parse::<usize>().map_err(|_| invalid());
+        let (min, end) = if let Some((min, max)) = text.split_once("..=") {
+            (parse(min)?, parse(max)?.checked_add(1).ok_or_else(invalid)?)
+        } else if let Some((min, end)) = text.split_once("..") {
+            (parse(min)?, parse(end)?)
+        } else {
+            let size = parse(&text)?;
+            (size, size.checked_add(1).ok_or_else(invalid)?)
+        };
+        if min >= end {
+            return Err(Error::new_spanned(value, "the size range is empty"));
+        }
+        Ok(SizeHint { min, max: end - 1 })
+    }
+}
+
+/// The tests a test is serialized with by `serial_test`, given with the `serial` argument.
+pub(crate) enum Serial {
+    Off,
+    /// All tests with `#[serial]` and no group.
+    All,
+    /// The tests of the given group only.
+    Group(Ident),
+}
+
+impl Serial {
+    pub(crate) fn from_expr(value: &Expr) -> syn::Result<Self> {
+        match value {
+            Expr::Lit(ExprLit {
+                lit: Lit::Bool(lit),
+                ..
+            }) => Ok(if lit.value { Serial::All } else { Serial::Off }),
+            Expr::Lit(ExprLit {
+                lit: Lit::Str(lit), ..
+            }) => match lit.parse() {
+                Ok(group) => Ok(Serial::Group(group)),
+                Err(_) => Err(Error::new_spanned(
+                    value,
+                    "invalid serial group: expected an identifier",
+                )),
+            },
+            _ => Err(Error::new_spanned(
+                value,
+                "invalid serial: expected a boolean or a group name",
+            )),
+        }
+    }
+
+    /// The attribute of `serial_test` placed on the test. The lock is local to the process: with
+    /// `fork` it is held by the parent for the whole run, while the subprocesses running the cases
+    /// take their own uncontended lock instead of waiting for the parent.
+    pub(crate) fn attr(&self) -> TokenStream {
+        match self {
+            Serial::Off => quote! {},
+            Serial::All => quote! { #[::serial_test::serial] },
+            Serial::Group(group) => quote! { #[::serial_test::serial(#group)] },
+        }
+    }
+}
+
+/// How failing test cases are persisted.
+pub(crate) enum Persistence {
+    Off,
+    /// Regression files in the given directory next to the source directory.
+    SourceParallel(LitStr),
+}
+
+impl Persistence {
+    pub(crate) fn from_expr(value: &Expr) -> syn::Result<Self> {
+        match value {
+            Expr::Lit(ExprLit {
+                lit: Lit::Str(lit), ..
+            }) => Ok(match lit.value().as_str() {
+                "off" => Persistence::Off,
+                "file" => {
+                    Persistence::SourceParallel(LitStr::new("proptest-regressions", lit.span()))
+                }
+                _ => Persistence::SourceParallel(lit.clone()),
+            }),
+            _ => Err(Error::new_spanned(
+                value,
+                "invalid persistence: expected \"file\", \"off\" or a directory name",
+            )),
+        }
+    }
+
+    /// The value of the `failure_persistence` config field.
+    pub(crate) fn failure_persistence(&self, krate: &Path) -> TokenStream {
+        let persistence = match self {
+            Persistence::Off => quote! { Off },
+            Persistence::SourceParallel(dir) => quote! { SourceParallel(#dir) },
+        };
+        quote! {
+            ::core::option::Option::Some(#krate::std_facade::Box::new(
+                #krate::test_runner::FileFailurePersistence::#persistence
+            ))
+        }
+    }
+}
+
+/// A failure persisted in a regression file to be replayed instead of running random cases.
+pub(crate) struct Replay(LitStr);
+
+impl Replay {
+    /// Parse a line of a regression file, e.g. `"cc 0123... # shrinks to ..."`.
+    pub(crate) fn from_expr(value: &Expr) -> syn::Result<Self> {
+        let error = || {
+            Error::new_spanned(
+                value,
+                "invalid replay: expected a seed from a regression file, e.g. \"cc 0123...\"",
+            )
+        };
+
+        let lit = match value {
+            Expr::Lit(ExprLit {
+                lit: Lit::Str(lit), ..
+            }) => lit,
+            _ => return Err(error()),
+        };
+        let line = lit.value();
+        // The value the seed shrinks to is written after the seed as a comment
+        let seed = line.split('#').next().unwrap_or_default().trim();
+        let parts: Vec<_> = seed.split_whitespace().collect();
+        let hex = |digits: &str| {
+            digits.len().is_multiple_of(2) && digits.chars().all(|c| c.is_ascii_hexdigit())
+        };
+        // The same forms as `proptest` accepts in the regression files
+        let valid = match parts.as_slice() {
+            ["cc", digits] | ["rr", digits] => digits.len() == 64 && hex(digits),
+            ["xs", dwords @ ..] => {
+                dwords.len() == 4 && dwords.iter().all(|dword| dword.parse::<u32>().is_ok())
+            }
+            ["pa"] => true,
+            ["pa", digits] => hex(digits),
+            _ => false,
+        };
+        if !valid {
+            return Err(error());
+        }
+
+        // `proptest` separates the parts with a single space
+        Ok(Replay(LitStr::new(&parts.join(" "), lit.span())))
+    }
+
+    /// The value of the `failure_persistence` config field, which only contains the replayed
+    /// seed.
+    pub(crate) fn failure_persistence(&self, krate: &Path) -> TokenStream {
+        let seed = &self.0;
+        quote! {
+            ::core::option::Option::Some(#krate::std_facade::Box::new({
+                let mut persistence = #krate::test_runner::MapFailurePersistence::default();
+                #krate::test_runner::FailurePersistence::save_persisted_failure2(
+                    &mut persistence,
+                    ::core::option::Option::Some(::core::file!()),
+                    ::core::result::Result::expect(
+                        ::core::str::FromStr::from_str(#seed),
+                        "invalid replay seed",
+                    ),
+                    &(),
+                );
+                persistence
+            }))
+        }
+    }
+}
+
+/// Where the generated inputs of each test case are logged.
+pub(crate) enum LogInputs {
+    /// `eprintln!`
+    Stderr,
+    /// `tracing::debug!`
+    Tracing,
+}
+
+impl LogInputs {
+    pub(crate) fn from_expr(value: &Expr) -> syn::Result<Self> {
+        match value {
+            Expr::Lit(ExprLit {
+                lit: Lit::Str(lit), ..
+            }) if lit.value() == "stderr" => Ok(LogInputs::Stderr),
+            Expr::Lit(ExprLit {
+                lit: Lit::Str(lit), ..
+            }) if lit.value() == "tracing" => Ok(LogInputs::Tracing),
+            _ => Err(Error::new_spanned(
+                value,
+                "invalid log_inputs: expected \"stderr\" or \"tracing\"",
+            )),
+        }
+    }
+
+    /// Log the values of the `values` variables under the given names.
+    pub(crate) fn log(&self, test_name: &Ident, names: &[String], values: &[Ident]) -> TokenStream {
+        let format = format!("{{}}: {}", vec!["{} = {:?}"; names.len()].join(", "));
+        let args = names
+            .iter()
+            .zip(values)
+            .map(|(name, value)| quote! { #name, &#value });
+        let test_name = test_name.to_string();
+        match self {
+            LogInputs::Stderr => quote! { ::std::eprintln!(#format, #test_name #(, #args)*); },
+            LogInputs::Tracing => quote! { ::tracing::debug!(#format, #test_name #(, #args)*); },
+        }
+    }
+}
+
+/// Statements run before the strategy is constructed, given with `setup`, such as
+/// `"let max = config::MAX;"`.
+pub(crate) struct Setup(Vec<Stmt>);
+
+impl Setup {
+    pub(crate) fn from_expr(value: &Expr) -> syn::Result<Self> {
+        match value {
+            Expr::Lit(ExprLit {
+                lit: Lit::Str(lit), ..
+            }) => match lit.parse_with(Block::parse_within) {
+                Ok(stmts) => Ok(Setup(stmts)),
+                Err(err) => Err(Error::new_spanned(
+                    lit,
+                    format!("setup is not a valid sequence of Rust statements: {}", err),
+                )),
+            },
+            Expr::Block(block) if block.attrs.is_empty() && block.label.is_none() => {
+                Ok(Setup(block.block.stmts.clone()))
+            }
+            _ => Err(Error::new_spanned(
+                value,
+                "invalid setup: expected statements in quotes or in braces",
+            )),
+        }
+    }
+}
+
+impl ToTokens for Setup {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        for stmt in &self.0 {
+            stmt.to_tokens(tokens);
+        }
+    }
+}
+
+/// How a failed test reports the failure, given with `report`.
+#[derive(Clone, Copy)]
+pub(crate) enum Report {
+    /// A panic with the error and the context of the run.
+    Panic,
+    /// The same message returned as a `googletest` assertion failure from the test, which returns
+    /// `googletest::Result<()>`.
+    Googletest,
+}
+
+impl Report {
+    pub(crate) fn from_expr(value: &Expr) -> syn::Result<Self> {
+        match value {
+            Expr::Lit(ExprLit {
+                lit: Lit::Str(lit), ..
+            }) if lit.value() == "panic" => Ok(Report::Panic),
+            Expr::Lit(ExprLit {
+                lit: Lit::Str(lit), ..
+            }) if lit.value() == "googletest" => Ok(Report::Googletest),
+            _ => Err(Error::new_spanned(
+                value,
+                "invalid report: expected \"panic\" or \"googletest\"",
+            )),
+        }
+    }
+
+    /// Fail the test with `message`, an expression implementing `Display`.
+    pub(crate) fn fail(self, message: TokenStream) -> TokenStream {
+        match self {
+            Report::Panic => quote! { ::core::panic!("{}", #message); },
+            Report::Googletest => quote! { return ::googletest::fail!("{}", #message); },
+        }
+    }
+
+    /// The attribute running the test function. It is left out when the test is built by
+    /// `trybuild`, so that the test function can be called there.
+    pub(crate) fn test_attr(self) -> TokenStream {
+        match self {
+            Report::Panic => quote! { #[cfg_attr(not(trybuild), test)] },
+            Report::Googletest => quote! { #[cfg_attr(not(trybuild), ::googletest::gtest)] },
+        }
+    }
+
+    /// The return type of the test function.
+    pub(crate) fn output(self) -> ReturnType {
+        match self {
+            Report::Panic => ReturnType::Default,
+            Report::Googletest => parse_quote! { -> ::googletest::Result<()> },
+        }
+    }
+
+    /// The value the test function returns when the test passes or is skipped.
+    pub(crate) fn pass(self) -> TokenStream {
+        match self {
+            Report::Panic => quote! {},
+            Report::Googletest => quote! { ::core::result::Result::Ok(()) },
+        }
+    }
+}
+
+/// One of the strategies given with the `strategies` argument, optionally named with
+/// `name = strategy`.
+pub(crate) struct MatrixStrategy {
+    pub(crate) name: Option<Ident>,
+    pub(crate) strategy: SpannedExpr,
+}
+
+impl MatrixStrategy {
+    /// Parse a list of strategies, each written either as is or enclosed in quotes.
+    fn parse_list(value: &Expr) -> syn::Result<Vec<Self>> {
+        let elems = match value {
+            Expr::Array(array) => &array.elems,
+            _ => {
+                return Err(Error::new_spanned(
+                    value,
+                    "invalid strategies: expected a list of strategies, e.g. `[0..10, 100..]`",
+                ))
+            }
+        };
+        if elems.is_empty() {
+            return Err(Error::new_spanned(
+                value,
+                "invalid strategies: at least one strategy is required",
+            ));
+        }
+        elems
+            .iter()
+            .map(|elem| {
+                let strategy = SpannedExpr::from_expr("strategies", elem)?;
+                match &strategy.expr {
+                    Expr::Assign(assign) => match &*assign.left {
+                        Expr::Path(ExprPath {
+                            qself: None, path, ..
+                        }) if path.get_ident().is_some() => Ok(MatrixStrategy {
+                            name: path.get_ident().cloned(),
+                            strategy: SpannedExpr::from_expr("strategies", &assign.right)?,
+                        }),
+                        left => Err(Error::new_spanned(
+                            left,
+                            "invalid strategies: the name of a strategy must be an identifier",
+                        )),
+                    },
+                    _ => Ok(MatrixStrategy {
+                        name: None,
+                        strategy,
+                    }),
+                }
+            })
+            .collect()
+    }
+}
+
+/// One of the strategies of `oneof`, written as `strategy`, optionally in quotes with a weight as
+/// `"weight => strategy"`.
+struct WeightedStrategy {
+    weight: Option<LitInt>,
+    strategy: SpannedExpr,
+}
+
+impl WeightedStrategy {
+    /// Parse a list of strategies, each written either as is or enclosed in quotes with an
+    /// optional weight.
+    fn parse_list(value: &Expr) -> syn::Result<Vec<Self>> {
+        let elems = match value {
+            Expr::Array(array) => &array.elems,
+            _ => {
+                return Err(Error::new_spanned(
+                    value,
+                    "invalid oneof: expected a list of strategies, e.g. `[\"3 => 0..10\", \"1 => 100..\"]`",
+                ))
+            }
+        };
+        if elems.is_empty() {
+            return Err(Error::new_spanned(
+                value,
+                "invalid oneof: at least one strategy is required",
+            ));
+        }
+        elems
+            .iter()
+            .map(|elem| match elem {
+                Expr::Lit(ExprLit {
+                    lit: Lit::Str(lit), ..
+                }) => {
+                    let weighted = lit.parse_with(parse_weighted).map_err(|error| {
+                        Error::new(lit.span(), format!("invalid oneof: {}", error))
+                    })?;
+                    match weighted {
+                        Some((weight, expr)) => Ok(WeightedStrategy {
+                            weight: Some(weight),
+                            strategy: SpannedExpr {
+                                expr,
+                                span: lit.span(),
+                            },
+                        }),
+                        // Not weighted, parsed as any other strategy
+                        None => Ok(WeightedStrategy {
+                            weight: None,
+                            strategy: SpannedExpr::from_lit_str("oneof", lit)?,
+                        }),
+                    }
+                }
+                elem => Ok(WeightedStrategy {
+                    weight: None,
+                    strategy: SpannedExpr::from_expr("oneof", elem)?,
+                }),
+            })
+            .collect()
+    }
+
+    /// The union of `strategies` picked according to their weights. The strategies without a
+    /// weight have the weight of 1 if any other strategy has one.
+    fn union(krate: &Path, strategies: &[Self], span: Span) -> SpannedExpr {
+        let weighted = strategies.iter().any(|strategy| strategy.weight.is_some());
+        let arms = strategies
+            .iter()
+            .map(
+                |WeightedStrategy { weight, strategy }| match (weight, weighted) {
+                    (Some(weight), _) => quote! { #weight => #strategy },
+                    (None, true) => quote! { 1 => #strategy },
+                    (None, false) => quote! { #strategy },
+                },
+            );
+        SpannedExpr {
+            expr: parse_quote_spanned!(span=> #krate::prop_oneof![#(#arms),*]),
+            span,
+        }
+    }
+}
+
+/// Parse `weight => strategy`, or nothing if there is no `=>` outside of the groups of `input`,
+/// such as the arms of a `match`, which makes it a strategy without a weight.
+fn parse_weighted(input: ParseStream) -> syn::Result<Option<(LitInt, Expr)>> {
+    let tokens = input.fork();
+    let mut weighted = false;
+    while !tokens.is_empty() && !weighted {
+        weighted = tokens.peek(Token![=>]);
+        tokens.parse::<TokenTree>()?;
+    }
+    if !weighted {
+        input.parse::<TokenStream>()?;
+        return Ok(None);
+    }
+
+    let weight = input
+        .parse::<LitInt>()
+        .and_then(|weight| weight.base10_parse::<u32>().map(|_| weight))
+        .map_err(|error| {
+            Error::new(
+                error.span(),
+                "the weight before `=>` must be an integer, e.g. `\"3 => 0..10\"`",
+            )
+        })?;
+    input.parse::<Token![=>]>()?;
+    Ok(Some((weight, input.parse()?)))
+}
+
+/// Name of a function generated next to the test.
+pub(crate) enum FnName {
+    /// `<test name>_<suffix>`
+    Default,
+    Named(Ident),
+}
+
+impl FnName {
+    pub(crate) fn ident(&self, test_name: &Ident, suffix: &str) -> Ident {
+        match self {
+            FnName::Default => format_ident!("{}_{}", test_name, suffix),
+            FnName::Named(ident) => ident.clone(),
+        }
+    }
+}
+
+/// Arguments configuring the runtime of `async = "tokio"`.
+const TOKIO_ARGS: &[&str] = &[
+    "flavor",
+    "worker_threads",
+    "start_paused",
+    "runtime_per_case",
+];
+
+/// Arguments of the `#[proptest(...)]` attribute.
+#[derive(Default)]
+pub(crate) struct ProptestArgs {
+    /// Path to the `proptest` crate, given with the `crate` argument.
+    pub(crate) krate: Option<Path>,
+    pub(crate) strategy: Option<SpannedExpr>,
+    /// Strategies to generate a separate test for each, given with `strategies`.
+    pub(crate) strategies: Option<Vec<MatrixStrategy>>,
+    /// Values of the arguments run as test cases before the random ones, one per `example`.
+    pub(crate) examples: Vec<SpannedExpr>,
+    pub(crate) cases: Option<u32>,
+    pub(crate) timeout: Option<u32>,
+    pub(crate) total_timeout: Option<TotalTimeout>,
+    /// The size of the collections and strings of the inferred strategies.
+    pub(crate) size: Option<SizeHint>,
+    pub(crate) max_shrink_iters: Option<u32>,
+    pub(crate) max_shrink_time: Option<u32>,
+    pub(crate) max_local_rejects: Option<u32>,
+    pub(crate) max_global_rejects: Option<u32>,
+    pub(crate) max_flat_map_regens: Option<u32>,
+    pub(crate) result_cache: Option<bool>,
+    pub(crate) no_shrink: Option<bool>,
+    pub(crate) cache_strategy: Option<bool>,
+    pub(crate) trace: Option<bool>,
+    pub(crate) no_std: Option<bool>,
+    pub(crate) exhaustive_small: Option<bool>,
+    pub(crate) falsify: Option<bool>,
+    pub(crate) fail_fast: Option<bool>,
+    /// The last argument takes a `TestRng` derived from the RNG generating the case.
+    pub(crate) with_rng: Option<bool>,
+    /// The last argument takes the context of the case from the runtime crate.
+    pub(crate) with_context: Option<bool>,
+    /// Skip the inputs that were already tested without counting them as cases.
+    pub(crate) dedup: Option<bool>,
+    /// Run every case for all the interleavings of its threads with `loom` under `cfg(loom)`.
+    pub(crate) loom: Option<bool>,
+    pub(crate) debug_expand: Option<bool>,
+    /// Describe the strategies and the configuration of the test in its documentation.
+    pub(crate) document: Option<bool>,
+    /// All the arguments as they are written, for `document`.
+    pub(crate) written: Vec<Arg>,
+    pub(crate) small_values: Option<u32>,
+    /// The number of distinct failing inputs to collect before failing the test.
+    pub(crate) continue_on_failure: Option<u32>,
+    pub(crate) env_overrides: Option<bool>,
+    pub(crate) fork: Option<bool>,
+    pub(crate) parallel: Option<bool>,
+    pub(crate) threads: Option<u32>,
+    pub(crate) config: Option<SpannedExpr>,
+    /// Expression creating the whole `TestRunner`.
+    pub(crate) runner: Option<SpannedExpr>,
+    pub(crate) persistence: Option<Persistence>,
+    /// Directory with the files run as test cases before the random ones.
+    pub(crate) corpus: Option<LitStr>,
+    /// File or directory of regression files, the seeds of which are replayed before the random
+    /// cases.
+    pub(crate) regressions: Option<LitStr>,
+    pub(crate) async_runtime: Option<AsyncRuntime>,
+    /// Options of the `tokio` runtime, moved into `async_runtime` once parsed.
+    pub(crate) flavor: Option<Flavor>,
+    pub(crate) worker_threads: Option<u32>,
+    pub(crate) start_paused: Option<bool>,
+    pub(crate) runtime_per_case: Option<bool>,
+    pub(crate) seed: Option<Seed>,
+    pub(crate) rng: Option<RngAlgorithm>,
+    pub(crate) rng_bytes: Option<SpannedExpr>,
+    pub(crate) filter: Option<SpannedExpr>,
+    pub(crate) no_test_attr: Option<bool>,
+    pub(crate) test_attr: Option<Meta>,
+    pub(crate) property_fn: Option<FnName>,
+    pub(crate) fuzz_target: Option<FnName>,
+    pub(crate) bench: Option<FnName>,
+    pub(crate) iai: Option<FnName>,
+    pub(crate) kani: Option<FnName>,
+    /// An AFL++ harness compiled with the `afl` feature of the crate.
+    pub(crate) afl: Option<FnName>,
+    /// The number of inputs the `iai` benchmark runs the property with.
+    pub(crate) iai_inputs: Option<u32>,
+    /// Text the panic message must contain, `Some(None)` for any panic.
+    pub(crate) expect_panic: Option<Option<LitStr>>,
+    /// The reason of ignoring the test, `Some(None)` if none is given.
+    pub(crate) ignore: Option<Option<LitStr>>,
+    pub(crate) ignore_if: Option<IgnoreIf>,
+    /// Types to instantiate a generic test function with.
+    pub(crate) types: Option<Vec<Type>>,
+    /// Constants to instantiate a test function with a const parameter with.
+    pub(crate) consts: Option<Vec<Expr>>,
+    /// Function called once before the first test case.
+    pub(crate) before_all: Option<Path>,
+    /// Functions called before and after each test case.
+    pub(crate) before_each: Option<Path>,
+    pub(crate) after_each: Option<Path>,
+    /// Function checking the value returned by the property together with its inputs.
+    pub(crate) postcondition: Option<Path>,
+    /// Function writing the message of a failure from the minimal failing input and the error.
+    pub(crate) format_failure: Option<Path>,
+    /// Callback given the summary of a passed run.
+    pub(crate) on_complete: Option<SpannedExpr>,
+    pub(crate) verbose: Option<u32>,
+    pub(crate) replay: Option<Replay>,
+    pub(crate) log_inputs: Option<LogInputs>,
+    pub(crate) report: Option<Report>,
+    /// Statements in scope for the strategy.
+    pub(crate) setup: Option<Setup>,
+    pub(crate) serial: Option<Serial>,
+    pub(crate) classify: Option<SpannedExpr>,
+    /// File the statistics of the generated values are written to.
+    pub(crate) stats_out: Option<LitStr>,
+    /// File the records of the runs are appended to as lines of JSON.
+    pub(crate) json_report: Option<LitStr>,
+    pub(crate) target: Option<Target>,
+    pub(crate) engine: Option<Engine>,
+}
+
+impl ProptestArgs {
+    pub(crate) fn parse(args: Vec<Arg>) -> syn::Result<Self> {
+        let mut result = ProptestArgs::default();
+        // Where each argument was written, for the errors about combinations of arguments
+        let mut spans = HashMap::new();
+        let mut oneof = None;
+        let mut tokio_args = Vec::new();
+
+        for arg in args {
+            result.written.push(arg.clone());
+            let name = arg.name.to_string();
+            spans.insert(name.clone(), arg.name.span());
+            if TOKIO_ARGS.contains(&name.as_str()) {
+                tokio_args.push(arg.name.clone());
+            }
+            let value = arg.value.as_ref().map(|(_, value)| value);
+            // Only flags and the arguments with a default below can be given without a value
+            let unknown = || Error::new_spanned(&arg, "unknown argument");
+
+            if let Some(flag) = result.flag_mut(&name) {
+                let flag_value = match value {
+                    Some(value) => parse_bool(&name, value)?,
+                    None => true,
+                };
+                set_once(flag, flag_value, &arg, &name)?;
+            } else if let Some(fn_name) = result.fn_name_mut(&name) {
+                let value = match value {
+                    Some(value) => parse_fn_name(&name, value)?,
+                    None => Some(FnName::Default),
+                };
+                if let Some(value) = value {
+                    set_once(fn_name, value, &arg, &name)?;
+                }
+            } else if let Some((number, unit)) = result.u32_mut(&name) {
+                let value = value.ok_or_else(unknown)?;
+                let parsed = parse_u32(&name, value)?;
+                if let (0, Some(unit)) = (parsed, unit) {
+                    return Err(Error::new_spanned(
+                        value,
+                        format!("invalid {}: at least one {} is required", name, unit),
+                    ));
+                }
+                set_once(number, parsed, &arg, &name)?;
+            } else if let Some(path) = result.path_mut(&name) {
+                let value = value.ok_or_else(unknown)?;
+                set_once(path, parse_path(&name, value)?, &arg, &name)?;
+            } else if let Some(lit) = result.lit_str_mut(&name) {
+                let value = value.ok_or_else(unknown)?;
+                set_once(lit, parse_lit_str(&name, value)?, &arg, &name)?;
+            } else if let Some(expr) = result.expr_mut(&name) {
+                let value = value.ok_or_else(unknown)?;
+                set_once(expr, SpannedExpr::from_expr(&name, value)?, &arg, &name)?;
+            } else {
+                match (name.as_str(), value) {
+                    ("expect_panic", None) => {
+                        set_once(&mut result.expect_panic, None, &arg, &name)?
+                    }
+                    ("expect_panic", Some(value)) => {
+                        let message = parse_lit_str(&name, value)?;
+                        set_once(&mut result.expect_panic, Some(message), &arg, &name)?;
+                    }
+                    ("ignore", None) => set_once(&mut result.ignore, None, &arg, &name)?,
+                    ("ignore", Some(value)) => {
+                        let reason = parse_lit_str(&name, value)?;
+                        set_once(&mut result.ignore, Some(reason), &arg, &name)?;
+                    }
+                    ("serial", None) => set_once(&mut result.serial, Serial::All, &arg, &name)?,
+                    ("serial", Some(value)) => {
+                        set_once(&mut result.serial, Serial::from_expr(value)?, &arg, &name)?
+                    }
+                    ("log_inputs", None) => {
+                        set_once(&mut result.log_inputs, LogInputs::Stderr, &arg, &name)?
+                    }
+                    ("log_inputs", Some(value)) => {
+                        let log_inputs = LogInputs::from_expr(value)?;
+                        set_once(&mut result.log_inputs, log_inputs, &arg, &name)?;
+                    }
+                    (_, None) => return Err(unknown()),
+                    ("strategy", Some(value)) => {
+                        if result.strategy.is_some() {
+                            return Err(Error::new_spanned(
+                                arg,
+                                "multiple strategies are not allowed",
+                            ));
+                        }
+                        result.strategy = Some(SpannedExpr::from_expr(&name, value)?);
+                    }
+                    ("example", Some(value)) => {
+                        result.examples.push(SpannedExpr::from_expr(&name, value)?);
+                    }
+                    ("strategies", Some(value)) => {
+                        let strategies = MatrixStrategy::parse_list(value)?;
+                        set_once(&mut result.strategies, strategies, &arg, &name)?;
+                    }
+                    ("oneof", Some(value)) => {
+                        set_once(
+                            &mut oneof,
+                            WeightedStrategy::parse_list(value)?,
+                            &arg,
+                            &name,
+                        )?;
+                    }
+                    ("replay", Some(value)) => {
+                        set_once(&mut result.replay, Replay::from_expr(value)?, &arg, &name)?;
+                    }
+                    ("total_timeout", Some(value)) => {
+                        let total_timeout = TotalTimeout::from_expr(value)?;
+                        set_once(&mut result.total_timeout, total_timeout, &arg, &name)?;
+                    }
+                    ("size", Some(value)) => {
+                        set_once(&mut result.size, SizeHint::from_expr(value)?, &arg, &name)?;
+                    }
+                    ("report", Some(value)) => {
+                        set_once(&mut result.report, Report::from_expr(value)?, &arg, &name)?;
+                    }
+                    ("setup", Some(value)) => {
+                        set_once(&mut result.setup, Setup::from_expr(value)?, &arg, &name)?;
+                    }
+                    ("target", Some(value)) => {
+                        set_once(&mut result.target, Target::from_expr(value)?, &arg, &name)?;
+                    }
+                    ("engine", Some(value)) => {
+                        set_once(&mut result.engine, Engine::from_expr(value)?, &arg, &name)?;
+                    }
+                    ("persistence", Some(value)) => {
+                        let persistence = Persistence::from_expr(value)?;
+                        set_once(&mut result.persistence, persistence, &arg, &name)?;
+                    }
+                    ("async", Some(value)) => {
+                        let runtime = AsyncRuntime::from_expr(value)?;
+                        set_once(&mut result.async_runtime, runtime, &arg, &name)?;
+                    }
+                    ("flavor", Some(value)) => {
+                        set_once(&mut result.flavor, Flavor::from_expr(value)?, &arg, &name)?;
+                    }
+                    ("seed", Some(value)) => {
+                        set_once(&mut result.seed, Seed::from_expr(value)?, &arg, &name)?;
+                    }
+                    ("rng", Some(value)) => {
+                        set_once(
+                            &mut result.rng,
+                            RngAlgorithm::from_expr(value)?,
+                            &arg,
+                            &name,
+                        )?;
+                    }
+                    ("test_attr", Some(value)) => {
+                        set_once(
+                            &mut result.test_attr,
+                            parse_meta(&name, value)?,
+                            &arg,
+                            &name,
+                        )?;
+                    }
+                    ("ignore_if", Some(value)) => {
+                        let ignore_if = IgnoreIf::from_expr(value)?;
+                        set_once(&mut result.ignore_if, ignore_if, &arg, &name)?;
+                    }
+                    ("types", Some(value)) => {
+                        set_once(&mut result.types, parse_types(&name, value)?, &arg, &name)?;
+                    }
+                    ("consts", Some(value)) => {
+                        set_once(&mut result.consts, parse_consts(&name, value)?, &arg, &name)?;
+                    }
+                    _ => return Err(unknown()),
+                }
+            }
+        }
+
+        let arg_span = |name: &str| spans.get(name).copied();
+        if let (Some(oneof), Some(span)) = (oneof, arg_span("oneof")) {
+            if result.strategy.is_some() || arg_span("strategies").is_some() {
+                return Err(Error::new(
+                    span,
+                    "`oneof` cannot be combined with `strategy` or `strategies`",
+                ));
+            }
+            result.strategy = Some(WeightedStrategy::union(&result.krate(), &oneof, span));
+        }
+
+        if let (Some(_), Some(span)) = (&result.strategy, arg_span("strategies")) {
+            return Err(Error::new(
+                span,
+                "`strategies` cannot be combined with `strategy`",
+            ));
+        }
+
+        if let (Some(true), Some(span)) = (result.parallel, arg_span("parallel")) {
+            if result.fork.unwrap_or(false) || result.timeout.is_some() {
+                return Err(Error::new(
+                    span,
+                    "`parallel` cannot be combined with `fork` or `timeout`",
+                ));
+            }
+            if let Some(replay) = &result.replay {
+                return Err(Error::new_spanned(
+                    &replay.0,
+                    "`replay` cannot be combined with `parallel`",
+                ));
+            }
+            if let Some(corpus) = &result.corpus {
+                return Err(Error::new_spanned(
+                    corpus,
+                    "`corpus` cannot be combined with `parallel`",
+                ));
+            }
+            if let Some(regressions) = &result.regressions {
+                return Err(Error::new_spanned(
+                    regressions,
+                    "`regressions` cannot be combined with `parallel`",
+                ));
+            }
+        } else if let Some(span) = arg_span("threads") {
+            return Err(Error::new(span, "`threads` requires `parallel`"));
+        }
+
+        if let Some(name) = tokio_args.first() {
+            let tokio = match &mut result.async_runtime {
+                Some(AsyncRuntime::Tokio(tokio)) => tokio,
+                _ => {
+                    return Err(Error::new_spanned(
+                        name,
+                        format!("`{}` requires `async = \"tokio\"`", name),
+                    ))
+                }
+            };
+            let arg_name = |arg: &str| tokio_args.iter().find(|name| *name == arg).unwrap();
+            tokio.flavor = result.flavor;
+            tokio.worker_threads = result.worker_threads;
+            tokio.start_paused = result.start_paused.unwrap_or(false);
+            tokio.per_case = result.runtime_per_case.unwrap_or(false);
+            if let (Some(Flavor::CurrentThread), Some(_)) = (tokio.flavor, tokio.worker_threads) {
+                return Err(Error::new_spanned(
+                    arg_name("worker_threads"),
+                    "`worker_threads` requires `flavor = \"multi_thread\"`",
+                ));
+            }
+            if tokio.start_paused && tokio.is_multi_thread() {
+                return Err(Error::new_spanned(
+                    arg_name("start_paused"),
+                    "`start_paused` requires `flavor = \"current_thread\"`",
+                ));
+            }
+        }
+
+        if let (Some(replay), Some(_)) = (&result.replay, &result.persistence) {
+            return Err(Error::new_spanned(
+                &replay.0,
+                "`replay` cannot be combined with `persistence`",
+            ));
+        }
+
+        if let (Some(replay), Some(_)) = (&result.replay, &result.corpus) {
+            return Err(Error::new_spanned(
+                &replay.0,
+                "`replay` cannot be combined with `corpus`",
+            ));
+        }
+
+        if let (Some(replay), Some(_)) = (&result.replay, &result.regressions) {
+            return Err(Error::new_spanned(
+                &replay.0,
+                "`replay` cannot be combined with `regressions`",
+            ));
+        }
+
+        match (&result.rng, &result.rng_bytes) {
+            (Some(rng), None) if rng.is_pass_through() => {
+                return Err(Error::new_spanned(
+                    &rng.0,
+                    "`rng = \"PassThrough\"` requires the bytes given with `rng_bytes`",
+                ));
+            }
+            (Some(rng), Some(_)) if !rng.is_pass_through() => {
+                return Err(Error::new_spanned(
+                    &rng.0,
+                    "`rng_bytes` can only be used with `rng = \"PassThrough\"`",
+                ));
+            }
+            (None, Some(rng_bytes)) => {
+                return Err(Error::new(
+                    rng_bytes.span,
+                    "`rng_bytes` requires `rng = \"PassThrough\"`",
+                ));
+            }
+            _ => {}
+        }
+        if let (Some(rng), Some(_)) = (&result.rng, &result.seed) {
+            return Err(Error::new_spanned(
+                &rng.0,
+                "`rng` cannot be combined with `seed`, which selects the algorithm itself",
+            ));
+        }
+
+        // Subprocesses and the file system are not available on wasm, and the test attribute is
+        // provided by `wasm-bindgen-test`
+        if let (Some(Target::Wasm), Some(span)) = (&result.target, arg_span("target")) {
+            let unsupported = [
+                ("fork", result.fork.unwrap_or(false)),
+                ("timeout", result.timeout.is_some()),
+                ("total_timeout", result.total_timeout.is_some()),
+                ("parallel", result.parallel.unwrap_or(false)),
+                ("persistence", result.persistence.is_some()),
+                ("corpus", result.corpus.is_some()),
+                ("regressions", result.regressions.is_some()),
+                (
+                    "serial",
+                    matches!(result.serial, Some(Serial::All | Serial::Group(_))),
+                ),
+                ("test_attr", result.test_attr.is_some()),
+                ("no_test_attr", result.no_test_attr.unwrap_or(false)),
+                (
+                    "report = \"googletest\"",
+                    matches!(result.report, Some(Report::Googletest)),
+                ),
+            ];
+            if let Some((name, _)) = unsupported.iter().find(|(_, used)| *used) {
+                return Err(Error::new(
+                    span,
+                    format!("`{}` cannot be combined with `target = \"wasm\"`", name),
+                ));
+            }
+        }
+
+        // The engine drives the cases and provides the randomness itself
+        if let (Some(Engine::Bolero), Some(span)) = (&result.engine, arg_span("engine")) {
+            let unsupported = [
+                ("fork", result.fork.unwrap_or(false)),
+                ("timeout", result.timeout.is_some()),
+                ("total_timeout", result.total_timeout.is_some()),
+                ("parallel", result.parallel.unwrap_or(false)),
+                ("persistence", result.persistence.is_some()),
+                ("replay", result.replay.is_some()),
+                ("corpus", result.corpus.is_some()),
+                ("regressions", result.regressions.is_some()),
+                ("seed", result.seed.is_some()),
+                ("rng", result.rng.is_some()),
+                ("target", result.target.is_some()),
+                (
+                    "report = \"googletest\"",
+                    matches!(result.report, Some(Report::Googletest)),
+                ),
+            ];
+            if let Some((name, _)) = unsupported.iter().find(|(_, used)| *used) {
+                return Err(Error::new(
+                    span,
+                    format!("`{}` cannot be combined with `engine = \"bolero\"`", name),
+                ));
+            }
+        }
+
+        // The configuration and the RNG are a part of the runner created by the expression
+        if let (Some(_), Some(span)) = (&result.runner, arg_span("runner")) {
+            let unsupported = [
+                ("config", result.config.is_some()),
+                ("cases", result.cases.is_some()),
+                ("timeout", result.timeout.is_some()),
+                ("max_shrink_iters", result.max_shrink_iters.is_some()),
+                ("max_shrink_time", result.max_shrink_time.is_some()),
+                ("max_local_rejects", result.max_local_rejects.is_some()),
+                ("max_global_rejects", result.max_global_rejects.is_some()),
+                ("max_flat_map_regens", result.max_flat_map_regens.is_some()),
+                ("result_cache", result.result_cache.is_some()),
+                ("env_overrides", result.env_overrides.is_some()),
+                ("verbose", result.verbose.is_some()),
+                ("fork", result.fork.is_some()),
+                ("persistence", result.persistence.is_some()),
+                ("seed", result.seed.is_some()),
+                ("rng", result.rng.is_some()),
+                ("replay", result.replay.is_some()),
+                ("parallel", result.parallel.is_some()),
+                ("engine", result.engine.is_some()),
+                // The cases are limited under `cfg(loom)` by changing the configuration
+                ("loom", result.loom.is_some()),
+                // The duplicates are rejected within a raised limit, and the smallest values are
+                // found by a runner made from the configuration
+                ("dedup", result.dedup.is_some()),
+                ("exhaustive_small", result.exhaustive_small.is_some()),
+            ];
+            if let Some((name, _)) = unsupported.iter().find(|(_, used)| *used) {
+                return Err(Error::new(
+                    span,
+                    format!("`{}` cannot be combined with `runner`", name),
+                ));
+            }
+        }
+
+        // Cases run in subprocesses, in other threads or by an engine are not recorded
+        let recorded = [
+            ("stats_out", &result.stats_out),
+            ("json_report", &result.json_report),
+        ];
+        for (recorder, path) in recorded.iter() {
+            if let Some(path) = path {
+                let unsupported = [
+                    ("fork", result.fork.unwrap_or(false)),
+                    ("parallel", result.parallel.unwrap_or(false)),
+                    ("engine", result.engine.is_some()),
+                ];
+                if let Some((name, _)) = unsupported.iter().find(|(_, used)| *used) {
+                    return Err(Error::new_spanned(
+                        path,
+                        format!("`{}` cannot be combined with `{}`", recorder, name),
+                    ));
+                }
+            }
+        }
+
+        // A failure is the expected outcome, which only the sequential runner reports this way
+        if let (Some(true), Some(span)) = (result.falsify, arg_span("falsify")) {
+            let unsupported = [
+                ("fork", result.fork.unwrap_or(false)),
+                ("parallel", result.parallel.unwrap_or(false)),
+                ("engine", result.engine.is_some()),
+                ("expect_panic", result.expect_panic.is_some()),
+                ("regressions", result.regressions.is_some()),
+            ];
+            if let Some((name, _)) = unsupported.iter().find(|(_, used)| *used) {
+                return Err(Error::new(
+                    span,
+                    format!("`{}` cannot be combined with `falsify`", name),
+                ));
+            }
+        }
+
+        // The RNG is generated by a strategy of the last argument, and Kani has no such values
+        if let (Some(true), Some(span)) = (result.with_rng, arg_span("with_rng")) {
+            let unsupported = [
+                ("strategies", result.strategies.is_some()),
+                ("oneof", arg_span("oneof").is_some()),
+                ("kani", result.kani.is_some()),
+            ];
+            if let Some((name, _)) = unsupported.iter().find(|(_, used)| *used) {
+                return Err(Error::new(
+                    span,
+                    format!("`{}` cannot be combined with `with_rng`", name),
+                ));
+            }
+        }
+
+        // The inputs tested so far are tracked by the sequential runner in this process
+        if let (Some(true), Some(span)) = (result.dedup, arg_span("dedup")) {
+            let unsupported = [
+                ("fork", result.fork.unwrap_or(false)),
+                ("timeout", result.timeout.is_some()),
+                ("parallel", result.parallel.unwrap_or(false)),
+                ("engine", result.engine.is_some()),
+            ];
+            if let Some((name, _)) = unsupported.iter().find(|(_, used)| *used) {
+                return Err(Error::new(
+                    span,
+                    format!("`{}` cannot be combined with `dedup`", name),
+                ));
+            }
+        }
+
+        // The model runs the case synchronously on the thread of the runner
+        if let (Some(true), Some(span)) = (result.loom, arg_span("loom")) {
+            let unsupported = [
+                ("async", result.async_runtime.is_some()),
+                ("parallel", result.parallel.unwrap_or(false)),
+            ];
+            if let Some((name, _)) = unsupported.iter().find(|(_, used)| *used) {
+                return Err(Error::new(
+                    span,
+                    format!("`{}` cannot be combined with `loom`", name),
+                ));
+            }
+        }
+
+        // The context is made by the runner of the test from the seed of the run, which the other
+        // callers of the property do not have
+        if let (Some(true), Some(span)) = (result.with_context, arg_span("with_context")) {
+            let unsupported = [
+                ("parallel", result.parallel.unwrap_or(false)),
+                ("engine", result.engine.is_some()),
+                ("loom", result.loom.unwrap_or(false)),
+                ("fuzz_target", result.fuzz_target.is_some()),
+                ("kani", result.kani.is_some()),
+                ("bench", result.bench.is_some()),
+                ("iai", result.iai.is_some()),
+                ("afl", result.afl.is_some()),
+            ];
+            if let Some((name, _)) = unsupported.iter().find(|(_, used)| *used) {
+                return Err(Error::new(
+                    span,
+                    format!("`{}` cannot be combined with `with_context`", name),
+                ));
+            }
+        }
+
+        // The other reports write the failures themselves
+        if let Some(format_failure) = &result.format_failure {
+            let unsupported = [
+                ("falsify", result.falsify.unwrap_or(false)),
+                ("continue_on_failure", result.continue_on_failure.is_some()),
+                ("fail_fast = false", result.fail_fast == Some(false)),
+                ("engine", result.engine.is_some()),
+            ];
+            if let Some((name, _)) = unsupported.iter().find(|(_, used)| *used) {
+                return Err(Error::new_spanned(
+                    format_failure,
+                    format!("`{}` cannot be combined with `format_failure`", name),
+                ));
+            }
+        }
+
+        // The summary is taken from the sequential runner in this process
+        if let Some(on_complete) = &result.on_complete {
+            let unsupported = [
+                ("fork", result.fork.unwrap_or(false)),
+                ("parallel", result.parallel.unwrap_or(false)),
+                ("engine", result.engine.is_some()),
+            ];
+            if let Some((name, _)) = unsupported.iter().find(|(_, used)| *used) {
+                return Err(Error::new(
+                    on_complete.span,
+                    format!("`{}` cannot be combined with `on_complete`", name),
+                ));
+            }
+        }
+
+        // The examples are run by the sequential runner before the random cases
+        if let Some(example) = result.examples.first() {
+            let unsupported = [
+                ("parallel", result.parallel.unwrap_or(false)),
+                ("continue_on_failure", result.continue_on_failure.is_some()),
+                ("engine", result.engine.is_some()),
+                ("replay", result.replay.is_some()),
+            ];
+            if let Some((name, _)) = unsupported.iter().find(|(_, used)| *used) {
+                return Err(Error::new(
+                    example.span,
+                    format!("`example` cannot be combined with `{}`", name),
+                ));
+            }
+        }
+
+        // The failures are collected by running the sequential runner repeatedly in this process
+        if let (Some(_), Some(span)) = (result.continue_on_failure, arg_span("continue_on_failure"))
+        {
+            let unsupported = [
+                ("fork", result.fork.unwrap_or(false)),
+                ("timeout", result.timeout.is_some()),
+                ("parallel", result.parallel.unwrap_or(false)),
+                ("engine", result.engine.is_some()),
+                ("falsify", result.falsify.unwrap_or(false)),
+                ("exhaustive_small", result.exhaustive_small.unwrap_or(false)),
+                ("regressions", result.regressions.is_some()),
+            ];
+            if let Some((name, _)) = unsupported.iter().find(|(_, used)| *used) {
+                return Err(Error::new(
+                    span,
+                    format!("`{}` cannot be combined with `continue_on_failure`", name),
+                ));
+            }
+        }
+
+        // The failures are recorded by the sequential runner in this process
+        if let (Some(false), Some(span)) = (result.fail_fast, arg_span("fail_fast")) {
+            let unsupported = [
+                ("fork", result.fork.unwrap_or(false)),
+                ("timeout", result.timeout.is_some()),
+                ("parallel", result.parallel.unwrap_or(false)),
+                ("engine", result.engine.is_some()),
+                ("falsify", result.falsify.unwrap_or(false)),
+                ("continue_on_failure", result.continue_on_failure.is_some()),
+                ("stats_out", result.stats_out.is_some()),
+                ("json_report", result.json_report.is_some()),
+            ];
+            if let Some((name, _)) = unsupported.iter().find(|(_, used)| *used) {
+                return Err(Error::new(
+                    span,
+                    format!("`{}` cannot be combined with `fail_fast = false`", name),
+                ));
+            }
+        }
+
+        // The values are run by the sequential runner before the random ones
+        if let (Some(true), Some(span)) = (result.exhaustive_small, arg_span("exhaustive_small")) {
+            let unsupported = [
+                ("parallel", result.parallel.unwrap_or(false)),
+                ("replay", result.replay.is_some()),
+                ("engine", result.engine.is_some()),
+            ];
+            if let Some((name, _)) = unsupported.iter().find(|(_, used)| *used) {
+                return Err(Error::new(
+                    span,
+                    format!("`{}` cannot be combined with `exhaustive_small`", name),
+                ));
+            }
+        } else if let Some(span) = arg_span("small_values") {
+            return Err(Error::new(
+                span,
+                "`small_values` requires `exhaustive_small`",
+            ));
+        }
+
+        if let (None, Some(span)) = (&result.iai, arg_span("iai_inputs")) {
+            return Err(Error::new(span, "`iai_inputs` requires `iai`"));
+        }
+
+        // The fuzz target and the proof harness only check the result of the property
+        if let Some(postcondition) = &result.postcondition {
+            let unsupported = [
+                ("fuzz_target", result.fuzz_target.is_some()),
+                ("kani", result.kani.is_some()),
+            ];
+            if let Some((name, _)) = unsupported.iter().find(|(_, used)| *used) {
+                return Err(Error::new_spanned(
+                    postcondition,
+                    format!("`postcondition` cannot be combined with `{}`", name),
+                ));
+            }
+        }
+
+        // The file system, threads, processes, stderr and unwinding are only available with `std`
+        if let (Some(true), Some(span)) = (result.no_std, arg_span("no_std")) {
+            let unsupported = [
+                ("fork", result.fork.unwrap_or(false)),
+                ("timeout", result.timeout.is_some()),
+                ("total_timeout", result.total_timeout.is_some()),
+                ("persistence", result.persistence.is_some()),
+                ("replay", result.replay.is_some()),
+                ("corpus", result.corpus.is_some()),
+                ("regressions", result.regressions.is_some()),
+                ("env_overrides", result.env_overrides.unwrap_or(false)),
+                ("parallel", result.parallel.unwrap_or(false)),
+                ("engine", result.engine.is_some()),
+                ("async", result.async_runtime.is_some()),
+                ("expect_panic", result.expect_panic.is_some()),
+                (
+                    "ignore_if",
+                    matches!(result.ignore_if, Some(IgnoreIf::Expr(_))),
+                ),
+                ("before_all", result.before_all.is_some()),
+                ("cache_strategy", result.cache_strategy.unwrap_or(false)),
+                ("classify", result.classify.is_some()),
+                ("stats_out", result.stats_out.is_some()),
+                ("json_report", result.json_report.is_some()),
+                ("falsify", result.falsify.unwrap_or(false)),
+                ("continue_on_failure", result.continue_on_failure.is_some()),
+                ("fail_fast = false", result.fail_fast == Some(false)),
+                ("dedup", result.dedup.unwrap_or(false)),
+                ("loom", result.loom.unwrap_or(false)),
+                ("on_complete", result.on_complete.is_some()),
+                ("with_context", result.with_context.unwrap_or(false)),
+                (
+                    "report = \"googletest\"",
+                    matches!(result.report, Some(Report::Googletest)),
+                ),
+                (
+                    "serial",
+                    matches!(result.serial, Some(Serial::All | Serial::Group(_))),
+                ),
+                (
+                    "log_inputs",
+                    matches!(result.log_inputs, Some(LogInputs::Stderr)),
+                ),
+                ("fuzz_target", result.fuzz_target.is_some()),
+                ("bench", result.bench.is_some()),
+                ("iai", result.iai.is_some()),
+                ("afl", result.afl.is_some()),
+            ];
+            if let Some((name, _)) = unsupported.iter().find(|(_, used)| *used) {
+                return Err(Error::new(
+                    span,
+                    format!("`{}` cannot be combined with `no_std`", name),
+                ));
+            }
+        }
+
+        // Without a test attribute, there is nothing to place `#[ignore]` next to
+        let ignore_attr = match &result.ignore_if {
+            Some(IgnoreIf::Cfg(_)) => true,
+            Some(IgnoreIf::Expr(_)) => false,
+            None => result.ignore.is_some(),
+        };
+        if let (true, Some(true), Some(span)) = (
+            ignore_attr,
+            result.no_test_attr,
+            arg_span("ignore").or_else(|| arg_span("ignore_if")),
+        ) {
+            return Err(Error::new(
+                span,
+                "`ignore` cannot be combined with `no_test_attr`",
+            ));
+        }
+
+        if let (Some(true), Some(test_attr)) = (result.no_test_attr, &result.test_attr) {
+            return Err(Error::new_spanned(
+                test_attr,
+                "`test_attr` cannot be combined with `no_test_attr`",
+            ));
+        }
+
+        Ok(result)
+    }
+
+    /// Path to the `proptest` crate used in the generated code.
+    pub(crate) fn krate(&self) -> Path {
+        self.krate
+            .clone()
+            .unwrap_or_else(|| parse_quote! { ::proptest })
+    }
+
+    /// How a failure is reported, a panic unless `report` is given.
+    pub(crate) fn report(&self) -> Report {
+        self.report.unwrap_or(Report::Panic)
+    }
+
+    /// Get the boolean argument with the given name.
+    fn flag_mut(&mut self, name: &str) -> Option<&mut Option<bool>> {
+        Some(match name {
+            "no_test_attr" => &mut self.no_test_attr,
+            "fork" => &mut self.fork,
+            "parallel" => &mut self.parallel,
+            "result_cache" => &mut self.result_cache,
+            "env_overrides" => &mut self.env_overrides,
+            "no_shrink" => &mut self.no_shrink,
+            "cache_strategy" => &mut self.cache_strategy,
+            "trace" => &mut self.trace,
+            "no_std" => &mut self.no_std,
+            "exhaustive_small" => &mut self.exhaustive_small,
+            "falsify" => &mut self.falsify,
+            "fail_fast" => &mut self.fail_fast,
+            "with_rng" => &mut self.with_rng,
+            "dedup" => &mut self.dedup,
+            "loom" => &mut self.loom,
+            "with_context" => &mut self.with_context,
+            "debug_expand" => &mut self.debug_expand,
+            "document" => &mut self.document,
+            "start_paused" => &mut self.start_paused,
+            "runtime_per_case" => &mut self.runtime_per_case,
+            _ => return None,
+        })
+    }
+
+    /// Get the argument naming a generated function with the given name.
+    fn fn_name_mut(&mut self, name: &str) -> Option<&mut Option<FnName>> {
+        Some(match name {
+            "property_fn" => &mut self.property_fn,
+            "fuzz_target" => &mut self.fuzz_target,
+            "bench" => &mut self.bench,
+            "afl" => &mut self.afl,
+            "iai" => &mut self.iai,
+            "kani" => &mut self.kani,
+            _ => return None,
+        })
+    }
+
+    /// Get the integer argument with the given name, along with what it counts if it cannot be
+    /// zero.
+    fn u32_mut(&mut self, name: &str) -> Option<(&mut Option<u32>, Option<&'static str>)> {
+        Some(match name {
+            "cases" => (&mut self.cases, None),
+            "timeout" => (&mut self.timeout, None),
+            "max_shrink_iters" => (&mut self.max_shrink_iters, None),
+            "max_shrink_time" => (&mut self.max_shrink_time, None),
+            "max_local_rejects" => (&mut self.max_local_rejects, None),
+            "max_global_rejects" => (&mut self.max_global_rejects, None),
+            "max_flat_map_regens" => (&mut self.max_flat_map_regens, None),
+            "verbose" => (&mut self.verbose, None),
+            "small_values" => (&mut self.small_values, None),
+            "threads" => (&mut self.threads, Some("thread")),
+            "worker_threads" => (&mut self.worker_threads, Some("thread")),
+            "continue_on_failure" => (&mut self.continue_on_failure, Some("failure")),
+            "iai_inputs" => (&mut self.iai_inputs, Some("input")),
+            _ => return None,
+        })
+    }
+
+    /// Get the path argument with the given name.
+    fn path_mut(&mut self, name: &str) -> Option<&mut Option<Path>> {
+        Some(match name {
+            "crate" => &mut self.krate,
+            "before_all" => &mut self.before_all,
+            "before_each" => &mut self.before_each,
+            "after_each" => &mut self.after_each,
+            "postcondition" => &mut self.postcondition,
+            "format_failure" => &mut self.format_failure,
+            _ => return None,
+        })
+    }
+
+    /// Get the string argument with the given name.
+    fn lit_str_mut(&mut self, name: &str) -> Option<&mut Option<LitStr>> {
+        Some(match name {
+            "stats_out" => &mut self.stats_out,
+            "json_report" => &mut self.json_report,
+            "corpus" => &mut self.corpus,
+            "regressions" => &mut self.regressions,
+            _ => return None,
+        })
+    }
+
+    /// Get the expression argument with the given name.
+    fn expr_mut(&mut self, name: &str) -> Option<&mut Option<SpannedExpr>> {
+        Some(match name {
+            "config" => &mut self.config,
+            "runner" => &mut self.runner,
+            "classify" => &mut self.classify,
+            "filter" => &mut self.filter,
+            "rng_bytes" => &mut self.rng_bytes,
+            "on_complete" => &mut self.on_complete,
+            _ => return None,
+        })
+    }
+}
+
+/// Store the value of an argument, failing if the argument was already specified.
+fn set_once<T>(slot: &mut Option<T>, value: T, arg: &Arg, name: &str) -> syn::Result<()> {
+    if slot.is_some() {
+        return Err(Error::new_spanned(
+            arg,
+            format!("multiple `{}` arguments are not allowed", name),
+        ));
+    }
+    *slot = Some(value);
+    Ok(())
+}
+
+fn parse_u32(name: &str, value: &Expr) -> syn::Result<u32> {
+    if let Expr::Lit(ExprLit {
+        lit: Lit::Int(int), ..
+    }) = value
+    {
+        int.base10_parse()
+            .map_err(|err| Error::new_spanned(int, format!("invalid {}: {}", name, err)))
+    } else {
+        Err(Error::new_spanned(
+            value,
+            format!("invalid {}: must be an integer literal", name),
+        ))
+    }
+}
+
+fn parse_bool(name: &str, value: &Expr) -> syn::Result<bool> {
+    if let Expr::Lit(ExprLit {
+        lit: Lit::Bool(value),
+        ..
+    }) = value
+    {
+        Ok(value.value)
+    } else {
+        Err(Error::new_spanned(
+            value,
+            format!("invalid {}: must be a boolean literal", name),
+        ))
+    }
+}
+
+/// Parse the name of a generated function: `true` for the default name, `false` for no function
+/// or a string with the name.
+fn parse_fn_name(name: &str, value: &Expr) -> syn::Result<Option<FnName>> {
+    match value {
+        Expr::Lit(ExprLit {
+            lit: Lit::Str(lit), ..
+        }) => Ok(Some(FnName::Named(lit.parse()?))),
+        Expr::Lit(ExprLit {
+            lit: Lit::Bool(value),
+            ..
+        }) => Ok(if value.value {
+            Some(FnName::Default)
+        } else {
+            None
+        }),
+        _ => Err(Error::new_spanned(
+            value,
+            format!("invalid {}: must be a string or boolean literal", name),
+        )),
+    }
+}
+
+fn parse_lit_str(name: &str, value: &Expr) -> syn::Result<LitStr> {
+    match value {
+        Expr::Lit(ExprLit {
+            lit: Lit::Str(lit), ..
+        }) => Ok(lit.clone()),
+        _ => Err(Error::new_spanned(
+            value,
+            format!("invalid {}: must be a string literal", name),
+        )),
+    }
+}
+
+/// Parse a path written either as is or enclosed in quotes.
+pub(crate) fn parse_path(name: &str, value: &Expr) -> syn::Result<Path> {
+    match value {
+        Expr::Lit(ExprLit {
+            lit: Lit::Str(lit), ..
+        }) => lit.parse().map_err(|err| {
+            Error::new_spanned(lit, format!("{} is not a valid path: {}", name, err))
+        }),
+        Expr::Path(ExprPath {
+            qself: None, path, ..
+        }) => Ok(path.clone()),
+        _ => Err(Error::new_spanned(
+            value,
+            format!("invalid {}: must be a path", name),
+        )),
+    }
+}
+
+/// Parse a tuple of types written either as is or enclosed in quotes. A single type is also
+/// accepted.
+fn parse_types(name: &str, value: &Expr) -> syn::Result<Vec<Type>> {
+    let ty = match value {
+        Expr::Lit(ExprLit {
+            lit: Lit::Str(lit), ..
+        }) => lit.parse(),
+        value => syn::parse2(value.to_token_stream()),
+    }
+    .map_err(|err| Error::new_spanned(value, format!("{} is not a valid type: {}", name, err)))?;
+
+    match ty {
+        Type::Tuple(tuple) if tuple.elems.is_empty() => Err(Error::new_spanned(
+            tuple,
+            format!("invalid {}: at least one type is required", name),
+        )),
+        Type::Tuple(tuple) => Ok(tuple.elems.into_iter().collect()),
+        ty => Ok(vec![ty]),
+    }
+}
+
+/// Parse a list of constants written either as is or enclosed in quotes, e.g. `[1, 8, 64]`.
+fn parse_consts(name: &str, value: &Expr) -> syn::Result<Vec<Expr>> {
+    let list = SpannedExpr::from_expr(name, value)?;
+    match list.expr {
+        Expr::Array(array) if array.elems.is_empty() => Err(Error::new(
+            list.span,
+            format!("invalid {}: at least one constant is required", name),
+        )),
+        Expr::Array(array) => Ok(array.elems.into_iter().collect()),
+        _ => Err(Error::new(
+            list.span,
+            format!(
+                "invalid {}: expected a list of constants, e.g. `[1, 8, 64]`",
+                name
+            ),
+        )),
+    }
+}
+
+/// Parse the contents of an attribute (e.g. `tokio::test` or `ignore = "reason"`) from a string.
+fn parse_meta(name: &str, value: &Expr) -> syn::Result<Meta> {
+    match value {
+        Expr::Lit(ExprLit {
+            lit: Lit::Str(lit), ..
+        }) => lit.parse().map_err(|err| {
+            Error::new_spanned(lit, format!("{} is not a valid attribute: {}", name, err))
+        }),
+        _ => Err(Error::new_spanned(
+            value,
+            format!("invalid {}: must be a string literal", name),
+        )),
+    }
+}
@@ -0,0 +1,138 @@
+//! Support for `async` test functions.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Error, Expr, ExprLit, Lit, Path};
+
+/// The runtime used to drive `async` test functions.
+pub(crate) enum AsyncRuntime {
+    /// A `tokio` runtime, shared by all cases of a test unless `runtime_per_case` is given.
+    Tokio(Tokio),
+    /// `async_std::task::block_on`.
+    AsyncStd,
+    /// A user-provided `block_on` function.
+    Custom(Path),
+}
+
+/// Options of the `tokio` runtime, mirroring the ones of `#[tokio::test]`.
+#[derive(Default)]
+pub(crate) struct Tokio {
+    pub(crate) flavor: Option<Flavor>,
+    pub(crate) worker_threads: Option<u32>,
+    pub(crate) start_paused: bool,
+    pub(crate) per_case: bool,
+}
+
+/// The scheduler of the `tokio` runtime.
+#[derive(Clone, Copy, PartialEq)]
+pub(crate) enum Flavor {
+    CurrentThread,
+    MultiThread,
+}
+
+impl Flavor {
+    pub(crate) fn from_expr(value: &Expr) -> syn::Result<Self> {
+        match value {
+            Expr::Lit(ExprLit {
+                lit: Lit::Str(lit), ..
+            }) if lit.value() == "current_thread" => Ok(Flavor::CurrentThread),
+            Expr::Lit(ExprLit {
+                lit: Lit::Str(lit), ..
+            }) if lit.value() == "multi_thread" => Ok(Flavor::MultiThread),
+            _ => Err(Error::new_spanned(
+                value,
+                "invalid flavor: expected \"current_thread\" or \"multi_thread\"",
+            )),
+        }
+    }
+}
+
+impl Tokio {
+    /// The runtime is multi-threaded if requested explicitly or by the number of worker threads.
+    pub(crate) fn is_multi_thread(&self) -> bool {
+        match self.flavor {
+            Some(flavor) => flavor == Flavor::MultiThread,
+            None => self.worker_threads.is_some(),
+        }
+    }
+
+    /// An expression building the runtime.
+    fn build(&self) -> TokenStream {
+        let builder = if self.is_multi_thread() {
+            quote! { ::tokio::runtime::Builder::new_multi_thread() }
+        } else {
+            quote! { ::tokio::runtime::Builder::new_current_thread() }
+        };
+        let worker_threads = self.worker_threads.map(|threads| {
+            let threads = threads as usize;
+            quote! { .worker_threads(#threads) }
+        });
+        let start_paused = if self.start_paused {
+            quote! { .start_paused(true) }
+        } else {
+            quote! {}
+        };
+        quote! {
+            #builder
+                #worker_threads
+                #start_paused
+                .enable_all()
+                .build()
+                .expect("failed to build the tokio runtime")
+        }
+    }
+}
+
+impl AsyncRuntime {
+    pub(crate) fn from_expr(value: &Expr) -> syn::Result<Self> {
+        let error = || {
+            Error::new_spanned(
+                value,
+                "invalid async runtime: expected \"tokio\", \"async-std\" or a path to a \
+                 `block_on` function",
+            )
+        };
+
+        let lit = match value {
+            Expr::Lit(ExprLit {
+                lit: Lit::Str(lit), ..
+            }) => lit,
+            _ => return Err(error()),
+        };
+
+        match lit.value().as_str() {
+            "tokio" => Ok(AsyncRuntime::Tokio(Tokio::default())),
+            "async-std" => Ok(AsyncRuntime::AsyncStd),
+            _ => lit.parse().map(AsyncRuntime::Custom).map_err(|_| error()),
+        }
+    }
+
+    /// Statements executed once before running the test cases.
+    pub(crate) fn setup(&self) -> TokenStream {
+        match self {
+            AsyncRuntime::Tokio(tokio) if !tokio.per_case => {
+                let build = tokio.build();
+                quote! { let async_runtime = #build; }
+            }
+            AsyncRuntime::Tokio(_) | AsyncRuntime::AsyncStd | AsyncRuntime::Custom(_) => quote! {},
+        }
+    }
+
+    /// Drive `future` to completion and return its output.
+    pub(crate) fn block_on(&self, future: TokenStream) -> TokenStream {
+        match self {
+            AsyncRuntime::Tokio(tokio) if tokio.per_case => {
+                let build = tokio.build();
+                quote! {
+                    {
+                        let async_runtime = #build;
+                        async_runtime.block_on(#future)
+                    }
+                }
+            }
+            AsyncRuntime::Tokio(_) => quote! { async_runtime.block_on(#future) },
+            AsyncRuntime::AsyncStd => quote! { ::async_std::task::block_on(#future) },
+            AsyncRuntime::Custom(path) => quote! { #path(#future) },
+        }
+    }
+}
@@ -0,0 +1,178 @@
+//! Generation of Criterion and iai-callgrind benchmarks measuring the property with values from the
+//! test strategy.
+
+use crate::args::ProptestArgs;
+use crate::async_runtime::AsyncRuntime;
+use crate::inputs::Input;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, ToTokens};
+use syn::{Ident, ItemFn, Type, Visibility};
+
+/// The number of inputs the `iai` benchmark runs the property with by default.
+const DEFAULT_IAI_INPUTS: u32 = 32;
+
+/// A function taking `&mut Criterion` and benchmarking the property with the values generated by
+/// `strategy` from a fixed seed. `callee` is the property function if one is generated next to the
+/// test.
+pub(crate) fn bench(
+    args: &ProptestArgs,
+    input: &ItemFn,
+    inputs: &[Input],
+    strategy: &TokenStream,
+    name: &Ident,
+    callee: Option<&TokenStream>,
+) -> syn::Result<TokenStream> {
+    let krate = args.krate();
+    let (nested_property_fn, values_pat, call) = property_call(args, input, inputs, callee)?;
+    let async_setup = args.async_runtime.as_ref().map(AsyncRuntime::setup);
+
+    let attrs = crate::propagated_attrs(&input.attrs);
+    let vis = &input.vis;
+    let setup = &args.setup;
+    let bench_name = input.sig.ident.to_string();
+
+    Ok(quote! {
+        #(#attrs)*
+        #vis fn #name(criterion: &mut ::criterion::Criterion) {
+            #nested_property_fn
+
+            #setup
+            let strategy = #strategy;
+            // The same values are generated on every run, so that the results are comparable
+            let mut runner = #krate::test_runner::TestRunner::deterministic();
+            #async_setup
+            criterion.bench_function(#bench_name, |bencher| {
+                bencher.iter_batched(
+                    || {
+                        let tree = #krate::strategy::Strategy::new_tree(&strategy, &mut runner)
+                            .expect("failed to generate a value");
+                        #krate::strategy::ValueTree::current(&tree)
+                    },
+                    |#values_pat| #call,
+                    ::criterion::BatchSize::SmallInput,
+                )
+            });
+        }
+    })
+}
+
+/// An iai-callgrind library benchmark running the property with a fixed sample of values generated
+/// by `strategy` from a fixed seed. The values are generated by a separate function passed as the
+/// argument of the benchmark, so that generating them is not counted.
+pub(crate) fn iai(
+    args: &ProptestArgs,
+    input: &ItemFn,
+    inputs: &[Input],
+    strategy: &TokenStream,
+    name: &Ident,
+    callee: Option<&TokenStream>,
+) -> syn::Result<TokenStream> {
+    let krate = args.krate();
+    let (nested_property_fn, values_pat, call) = property_call(args, input, inputs, callee)?;
+    let async_setup = args.async_runtime.as_ref().map(AsyncRuntime::setup);
+
+    let value_ty = match values_pat.tys.as_slice() {
+        [ty] => quote! { #ty },
+        tys => quote! { ( #(#tys),* ) },
+    };
+    let values = match values_pat.values.as_slice() {
+        [value] => quote! { #value },
+        values => quote! { ( #(#values),* ) },
+    };
+
+    let attrs = crate::propagated_attrs(&input.attrs);
+    let inputs_attrs = crate::cfg_and_lint_attrs(&input.attrs);
+    let vis = &input.vis;
+    let setup = &args.setup;
+    let inputs_name = format_ident!("{}_inputs", name);
+    let count = args.iai_inputs.unwrap_or(DEFAULT_IAI_INPUTS) as usize;
+
+    Ok(quote! {
+        #(#inputs_attrs)*
+        #[doc(hidden)]
+        #vis fn #inputs_name() -> ::std::vec::Vec<#value_ty> {
+            #setup
+            let strategy = #strategy;
+            // The same values are generated on every run, so that the results are comparable
+            let mut runner = #krate::test_runner::TestRunner::deterministic();
+            (0..#count)
+                .map(|_| {
+                    let tree = #krate::strategy::Strategy::new_tree(&strategy, &mut runner)
+                        .expect("failed to generate a value");
+                    #krate::strategy::ValueTree::current(&tree)
+                })
+                .collect()
+        }
+
+        #(#attrs)*
+        #[::iai_callgrind::library_benchmark]
+        #[bench::inputs(#inputs_name())]
+        #vis fn #name(inputs: ::std::vec::Vec<#value_ty>) {
+            #nested_property_fn
+
+            #async_setup
+            for #values in inputs {
+                let _ = ::std::hint::black_box(#call);
+            }
+        }
+    })
+}
+
+/// The pattern binding the generated values to `arg0`, `arg1`, etc.
+pub(crate) struct ValuesPat<'a> {
+    values: Vec<Ident>,
+    tys: Vec<&'a Type>,
+}
+
+impl ToTokens for ValuesPat<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let values = &self.values;
+        let tys = &self.tys;
+        tokens.extend(match values.len() {
+            0 => quote! { (): () },
+            1 => quote! { #(#values)*: #(#tys)* },
+            _ => quote! { ( #(#values),* ): ( #(#tys),* ) },
+        });
+    }
+}
+
+/// The property function nested into the benchmark (or the AFL harness) if there is no `callee`
+/// generated next to the test, the pattern binding the generated values and the call of the
+/// property with them.
+pub(crate) fn property_call<'a>(
+    args: &ProptestArgs,
+    input: &ItemFn,
+    inputs: &'a [Input],
+    callee: Option<&TokenStream>,
+) -> syn::Result<(TokenStream, ValuesPat<'a>, TokenStream)> {
+    let (nested_property_fn, callee) = match callee {
+        Some(callee) => (quote! {}, callee.clone()),
+        None => {
+            let property_name = format_ident!("property");
+            let nested_input = ItemFn {
+                vis: Visibility::Inherited,
+                ..input.clone()
+            };
+            let property_fn = crate::property_fn_item(&nested_input, &property_name)?;
+            (property_fn, quote! { #property_name })
+        }
+    };
+
+    let values: Vec<_> = (0..inputs.len())
+        .map(|i| format_ident!("arg{}", i))
+        .collect();
+    let tys = inputs.iter().map(|input| &*input.ty).collect();
+
+    let call_args = inputs
+        .iter()
+        .zip(&values)
+        .map(|(input, value)| input.arg(value));
+    let call = quote! { #callee(#(#call_args),*) };
+    let call = match &args.async_runtime {
+        Some(runtime) => runtime.block_on(call),
+        None => call,
+    };
+    let call = crate::hooks(args, quote! { { #call } });
+
+    Ok((nested_property_fn, ValuesPat { values, tys }, call))
+}
@@ -0,0 +1,45 @@
+//! Running the property as a `bolero` harness with `engine = "bolero"`.
+
+use crate::args::ProptestArgs;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Run `test` (a closure taking the generated values and returning `TestCaseResult`) with
+/// `bolero::check!`. The input of the engine is used as the source of randomness for the
+/// strategy, so that `bolero` can mutate and shrink it like any other input.
+pub(crate) fn run(args: &ProptestArgs, test: TokenStream) -> TokenStream {
+    let krate = args.krate();
+    let iterations = args.cases.map(|cases| {
+        let cases = cases as usize;
+        quote! { .with_iterations(#cases) }
+    });
+    let rng = crate::pass_through::rng(&krate, quote! { input });
+    quote! {
+        // Engines catch panics of the harness, nothing observed after a panic is mutated by a case
+        let harness = ::std::panic::AssertUnwindSafe((&runner_settings, &strategy, #test));
+        ::bolero::check!()
+            #iterations
+            .for_each(|input: &[u8]| {
+                let (runner_settings, strategy, test) = &*harness;
+                let mut runner = #krate::test_runner::TestRunner::new_with_rng(
+                    ::core::clone::Clone::clone(*runner_settings),
+                    #rng,
+                );
+                // Inputs that cannot produce a value are not interesting, like rejected cases
+                let tree = match #krate::strategy::Strategy::new_tree(*strategy, &mut runner) {
+                    ::core::result::Result::Ok(tree) => tree,
+                    ::core::result::Result::Err(_) => return,
+                };
+                let value = #krate::strategy::ValueTree::current(&tree);
+                if let ::core::result::Result::Err(
+                    #krate::test_runner::TestCaseError::Fail(reason),
+                ) = test(value) {
+                    ::core::panic!(
+                        "Test failed: {}; input: {:?}",
+                        reason,
+                        #krate::strategy::ValueTree::current(&tree),
+                    );
+                }
+            });
+    }
+}
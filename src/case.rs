@@ -0,0 +1,122 @@
+//! The `proptest_case!` macro running an inline property written as a closure, for example inside
+//! an existing test.
+
+use crate::args::{Arg, ProptestArgs};
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, ToTokens};
+use syn::{
+    parse::{Parse, ParseStream},
+    parse_quote, Error, Expr, ExprClosure, FnArg, Ident, ItemFn, Pat, Token,
+};
+
+/// Arguments that configure the test function itself, which is not a test here.
+const TEST_ONLY_ARGS: &[&str] = &[
+    "test_attr",
+    "no_test_attr",
+    "ignore",
+    "ignore_if",
+    "target",
+    "serial",
+    "report",
+    "property_fn",
+    "fuzz_target",
+    "kani",
+    "bench",
+    "iai",
+    "iai_inputs",
+    "types",
+    "consts",
+];
+
+/// `proptest_case!([strategy,] [name = value, ...] |arg: Type, ...| body)`.
+pub(crate) struct Case {
+    args: Vec<Arg>,
+    closure: ExprClosure,
+}
+
+impl Parse for Case {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut args = Vec::new();
+        // The strategy can be given first without its name
+        let is_closure =
+            input.peek(Token![|]) || input.peek(Token![||]) || input.peek(Token![move]);
+        let is_named = input.peek(Ident) && input.peek2(Token![=]);
+        if !is_closure && !is_named {
+            let strategy: Expr = input.parse()?;
+            args.push(Arg {
+                name: Ident::new("strategy", Span::call_site()),
+                value: Some((Token![=](Span::call_site()), strategy)),
+            });
+            input.parse::<Token![,]>()?;
+        }
+        while !(input.peek(Token![|]) || input.peek(Token![||]) || input.peek(Token![move])) {
+            if !input.peek(Ident) {
+                return Err(input.error(
+                    "expected an argument or the property closure, the strategy can only be given \
+                     without its name as the first argument",
+                ));
+            }
+            args.push(input.parse()?);
+            input.parse::<Token![,]>()?;
+        }
+        let closure = input.parse()?;
+        input.parse::<Option<Token![,]>>()?;
+        Ok(Case { args, closure })
+    }
+}
+
+/// Generate a function named `proptest_case` from the closure the same way as `#[proptest]`
+/// does, and call it. Like any nested function, the property cannot capture local variables.
+pub(crate) fn expand(case: Case) -> syn::Result<TokenStream> {
+    let Case { mut args, closure } = case;
+    for arg in &args {
+        if let Some(name) = TEST_ONLY_ARGS.iter().find(|name| arg.name == name) {
+            return Err(Error::new_spanned(
+                arg,
+                format!("`{}` cannot be specified for `proptest_case!`", name),
+            ));
+        }
+    }
+    if let Some(capture) = &closure.capture {
+        return Err(Error::new_spanned(
+            capture,
+            "the property cannot capture local variables, remove `move`",
+        ));
+    }
+    args.push(Arg {
+        name: Ident::new("no_test_attr", Span::call_site()),
+        value: None,
+    });
+
+    let inputs = closure
+        .inputs
+        .iter()
+        .map(|input| match input {
+            Pat::Type(input) => Ok(FnArg::Typed(input.clone())),
+            input => Err(Error::new_spanned(
+                input,
+                "the arguments of the property must have types, e.g. `|value: u8|`",
+            )),
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+    let asyncness = &closure.asyncness;
+    let output = &closure.output;
+    let body = match &*closure.body {
+        Expr::Block(block) if block.attrs.is_empty() && block.label.is_none() => {
+            block.block.to_token_stream()
+        }
+        body => quote! { { #body } },
+    };
+    let item: ItemFn = parse_quote! {
+        #asyncness fn proptest_case(#(#inputs),*) #output #body
+    };
+
+    let args = ProptestArgs::parse(args)?;
+    let item = crate::expand(args, item)?;
+    Ok(quote! {
+        {
+            #item
+            proptest_case();
+        }
+    })
+}
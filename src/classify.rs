@@ -0,0 +1,52 @@
+//! Classification of the test cases given with the `classify` argument.
+
+use crate::args::SpannedExpr;
+use crate::inputs::Input;
+use proc_macro2::{Ident, TokenStream};
+use quote::{quote, quote_spanned};
+
+/// The variable holding the number of passed test cases for each label.
+pub(crate) fn setup() -> TokenStream {
+    quote! {
+        let classification = ::proptest_attr_runtime::Classification::new();
+    }
+}
+
+/// Compute the label of a test case before running `block` and count it if the case passes. The
+/// arguments are available by reference in the expression under their names in the signature.
+pub(crate) fn record(
+    classify: &SpannedExpr,
+    inputs: &[Input],
+    values: &[Ident],
+    block: TokenStream,
+) -> TokenStream {
+    let pats = inputs.iter().map(Input::ref_pat);
+    let tys = inputs.iter().map(|input| &input.ty);
+    let label = quote_spanned! {classify.span=>
+        ::std::string::ToString::to_string(&(#classify))
+    };
+    quote! {
+        {
+            let label = {
+                #(
+                    #[allow(unused_variables)]
+                    let #pats: &#tys = &#values;
+                )*
+                #label
+            };
+            let result = #block;
+            if result.is_ok() {
+                classification.record(label);
+            }
+            result
+        }
+    }
+}
+
+/// Print the share of the passed test cases for each label, most frequent first.
+pub(crate) fn report(test_name: &Ident) -> TokenStream {
+    let test_name = test_name.to_string();
+    quote! {
+        classification.report(#test_name);
+    }
+}
@@ -0,0 +1,69 @@
+//! Passing the context of the case to the last argument with `with_context = true`.
+
+use crate::inputs::is_arg_attr;
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, quote_spanned};
+use syn::{spanned::Spanned, Error, FnArg, Path, Type};
+
+/// The argument taking the context, the last of the `generated` ones. `span` is where a test
+/// without arguments is reported.
+pub(crate) fn take_argument(generated: &mut Vec<FnArg>, span: Span) -> syn::Result<FnArg> {
+    let arg = match generated.pop() {
+        Some(arg) => arg,
+        None => {
+            return Err(Error::new(
+                span,
+                "`with_context` requires the last argument to take the context, e.g. \
+                 `ctx: &Context`",
+            ))
+        }
+    };
+    if let FnArg::Typed(arg) = &arg {
+        if let Some(attr) = arg.attrs.iter().find(|attr| is_arg_attr(attr)) {
+            return Err(Error::new_spanned(
+                attr,
+                "the argument taking the context of `with_context` is not generated",
+            ));
+        }
+    }
+    Ok(arg)
+}
+
+/// The context of the case passed for `arg`, by reference if it takes a reference. The case is
+/// counted by `failure::count` before the property is called, and `seed` is the seed of the run.
+pub(crate) fn value(arg: &FnArg) -> TokenStream {
+    let (reference, span) = match arg {
+        FnArg::Typed(arg) => (matches!(*arg.ty, Type::Reference(_)), arg.ty.span()),
+        FnArg::Receiver(receiver) => (false, receiver.span()),
+    };
+    let reference = if reference { Some(quote! { & }) } else { None };
+    quote_spanned! {span=>
+        #reference ::proptest_attr_runtime::Context::new(
+            cases_run.load(::core::sync::atomic::Ordering::Relaxed) - 1,
+            ::core::option::Option::as_deref(&seed),
+        )
+    }
+}
+
+/// Reject the case if `block` unwinds with the rejection of `Context::reject`.
+pub(crate) fn catch_rejection(krate: &Path, block: TokenStream) -> TokenStream {
+    quote! {
+        {
+            match ::std::panic::catch_unwind(::std::panic::AssertUnwindSafe(|| #block)) {
+                ::core::result::Result::Ok(result) => result,
+                ::core::result::Result::Err(payload) => {
+                    match payload.downcast::<::proptest_attr_runtime::Rejection>() {
+                        ::core::result::Result::Ok(rejection) => ::core::result::Result::Err(
+                            #krate::test_runner::TestCaseError::Reject(
+                                ::core::convert::From::from((*rejection).into_reason()),
+                            ),
+                        ),
+                        ::core::result::Result::Err(payload) => {
+                            ::std::panic::resume_unwind(payload)
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,95 @@
+//! Running the files of a corpus directory given with `corpus = "..."` as test cases.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{LitStr, Path};
+
+/// Run `test` (a closure taking the generated values and returning `TestCaseResult`) with the
+/// values generated from each file in the `corpus` directory, in the order of their paths. The
+/// contents of a file are the source of randomness for `strategy`, like the inputs of a fuzzer.
+/// The runners of the cases take the configuration of the `runner` variable.
+pub(crate) fn run(krate: &Path, corpus: &LitStr, test: TokenStream) -> TokenStream {
+    let rng = crate::pass_through::rng(krate, quote! { &input });
+    quote! {
+        {
+            let dir = ::std::path::Path::new(::core::env!("CARGO_MANIFEST_DIR")).join(#corpus);
+            // A corpus that has not been created yet is empty
+            let mut paths: ::std::vec::Vec<_> = match ::std::fs::read_dir(&dir) {
+                ::core::result::Result::Ok(entries) => entries
+                    .map(|entry| match entry {
+                        ::core::result::Result::Ok(entry) => entry.path(),
+                        ::core::result::Result::Err(err) => ::core::panic!(
+                            "cannot read the corpus directory {}: {}",
+                            dir.display(),
+                            err,
+                        ),
+                    })
+                    .filter(|path| path.is_file())
+                    .collect(),
+                ::core::result::Result::Err(err)
+                    if err.kind() == ::std::io::ErrorKind::NotFound =>
+                {
+                    ::std::vec::Vec::new()
+                }
+                ::core::result::Result::Err(err) => ::core::panic!(
+                    "cannot read the corpus directory {}: {}",
+                    dir.display(),
+                    err,
+                ),
+            };
+            paths.sort();
+            let test = #test;
+            for path in paths {
+                let input = match ::std::fs::read(&path) {
+                    ::core::result::Result::Ok(input) => input,
+                    ::core::result::Result::Err(err) => ::core::panic!(
+                        "cannot read the corpus file {}: {}",
+                        path.display(),
+                        err,
+                    ),
+                };
+                let mut corpus_runner = #krate::test_runner::TestRunner::new_with_rng(
+                    ::core::clone::Clone::clone(runner.config()),
+                    #rng,
+                );
+                // Inputs that cannot produce a value are skipped, like rejected cases
+                let tree = #krate::strategy::Strategy::new_tree(&strategy, &mut corpus_runner);
+                let tree = match tree {
+                    ::core::result::Result::Ok(tree) => tree,
+                    ::core::result::Result::Err(_) => continue,
+                };
+                let value = #krate::strategy::ValueTree::current(&tree);
+                // Panics fail the case the same way they do in the runner
+                let result =
+                    ::std::panic::catch_unwind(::std::panic::AssertUnwindSafe(|| test(value)));
+                let reason = match result {
+                    ::core::result::Result::Ok(::core::result::Result::Err(
+                        #krate::test_runner::TestCaseError::Fail(reason),
+                    )) => ::std::string::ToString::to_string(&reason),
+                    ::core::result::Result::Ok(_) => continue,
+                    ::core::result::Result::Err(payload) => {
+                        match (
+                            payload.downcast_ref::<&str>(),
+                            payload.downcast_ref::<::std::string::String>(),
+                        ) {
+                            (::core::option::Option::Some(message), _) => {
+                                ::std::string::ToString::to_string(message)
+                            }
+                            (_, ::core::option::Option::Some(message)) => {
+                                ::core::clone::Clone::clone(message)
+                            }
+                            _ => ::std::string::String::from("<unknown panic value>"),
+                        }
+                    }
+                };
+                ::core::panic!(
+                    "Test failed: {}; corpus file: {}; input: {:?}",
+                    reason,
+                    path.display(),
+                    #krate::strategy::ValueTree::current(&tree),
+                );
+            }
+            test
+        }
+    }
+}
@@ -0,0 +1,119 @@
+//! Showing the code generated for a test with `debug_expand = true`.
+
+use proc_macro2::{Delimiter, TokenStream, TokenTree};
+use quote::ToTokens;
+use syn::{Error, File, Ident, Item};
+
+/// Attach the code generated for the test named `test_name` as documentation to the generated
+/// functions and modules, so that it can be seen on hover in an editor. When the crate has a build
+/// script, the code is also written to `$OUT_DIR/proptest-attr/<test name>.rs`.
+pub(crate) fn document(test_name: &Ident, expansion: TokenStream) -> syn::Result<TokenStream> {
+    let text = pretty(expansion.clone());
+    if let Some(out_dir) = std::env::var_os("OUT_DIR") {
+        let dir = std::path::Path::new(&out_dir).join("proptest-attr");
+        let path = dir.join(format!("{}.rs", test_name));
+        if let Err(err) = std::fs::create_dir_all(&dir).and_then(|()| std::fs::write(&path, &text))
+        {
+            return Err(Error::new(
+                test_name.span(),
+                format!("cannot write the expansion to {}: {}", path.display(), err),
+            ));
+        }
+    }
+
+    let doc = format!(
+        " The code generated by `#[proptest]`:\n\n```text\n{}```",
+        text
+    );
+    let mut file: File = syn::parse2(expansion)?;
+    for item in &mut file.items {
+        let attrs = match item {
+            Item::Fn(item) => &mut item.attrs,
+            Item::Mod(item) => &mut item.attrs,
+            _ => continue,
+        };
+        attrs.push(syn::parse_quote! { #[doc = #doc] });
+    }
+    Ok(file.into_token_stream())
+}
+
+/// Write `tokens` with a statement or an item per line and the contents of braces indented, which
+/// is enough to follow the generated code without a formatter.
+fn pretty(tokens: TokenStream) -> String {
+    let mut text = String::new();
+    write_tokens(&mut text, tokens, 0);
+    text
+}
+
+fn write_tokens(text: &mut String, tokens: TokenStream, indent: usize) {
+    // Tokens between the line breaks are written by `TokenStream`, which keeps `::` and `->` intact
+    let mut line = Vec::new();
+    for token in tokens {
+        match &token {
+            TokenTree::Group(group) if group.delimiter() == Delimiter::Brace => {
+                flush(text, &mut line);
+                let inner = group.stream();
+                if inner.is_empty() {
+                    text.push_str(" {}");
+                } else {
+                    text.push_str(" {");
+                    new_line(text, indent + 1);
+                    write_tokens(text, inner, indent + 1);
+                    new_line(text, indent);
+                    text.push('}');
+                }
+                new_line(text, indent);
+            }
+            TokenTree::Punct(punct) if punct.as_char() == ';' => {
+                flush(text, &mut line);
+                // Also after a block, as in `let value = { ... };`
+                text.truncate(text.trim_end().len());
+                text.push(';');
+                new_line(text, indent);
+            }
+            TokenTree::Punct(punct) if punct.as_char() == ',' && line.is_empty() => {
+                text.truncate(text.trim_end().len());
+                text.push(',');
+                new_line(text, indent);
+            }
+            // An attribute
+            TokenTree::Group(group)
+                if group.delimiter() == Delimiter::Bracket
+                    && matches!(line.as_slice(), [TokenTree::Punct(punct)] if punct.as_char() == '#') =>
+            {
+                line.push(token);
+                flush(text, &mut line);
+                new_line(text, indent);
+            }
+            // `} else {`
+            TokenTree::Ident(ident) if ident == "else" && line.is_empty() => {
+                text.truncate(text.trim_end().len());
+                line.push(token);
+            }
+            _ => line.push(token),
+        }
+    }
+    flush(text, &mut line);
+}
+
+fn flush(text: &mut String, line: &mut Vec<TokenTree>) {
+    if line.is_empty() {
+        return;
+    }
+    if !text.is_empty() && !text.ends_with([' ', '\n'].as_ref()) {
+        text.push(' ');
+    }
+    // `TokenStream` wraps long lines itself
+    let tokens = line.drain(..).collect::<TokenStream>().to_string();
+    let tokens: Vec<_> = tokens.split('\n').map(str::trim_start).collect();
+    text.push_str(&tokens.join(" "));
+}
+
+fn new_line(text: &mut String, indent: usize) {
+    let len = text.trim_end_matches(' ').len();
+    text.truncate(len);
+    if !text.ends_with('\n') {
+        text.push('\n');
+    }
+    text.push_str(&"    ".repeat(indent));
+}
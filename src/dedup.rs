@@ -0,0 +1,75 @@
+//! Skipping the inputs that were already tested with `dedup = true`.
+
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use syn::Path;
+
+/// The variables holding the `Debug` representations of the inputs tested so far, the numbers of
+/// duplicates skipped and of the cases rejected in total, and whether a case has failed. `config`
+/// doubles the global rejects allowed by the configuration, the added half is left to the
+/// duplicates.
+pub(crate) fn setup() -> TokenStream {
+    quote! {
+        let tested_inputs =
+            ::core::cell::RefCell::new(::std::collections::HashSet::<::std::string::String>::new());
+        let duplicates = ::core::cell::Cell::new(0u32);
+        let rejects = ::core::cell::Cell::new(0u32);
+        let max_rejects = runner.config().max_global_rejects;
+        let max_duplicates = max_rejects / 2;
+        let failed = ::core::cell::Cell::new(false);
+    }
+}
+
+/// Reject the test case without running `block` if its `values` were already tested, so that it
+/// does not count as a case. Once the duplicates have used up their share of the global rejects,
+/// or when rejecting one more would reach the limit because the test has rejected cases too, they
+/// pass without running `block` instead, so that a test of fewer distinct inputs than cases still
+/// finishes. After the first failure, every case runs `block`, so that shrinking is not affected.
+pub(crate) fn skip_tested(krate: &Path, values: &[Ident], block: TokenStream) -> TokenStream {
+    let values = match values {
+        [value] => quote! { &#value },
+        values => quote! { ( #(&#values,)* ) },
+    };
+    quote! {
+        {
+            if failed.get() {
+                #block
+            } else if tested_inputs.borrow_mut().insert(::std::format!("{:?}", #values)) {
+                // Stays set if the case panics
+                failed.set(true);
+                let result = #block;
+                match &result {
+                    ::core::result::Result::Err(#krate::test_runner::TestCaseError::Fail(_)) => {}
+                    ::core::result::Result::Err(#krate::test_runner::TestCaseError::Reject(_)) => {
+                        rejects.set(rejects.get() + 1);
+                        failed.set(false);
+                    }
+                    ::core::result::Result::Ok(()) => failed.set(false),
+                }
+                result
+            } else if duplicates.get() < max_duplicates
+                && rejects.get() + 1 < max_rejects
+            {
+                duplicates.set(duplicates.get() + 1);
+                rejects.set(rejects.get() + 1);
+                ::core::result::Result::Err(#krate::test_runner::TestCaseError::reject(
+                    "the input was already tested",
+                ))
+            } else {
+                ::core::result::Result::Ok(())
+            }
+        }
+    }
+}
+
+/// The configuration of the runner with twice the global rejects allowed by `config`, so that
+/// the duplicates skipped do not count against the limit of the cases rejected by the test.
+pub(crate) fn config(config: TokenStream) -> TokenStream {
+    quote! {
+        {
+            let mut config = #config;
+            config.max_global_rejects = config.max_global_rejects.saturating_mul(2);
+            config
+        }
+    }
+}
@@ -0,0 +1,178 @@
+//! Describing what a test explores in its documentation with `document = true`.
+
+use crate::{args::ProptestArgs, inputs::Input, strategy::named_strategies};
+use quote::ToTokens;
+use syn::{parse_quote, Attribute, Type};
+
+/// The arguments describing the inputs rather than the configuration of the runner, which are
+/// listed with the inputs.
+const INPUT_ARGS: &[&str] = &[
+    "strategy",
+    "strategies",
+    "oneof",
+    "filter",
+    "example",
+    "document",
+    "debug_expand",
+];
+
+/// The documentation of a test listing the strategies of its `inputs`, the examples always tested
+/// and the configuration given with `args`, so that it shows up on hover in an editor and in
+/// `cargo doc`. A blank line separates it from the documentation already in `attrs`.
+pub(crate) fn document(
+    args: &ProptestArgs,
+    inputs: &[Input],
+    attrs: &[Attribute],
+) -> syn::Result<Vec<Attribute>> {
+    let mut lines = Vec::new();
+    if attrs.iter().any(|attr| attr.path.is_ident("doc")) {
+        lines.push(String::new());
+    }
+    lines.push(" # Property test".to_string());
+    lines.push(String::new());
+
+    let named = match &args.strategy {
+        Some(strategy) => named_strategies(strategy, inputs)?,
+        None => None,
+    };
+    let whole = match (&args.strategy, &named) {
+        (Some(strategy), None) => Some(strategy),
+        _ => None,
+    };
+    lines.push(" Inputs:".to_string());
+    lines.push(String::new());
+    let with_rng = args.with_rng.unwrap_or(false);
+    for (i, input) in inputs.iter().enumerate() {
+        let name = format!("`{}: {}`", tokens(&input.pat), ty(&input.ty));
+        if with_rng && i == inputs.len() - 1 {
+            lines.push(format!(
+                " - {}: the random number generator of `with_rng`",
+                name
+            ));
+            continue;
+        }
+        let strategy = match (
+            &input.strategy,
+            named.as_ref().and_then(|named| named[i].as_ref()),
+        ) {
+            _ if whole.is_some() => None,
+            (Some(strategy), _) | (None, Some(strategy)) => {
+                Some(format!("`{}`", tokens(&strategy.expr)))
+            }
+            (None, None) => Some(format!("`any::<{}>()`", ty(&input.ty))),
+        };
+        let mut parts: Vec<_> = strategy.into_iter().collect();
+        if let Some(map) = &input.map {
+            parts.push(format!("mapped with `{}`", tokens(&map.expr)));
+        }
+        for filter in &input.filters {
+            parts.push(format!("filtered by `{}`", tokens(&filter.expr)));
+        }
+        if let Some(shrinker) = &input.shrink_with {
+            parts.push(format!("shrunk with `{}`", tokens(&shrinker.expr)));
+        }
+        if input.no_shrink.is_some() {
+            parts.push("not shrunk".to_string());
+        }
+        if parts.is_empty() {
+            lines.push(format!(" - {}", name));
+        } else {
+            lines.push(format!(" - {}: {}", name, parts.join(", ")));
+        }
+    }
+
+    if let Some(strategy) = whole {
+        lines.push(String::new());
+        lines.push(format!(" Strategy: `{}`", tokens(&strategy.expr)));
+    }
+    if let Some(filter) = &args.filter {
+        lines.push(String::new());
+        lines.push(format!(" Filtered by: `{}`", tokens(&filter.expr)));
+    }
+    if !args.examples.is_empty() {
+        lines.push(String::new());
+        lines.push(" Always tested with:".to_string());
+        lines.push(String::new());
+        for example in &args.examples {
+            lines.push(format!(" - `{}`", tokens(&example.expr)));
+        }
+    }
+
+    let config: Vec<_> = args
+        .written
+        .iter()
+        .filter(|arg| !INPUT_ARGS.iter().any(|name| arg.name == name))
+        .map(|arg| format!("`{}`", tokens(arg)))
+        .collect();
+    if !config.is_empty() {
+        lines.push(String::new());
+        lines.push(format!(" Configuration: {}", config.join(", ")));
+    }
+
+    Ok(lines
+        .into_iter()
+        .map(|line| parse_quote! { #[doc = #line] })
+        .collect())
+}
+
+/// `tokens` written the way they usually are rather than with a space between every two tokens.
+fn tokens(tokens: impl ToTokens) -> String {
+    let text = tokens.to_token_stream().to_string();
+    let chars: Vec<_> = text.chars().collect();
+    let mut result = String::with_capacity(text.len());
+    for (i, &c) in chars.iter().enumerate() {
+        if c != ' ' || !joined(&result, &chars[i + 1..]) {
+            result.push(c);
+        }
+    }
+    result
+}
+
+/// Whether the tokens written so far in `before` and the ones `after` a space are usually written
+/// without the space between them.
+fn joined(before: &str, after: &[char]) -> bool {
+    let prev = before.chars().last();
+    let ident = |c: char| c.is_alphanumeric() || c == '_';
+    // A reference rather than `a & b`
+    let unary = |op: &str| {
+        before.ends_with(op)
+            && before[..before.len() - op.len()]
+                .trim_end()
+                .chars()
+                .last()
+                .is_none_or(|c| "(,[=:{|".contains(c))
+    };
+    match after {
+        [',' | ';' | '.' | ')' | ']' | '?', ..] => true,
+        [':', ':' | ' ', ..] => true,
+        ['(', ..] => prev.is_some_and(|c| ident(c) || c == '>' || c == '!'),
+        ['!', ..] => prev.is_some_and(ident),
+        ['[', ..] => prev == Some('!'),
+        // Closing the generic arguments of a path like `any::<u8>()`
+        ['>', ..] => before.matches("::<").count() > closing(before),
+        _ => {
+            matches!(prev, Some('(' | '[' | '.'))
+                || before.ends_with("::")
+                || before.ends_with("::<")
+                || unary("&")
+                || unary("!")
+                || unary("*")
+        }
+    }
+}
+
+/// A type written by [`tokens`], without spaces around the angle brackets, which are always
+/// generics in a type.
+fn ty(ty: &Type) -> String {
+    tokens(ty)
+        .replace(" <", "<")
+        .replace("< ", "<")
+        .replace(" >", ">")
+}
+
+/// The number of `>` closing generic arguments in `text`, not counting `->` and `=>`.
+fn closing(text: &str) -> usize {
+    text.match_indices('>')
+        .filter(|(i, _)| !text[..*i].ends_with(['-', '='].as_ref()))
+        .count()
+}
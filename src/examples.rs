@@ -0,0 +1,35 @@
+//! Running the values given with `example = ...` as test cases before the random ones.
+
+use crate::args::SpannedExpr;
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+use syn::Path;
+
+/// Run `test` with each of the `examples`, in the order they are given, with the `runner`
+/// variable. The examples are values of `value_type`, the type of the values of the strategy, and
+/// are neither filtered nor shrunk. Evaluates to the result of the first failing example.
+pub(crate) fn run(krate: &Path, examples: &[SpannedExpr], value_type: TokenStream) -> TokenStream {
+    // A value of a wrong type is reported at the example
+    let examples = examples.iter().map(|example| {
+        quote_spanned! {example.span=>
+            {
+                let example: #value_type = #example;
+                example
+            }
+        }
+    });
+    quote! {
+        {
+            let mut result = ::core::result::Result::Ok(());
+            for example in [#(#examples),*] {
+                if let ::core::result::Result::Err(error) =
+                    runner.run_one(#krate::strategy::Just(example), &test)
+                {
+                    result = ::core::result::Result::Err(error);
+                    break;
+                }
+            }
+            result
+        }
+    }
+}
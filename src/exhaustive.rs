@@ -0,0 +1,94 @@
+//! Running the smallest values of the strategy before the random ones with `exhaustive_small`.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Path;
+
+/// The number of values run when `small_values` is not given.
+pub(crate) const DEFAULT_SMALL_VALUES: u32 = 16;
+
+/// The maximum number of simplifications of a single value tree.
+const MAX_SIMPLIFICATIONS: usize = 64;
+
+/// Run `test` (a closure taking the generated values and returning `TestCaseResult`) against up
+/// to `values` distinct values closest to the minimal values of `strategy`, with the smallest
+/// ones first. The values are found by simplifying the value trees of a deterministic runner all
+/// the way down, and are compared by their `Debug` representation. Evaluates to the result of the
+/// `runner` variable running the cases, so that failures are shrunk and reported as usual.
+pub(crate) fn sweep(krate: &Path, values: u32) -> TokenStream {
+    let values = values as usize;
+    quote! {
+        {
+            let config = ::core::clone::Clone::clone(runner.config());
+            let deterministic = || {
+                #krate::test_runner::TestRunner::new_with_rng(
+                    ::core::clone::Clone::clone(&config),
+                    #krate::test_runner::TestRng::deterministic_rng(
+                        ::core::default::Default::default(),
+                    ),
+                )
+            };
+            let describe = |value| {
+                let mut text = #krate::std_facade::String::new();
+                let _ = ::core::fmt::Write::write_fmt(
+                    &mut text,
+                    ::core::format_args!("{:?}", value),
+                );
+                text
+            };
+
+            // The values each tree passes through while simplified, from the minimal one
+            let mut sweep_runner = deterministic();
+            let mut chains = #krate::std_facade::Vec::new();
+            for _ in 0..#values {
+                let mut chain = #krate::std_facade::Vec::new();
+                if let ::core::result::Result::Ok(mut tree) =
+                    #krate::strategy::Strategy::new_tree(&strategy, &mut sweep_runner)
+                {
+                    chain.push(describe(#krate::strategy::ValueTree::current(&tree)));
+                    while chain.len() <= #MAX_SIMPLIFICATIONS
+                        && #krate::strategy::ValueTree::simplify(&mut tree)
+                    {
+                        chain.push(describe(#krate::strategy::ValueTree::current(&tree)));
+                    }
+                    chain.reverse();
+                }
+                chains.push(chain);
+            }
+
+            // Take the values level by level, with the number of simplifications left to reach
+            // the minimal value as the level
+            let mut seen = #krate::std_facade::BTreeSet::new();
+            let mut cases = #krate::std_facade::Vec::new();
+            let levels = chains.iter().map(|chain| chain.len()).max().unwrap_or(0);
+            for level in 0..levels {
+                for (index, chain) in chains.iter().enumerate() {
+                    if cases.len() < #values && level < chain.len() && seen.insert(&chain[level]) {
+                        cases.push((index, chain.len() - 1 - level));
+                    }
+                }
+            }
+
+            // Trees cannot be cloned, so each case regenerates its tree from the same runner
+            let mut result = ::core::result::Result::Ok(());
+            for (index, simplifications) in cases {
+                let mut sweep_runner = deterministic();
+                for _ in 0..index {
+                    let _ = #krate::strategy::Strategy::new_tree(&strategy, &mut sweep_runner);
+                }
+                if let ::core::result::Result::Ok(mut tree) =
+                    #krate::strategy::Strategy::new_tree(&strategy, &mut sweep_runner)
+                {
+                    for _ in 0..simplifications {
+                        #krate::strategy::ValueTree::simplify(&mut tree);
+                    }
+                    if let ::core::result::Result::Err(error) = runner.run_one(tree, &test) {
+                        result = ::core::result::Result::Err(error);
+                        break;
+                    }
+                }
+            }
+            result
+        }
+    }
+}
@@ -0,0 +1,149 @@
+//! Reporting of failed test runs with the context needed to reproduce them.
+
+use crate::{args::Report, location};
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, quote_spanned};
+use syn::spanned::Spanned;
+use syn::{Ident, Path};
+
+/// A test runner created from the `runner_settings` variable with a fresh seed taken from the
+/// configured RNG, together with the seed written the same way as the `seed` argument. Algorithms
+/// that cannot be seeded use the configured RNG as is and have no seed to report.
+pub(crate) fn seeded_runner(krate: &Path) -> TokenStream {
+    quote! {
+        {
+            let mut runner = #krate::test_runner::TestRunner::new(runner_settings);
+            let algorithm = runner.config().rng_algorithm;
+            let (prefix, len) = match algorithm {
+                #krate::test_runner::RngAlgorithm::XorShift => ("xs", 16),
+                #krate::test_runner::RngAlgorithm::ChaCha => ("cc", 32),
+                _ => ("", 0),
+            };
+            // The seed is generated like any other value to keep deterministic configurations
+            // deterministic
+            let seed = if len > 0 {
+                #krate::strategy::Strategy::new_tree(
+                    &#krate::arbitrary::any::<[u8; 32]>(),
+                    &mut runner,
+                )
+                .ok()
+                .map(|tree| #krate::strategy::ValueTree::current(&tree))
+            } else {
+                ::core::option::Option::None
+            };
+            match seed {
+                ::core::option::Option::Some(seed) => {
+                    let config = ::core::clone::Clone::clone(runner.config());
+                    let rng = #krate::test_runner::TestRng::from_seed(algorithm, &seed[..len]);
+                    let mut text = #krate::std_facade::String::from(prefix);
+                    text.push(' ');
+                    for byte in &seed[..len] {
+                        let _ = ::core::fmt::Write::write_fmt(
+                            &mut text,
+                            ::core::format_args!("{:02x}", byte),
+                        );
+                    }
+                    (
+                        #krate::test_runner::TestRunner::new_with_rng(config, rng),
+                        ::core::option::Option::Some(text),
+                    )
+                }
+                _ => (runner, ::core::option::Option::None),
+            }
+        }
+    }
+}
+
+/// The counter of the executed test cases.
+pub(crate) fn setup() -> TokenStream {
+    quote! {
+        let cases_run = ::core::sync::atomic::AtomicU32::new(0);
+    }
+}
+
+/// Count a test case before running `block`. `span` is the span of the block in errors.
+pub(crate) fn count(block: TokenStream, span: Span) -> TokenStream {
+    quote_spanned! {span=>
+        {
+            cases_run.fetch_add(1, ::core::sync::atomic::Ordering::Relaxed);
+            #block
+        }
+    }
+}
+
+/// Fail with the error of `result` (a `TestRunResult`), the number of executed cases and the
+/// `seed` (an `&Option<String>`) of the run. Cases run in subprocesses with `fork` are not counted.
+/// A failure with an input is written by `format_failure` if it is given. The failure is reported
+/// at the location of the panic failing the minimal case if it is `located`.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn report(
+    krate: &Path,
+    test_name: &Ident,
+    result: TokenStream,
+    fork: TokenStream,
+    seed: TokenStream,
+    report: Report,
+    format_failure: Option<&Path>,
+    located: bool,
+) -> TokenStream {
+    let test_name = test_name.to_string();
+    let fail = report.fail(quote! { message });
+    let resume = located.then(|| location::resume(krate));
+    let error = match format_failure {
+        Some(format_failure) => {
+            // A formatter of the wrong type is reported at its name
+            let message = quote_spanned!(format_failure.span()=> #format_failure(value, &error));
+            quote! {
+                match &error {
+                    #krate::test_runner::TestError::Fail(reason, value) => {
+                        let error = #krate::test_runner::TestCaseError::Fail(
+                            ::core::clone::Clone::clone(reason),
+                        );
+                        let _ = ::core::fmt::Write::write_fmt(
+                            &mut message,
+                            ::core::format_args!("{}\n", #message),
+                        );
+                    }
+                    error => {
+                        let _ = ::core::fmt::Write::write_fmt(
+                            &mut message,
+                            ::core::format_args!("{}\n", error),
+                        );
+                    }
+                }
+            }
+        }
+        None => quote! {
+            let _ = ::core::fmt::Write::write_fmt(
+                &mut message,
+                ::core::format_args!("{}\n", error),
+            );
+        },
+    };
+    quote! {
+        if let ::core::result::Result::Err(error) = #result {
+            let mut message = #krate::std_facade::String::new();
+            #error
+            if !#fork {
+                let cases_run = cases_run.load(::core::sync::atomic::Ordering::Relaxed);
+                let _ = ::core::fmt::Write::write_fmt(
+                    &mut message,
+                    ::core::format_args!("cases executed: {}\n", cases_run),
+                );
+            }
+            if let ::core::option::Option::Some(seed) = #seed {
+                let _ = ::core::fmt::Write::write_fmt(
+                    &mut message,
+                    ::core::format_args!(
+                        "seed: {}\nto reproduce, add `seed = \"{}\"` to the attribute of `{}`\n",
+                        seed,
+                        seed,
+                        #test_name,
+                    ),
+                );
+            }
+            #resume
+            #fail
+        }
+    }
+}
@@ -0,0 +1,85 @@
+//! Negative tests with `falsify = true`, which pass only if the property fails for some input.
+
+use crate::args::Report;
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use syn::Path;
+
+/// The variable holding the first input the property held for.
+pub(crate) fn setup() -> TokenStream {
+    quote! {
+        let sample = ::std::sync::Mutex::new(::core::option::Option::<::std::string::String>::None);
+    }
+}
+
+/// Keep the `values` of the test case as the sample if the property holds for them and there is
+/// no sample yet.
+pub(crate) fn record(values: &[Ident], block: TokenStream) -> TokenStream {
+    let values = match values {
+        [value] => quote! { &#value },
+        values => quote! { ( #(&#values,)* ) },
+    };
+    quote! {
+        {
+            let has_sample = sample
+                .lock()
+                .unwrap_or_else(::std::sync::PoisonError::into_inner)
+                .is_some();
+            let input = if has_sample {
+                ::core::option::Option::None
+            } else {
+                ::core::option::Option::Some(::std::format!("{:#?}", #values))
+            };
+            let result = #block;
+            if result.is_ok() {
+                let mut sample = sample.lock().unwrap_or_else(::std::sync::PoisonError::into_inner);
+                if sample.is_none() {
+                    *sample = input;
+                }
+            }
+            result
+        }
+    }
+}
+
+/// Fail unless `result` (a `TestRunResult`) is a failure, with the sample input the property held
+/// for.
+pub(crate) fn report(
+    krate: &Path,
+    test_name: &Ident,
+    result: TokenStream,
+    report: Report,
+) -> TokenStream {
+    let test_name = test_name.to_string();
+    let fail = report.fail(quote! { message });
+    quote! {
+        let message = match #result {
+            ::core::result::Result::Err(#krate::test_runner::TestError::Fail(..)) => {
+                ::core::option::Option::None
+            }
+            ::core::result::Result::Err(error) => {
+                ::core::option::Option::Some(::std::string::ToString::to_string(&error))
+            }
+            ::core::result::Result::Ok(()) => {
+                let cases_run = cases_run.load(::core::sync::atomic::Ordering::Relaxed);
+                let sample = sample.into_inner().unwrap_or_else(::std::sync::PoisonError::into_inner);
+                ::core::option::Option::Some(match sample {
+                    ::core::option::Option::Some(sample) => ::std::format!(
+                        "`{}` was expected to fail, but the property held for all {} cases.\n\
+                         sample satisfying input: {}",
+                        #test_name,
+                        cases_run,
+                        sample,
+                    ),
+                    ::core::option::Option::None => ::std::format!(
+                        "`{}` was expected to fail, but no cases were run",
+                        #test_name,
+                    ),
+                })
+            }
+        };
+        if let ::core::option::Option::Some(message) = message {
+            #fail
+        }
+    }
+}
@@ -0,0 +1,114 @@
+//! Generation of `cargo fuzz` entry points sharing the property with the test.
+
+use crate::args::ProptestArgs;
+use crate::async_runtime::AsyncRuntime;
+use crate::inputs::Input;
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{Ident, ItemFn, Visibility};
+
+/// A function taking the fuzzer input, converting it to the test arguments with `arbitrary` and
+/// checking the property. `callee` is the property function if one is generated next to the test.
+pub(crate) fn fuzz_target(
+    args: &ProptestArgs,
+    input: &ItemFn,
+    inputs: &[Input],
+    name: &Ident,
+    callee: Option<&TokenStream>,
+    unit_output: bool,
+) -> syn::Result<TokenStream> {
+    let krate = args.krate();
+
+    let (nested_property_fn, callee) = match callee {
+        Some(callee) => (quote! {}, callee.clone()),
+        None => {
+            let property_name = format_ident!("property");
+            let nested_input = ItemFn {
+                vis: Visibility::Inherited,
+                ..input.clone()
+            };
+            let property_fn = crate::property_fn_item(&nested_input, &property_name)?;
+            (property_fn, quote! { #property_name })
+        }
+    };
+
+    let values: Vec<_> = (0..inputs.len())
+        .map(|i| format_ident!("arg{}", i))
+        .collect();
+    let tys = inputs.iter().map(|input| &input.ty);
+
+    // Inputs not satisfying the filters are skipped, the same way the strategies reject them
+    let mut filters = Vec::new();
+    for (input, value) in inputs.iter().zip(&values) {
+        let pat = input.ref_pat();
+        filters.extend(input.filters.iter().map(|filter| {
+            quote! {
+                {
+                    #[allow(unused_variables)]
+                    let #pat = &#value;
+                    #filter
+                }
+            }
+        }));
+    }
+    if let Some(filter) = &args.filter {
+        let pats = inputs.iter().map(Input::ref_pat);
+        filters.push(quote! {
+            {
+                #[allow(unused_variables)]
+                let (#(#pats,)*) = (#(&#values,)*);
+                #filter
+            }
+        });
+    }
+
+    let call_args = inputs
+        .iter()
+        .zip(&values)
+        .map(|(input, value)| input.arg(value));
+    let call = quote! { #callee(#(#call_args),*) };
+    let call = match &args.async_runtime {
+        Some(runtime) => runtime.block_on(call),
+        None => call,
+    };
+    let async_setup = args.async_runtime.as_ref().map(AsyncRuntime::setup);
+    let check = if unit_output {
+        quote! { let () = #call; }
+    } else {
+        let result = crate::output::convert(call, Span::call_site());
+        let conversion_items = crate::output::conversion_items(&krate);
+        quote! {
+            #conversion_items
+            if let ::core::result::Result::Err(
+                #krate::test_runner::TestCaseError::Fail(reason)
+            ) = #result {
+                ::core::panic!("{}", reason);
+            }
+        }
+    };
+    let check = crate::hooks(args, check);
+
+    let attrs = crate::propagated_attrs(&input.attrs);
+    let vis = &input.vis;
+
+    Ok(quote! {
+        #(#attrs)*
+        #vis fn #name(data: &[u8]) {
+            #nested_property_fn
+
+            let (#(#values,)*): (#(#tys,)*) = match ::arbitrary::Arbitrary::arbitrary_take_rest(
+                ::arbitrary::Unstructured::new(data),
+            ) {
+                ::core::result::Result::Ok(values) => values,
+                ::core::result::Result::Err(_) => return,
+            };
+            #(
+                if !#filters {
+                    return;
+                }
+            )*
+            #async_setup
+            #check
+        }
+    })
+}
@@ -0,0 +1,210 @@
+//! Support for generic test functions instantiated with the types from the `types` argument or
+//! the constants from the `consts` argument.
+
+use crate::args::ProptestArgs;
+use proc_macro2::{Group, Span, TokenStream, TokenTree};
+use quote::{format_ident, quote, quote_spanned, ToTokens};
+use syn::spanned::Spanned;
+use syn::{parse_quote, Error, GenericParam, Generics, Ident, ItemFn, ReturnType, Visibility};
+
+/// The generic parameter of a test function.
+pub(crate) enum Param {
+    /// A type parameter, instantiated with the `types` argument.
+    Type(Ident),
+    /// A const parameter, instantiated with the `consts` argument.
+    Const(Ident),
+}
+
+/// The generic parameter of the test function, if any.
+pub(crate) fn param(generics: &Generics) -> syn::Result<Option<Param>> {
+    let mut params = generics.params.iter();
+    match (params.next(), params.next()) {
+        (None, _) => Ok(None),
+        (Some(GenericParam::Type(param)), None) => Ok(Some(Param::Type(param.ident.clone()))),
+        (Some(GenericParam::Const(param)), None) => Ok(Some(Param::Const(param.ident.clone()))),
+        _ => Err(Error::new_spanned(
+            generics,
+            "only a single generic type or const parameter is supported",
+        )),
+    }
+}
+
+/// Keep the generic function as is and generate a module with a test for each of the types or
+/// constants.
+pub(crate) fn expand(args: &ProptestArgs, input: ItemFn, param: Param) -> syn::Result<TokenStream> {
+    // The tests are placed in a module, so the paths relative to the module of the test function
+    // are written relative to the generated one, while the tests are named after the types as
+    // they are written
+    let nested_args = args
+        .written
+        .iter()
+        .map(|arg| crate::nested::nest(arg, None))
+        .collect::<syn::Result<_>>()?;
+    let nested_args = ProptestArgs::parse(nested_args)?;
+    // Constants are passed as blocks, which are valid both as generic arguments and expressions
+    let (param, instances) = match (param, &args.types, &args.consts) {
+        (Param::Type(param), Some(types), None) => {
+            let nested_types = nested_args.types.iter().flatten();
+            let instances = types
+                .iter()
+                .zip(nested_types)
+                .map(|(ty, nested_ty)| (test_ident(ty, None), quote! { #nested_ty }))
+                .collect::<Vec<_>>();
+            (param, instances)
+        }
+        (Param::Const(param), None, Some(consts)) => {
+            let nested_consts = nested_args.consts.iter().flatten();
+            let instances = consts
+                .iter()
+                .zip(nested_consts)
+                .map(|(value, nested_value)| {
+                    let instance = quote_spanned!(value.span()=> { #nested_value });
+                    (test_ident(value, Some(&param)), instance)
+                })
+                .collect::<Vec<_>>();
+            (param, instances)
+        }
+        (Param::Type(_), _, Some(_)) => {
+            return Err(Error::new_spanned(
+                &input.sig.generics,
+                "the `consts` argument requires a const generic parameter",
+            ))
+        }
+        (Param::Const(_), Some(_), _) => {
+            return Err(Error::new_spanned(
+                &input.sig.generics,
+                "the `types` argument requires a generic type parameter",
+            ))
+        }
+        (Param::Type(_), None, _) => {
+            return Err(Error::new_spanned(
+                &input.sig.generics,
+                "generic test functions require the `types` argument",
+            ))
+        }
+        (Param::Const(_), _, None) => {
+            return Err(Error::new_spanned(
+                &input.sig.generics,
+                "test functions with a const parameter require the `consts` argument",
+            ))
+        }
+    };
+    if args.property_fn.is_some() {
+        return Err(Error::new_spanned(
+            &input.sig.generics,
+            "`property_fn` cannot be used with generic test functions, which are always callable",
+        ));
+    }
+    if args.fuzz_target.is_some()
+        || args.kani.is_some()
+        || args.bench.is_some()
+        || args.iai.is_some()
+    {
+        return Err(Error::new_spanned(
+            &input.sig.generics,
+            "`fuzz_target`, `kani`, `bench` and `iai` cannot be used with generic test functions",
+        ));
+    }
+
+    let name = &input.sig.ident;
+    let vis = &input.vis;
+    // The tests are placed in a module, so they need a visibility to be seen where the original
+    // function would be
+    let test_vis = match vis {
+        Visibility::Inherited => parse_quote! { pub(super) },
+        vis => vis.clone(),
+    };
+
+    let mut tests = Vec::with_capacity(instances.len());
+    let mut test_names = Vec::<Ident>::with_capacity(instances.len());
+    for (test_name, ty) in instances {
+        if test_names.contains(&test_name) {
+            let message = match &args.types {
+                Some(_) => "duplicate type in `types`",
+                None => "duplicate constant in `consts`",
+            };
+            return Err(Error::new_spanned(ty, message));
+        }
+
+        let mut test = input.clone();
+        test.vis = test_vis.clone();
+        test.sig.ident = test_name.clone();
+        test.sig.generics = Generics::default();
+        // Per-argument strategies may refer to the type parameter as well
+        for arg in &mut test.sig.inputs {
+            *arg = substitute(&crate::nested::nest(&*arg, None)?, &param, &ty)?;
+        }
+        if let ReturnType::Type(_, output) = &mut test.sig.output {
+            **output = substitute(&crate::nested::nest(&**output, None)?, &param, &ty)?;
+        }
+
+        tests.push(crate::expand_test(
+            &nested_args,
+            test,
+            Some(quote! { super::#name::<#ty> }),
+        )?);
+        test_names.push(test_name);
+    }
+
+    let property_fn = crate::property_fn_item(&input, name)?;
+    let module_attrs = crate::propagated_attrs(&input.attrs);
+
+    Ok(quote! {
+        #property_fn
+
+        #(#module_attrs)*
+        #vis mod #name {
+            #[allow(unused_imports)]
+            use super::*;
+
+            #(#tests)*
+        }
+    })
+}
+
+/// Replace the generic parameter with the concrete type or constant.
+fn substitute<T>(node: &T, param: &Ident, ty: &TokenStream) -> syn::Result<T>
+where
+    T: ToTokens + syn::parse::Parse,
+{
+    fn substitute_tokens(tokens: TokenStream, param: &Ident, ty: &TokenStream) -> TokenStream {
+        tokens
+            .into_iter()
+            .map(|token| match token {
+                TokenTree::Ident(ident) if ident == *param => quote! { #ty },
+                TokenTree::Group(group) => {
+                    let mut substituted = Group::new(
+                        group.delimiter(),
+                        substitute_tokens(group.stream(), param, ty),
+                    );
+                    substituted.set_span(group.span());
+                    TokenTree::Group(substituted).into()
+                }
+                token => token.into(),
+            })
+            .collect()
+    }
+
+    syn::parse2(substitute_tokens(node.to_token_stream(), param, ty))
+}
+
+/// A test name derived from the type, e.g. `vec_u8` for `Vec<u8>`, or from the constant and the
+/// name of its parameter, e.g. `n_64` for `64`.
+fn test_ident(ty: &impl ToTokens, param: Option<&Ident>) -> Ident {
+    let mut name = match param {
+        Some(param) => format!("{}_", param.to_string().to_ascii_lowercase()),
+        None => String::new(),
+    };
+    for c in ty.to_token_stream().to_string().chars() {
+        if c.is_ascii_alphanumeric() {
+            name.push(c.to_ascii_lowercase());
+        } else if !name.is_empty() && !name.ends_with('_') {
+            name.push('_');
+        }
+    }
+    let name = match name.trim_end_matches('_') {
+        "" => "unit",
+        name => name,
+    };
+    format_ident!("{}", name, span = Span::call_site())
+}
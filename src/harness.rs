@@ -0,0 +1,60 @@
+//! Test harness attributes placed on the test function below `#[proptest]`.
+
+use crate::args::Arg;
+use syn::{parse_quote_spanned, spanned::Spanned, Attribute, Error, Expr, ExprLit, ItemFn, Lit};
+
+/// Turn `#[tokio::test(...)]` and `#[async_std::test]` on `input` into the `async` argument, so
+/// that the cases run in the runtime of the generated test instead of the attribute wrapping the
+/// synchronous test in another runtime. The options of `#[tokio::test(...)]` are passed on as the
+/// arguments of the same names.
+pub(crate) fn extract_async(args: &mut Vec<Arg>, input: &mut ItemFn) -> syn::Result<()> {
+    let mut attrs = Vec::with_capacity(input.attrs.len());
+    for attr in std::mem::take(&mut input.attrs) {
+        let (name, runtime) = match async_runtime(&attr) {
+            Some(runtime) => runtime,
+            None => {
+                attrs.push(attr);
+                continue;
+            }
+        };
+        let given = args.iter().find(|arg| arg.name == "async");
+        match given.map(|arg| &arg.value) {
+            None => args.push(parse_quote_spanned!(attr.span()=> async = #runtime)),
+            Some(Some((
+                _,
+                Expr::Lit(ExprLit {
+                    lit: Lit::Str(lit), ..
+                }),
+            ))) if lit.value() == runtime => {}
+            Some(_) => {
+                return Err(Error::new_spanned(
+                    &attr,
+                    format!("`#[{}]` conflicts with the `async` argument", name),
+                ))
+            }
+        }
+        if !attr.tokens.is_empty() {
+            args.extend(attr.parse_args_with(crate::args::parse_args)?);
+        }
+    }
+    input.attrs = attrs;
+    Ok(())
+}
+
+/// The name of `attr` and the `async` argument equivalent to it, if it is the test attribute of an
+/// async runtime.
+fn async_runtime(attr: &Attribute) -> Option<(&'static str, &'static str)> {
+    let segments: Vec<_> = attr
+        .path
+        .segments
+        .iter()
+        .map(|segment| &segment.ident)
+        .collect();
+    match segments.as_slice() {
+        [krate, name] if *krate == "tokio" && *name == "test" => Some(("tokio::test", "tokio")),
+        [krate, name] if *krate == "async_std" && *name == "test" => {
+            Some(("async_std::test", "async-std"))
+        }
+        _ => None,
+    }
+}
@@ -0,0 +1,125 @@
+//! `#[proptest]` associated functions in an `impl` block marked with `#[proptest_config]`.
+
+use crate::args::{self, Arg, ProptestArgs};
+use crate::module_config;
+use crate::nested;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{parse_quote, Error, ImplItem, ItemFn, ItemImpl, Type};
+
+/// Keep the associated functions in the `impl` block and generate their tests in a module next
+/// to it, named after the type (e.g. `parser_proptest` for `Parser`).
+pub(crate) fn expand(defaults: &[Arg], mut input: ItemImpl) -> syn::Result<TokenStream> {
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &input.generics,
+            "generic `impl` blocks are not supported by `proptest_config`",
+        ));
+    }
+    if let Some((_, trait_, _)) = &input.trait_ {
+        return Err(Error::new_spanned(
+            trait_,
+            "`proptest_config` must be applied to an inherent `impl` block",
+        ));
+    }
+    let self_ty = &input.self_ty;
+    let type_name = match &**self_ty {
+        Type::Path(path) if path.qself.is_none() => match path.path.segments.last() {
+            Some(segment) => segment.ident.to_string(),
+            None => unreachable!("paths have at least one segment"),
+        },
+        ty => {
+            return Err(Error::new_spanned(
+                ty,
+                "`proptest_config` can only be applied to `impl` blocks of named types",
+            ))
+        }
+    };
+
+    let nested_ty = nested::nest(&**self_ty, None)?;
+    let mut tests = Vec::new();
+    for item in &mut input.items {
+        let method = match item {
+            ImplItem::Method(method) => method,
+            _ => continue,
+        };
+        let mut attr = match method
+            .attrs
+            .iter()
+            .position(|attr| module_config::is_attr(attr, "proptest"))
+        {
+            Some(i) => method.attrs.remove(i),
+            None => continue,
+        };
+
+        module_config::merge(&mut attr, defaults)?;
+        let args = if attr.tokens.is_empty() {
+            Vec::new()
+        } else {
+            attr.parse_args_with(args::parse_args)?
+        };
+        // The tests are in a module next to the `impl` block, where `Self` is not defined
+        let args = args
+            .iter()
+            .map(|arg| nested::nest(arg, Some(self_ty)))
+            .collect::<syn::Result<_>>()?;
+        let args = ProptestArgs::parse(args)?;
+        if !method.sig.generics.params.is_empty() {
+            return Err(Error::new_spanned(
+                &method.sig.generics,
+                "generic associated functions are not supported by `proptest`",
+            ));
+        }
+        if args.property_fn.is_some()
+            || args.fuzz_target.is_some()
+            || args.kani.is_some()
+            || args.bench.is_some()
+            || args.iai.is_some()
+            || args.afl.is_some()
+        {
+            return Err(Error::new_spanned(
+                &attr,
+                "`property_fn`, `fuzz_target`, `kani`, `bench`, `iai` and `afl` cannot be used \
+                 with associated functions",
+            ));
+        }
+
+        let test = ItemFn {
+            attrs: method.attrs.clone(),
+            // Visible where the `impl` block is
+            vis: parse_quote! { pub(super) },
+            sig: nested::nest(&method.sig, Some(self_ty))?,
+            block: Box::new(method.block.clone()),
+        };
+        let name = &method.sig.ident;
+        let callee = quote! { <#nested_ty>::#name };
+        tests.push(if args.strategies.is_some() {
+            crate::matrix::tests(args, test, callee)?
+        } else {
+            crate::expand_test(&args, test, Some(callee))?
+        });
+
+        // The attributes of the test (e.g. `#[should_panic]`) are moved to the generated test
+        method.attrs.retain(crate::is_propagated);
+        crate::state_machine::strip_arg_attrs(method);
+        // The function may only be used by its test
+        method
+            .attrs
+            .push(parse_quote! { #[cfg_attr(not(test), allow(dead_code))] });
+    }
+
+    let module = format_ident!("{}_proptest", crate::state_machine::snake_case(&type_name));
+    let module_attrs = crate::cfg_and_lint_attrs(&input.attrs);
+    Ok(quote! {
+        #input
+
+        #(#module_attrs)*
+        #[cfg(any(test, trybuild))]
+        mod #module {
+            #[allow(unused_imports)]
+            use super::*;
+
+            #(#tests)*
+        }
+    })
+}
@@ -0,0 +1,430 @@
+//! Processing of the test function arguments and their attributes.
+
+use crate::args::SpannedExpr;
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+use syn::{
+    parse_quote, parse_quote_spanned, spanned::Spanned, Attribute, Error, Expr, FnArg,
+    GenericArgument, Ident, Pat, PatType, Path, PathArguments, RangeLimits, Type,
+};
+
+/// A single argument of the test function.
+pub(crate) struct Input {
+    pub(crate) pat: Box<Pat>,
+    pub(crate) ty: Box<Type>,
+    /// Strategy given with `#[strategy(...)]` on this argument, or made from `#[range(...)]` and
+    /// `#[len(...)]`.
+    pub(crate) strategy: Option<SpannedExpr>,
+    /// Function given with `#[map(...)]` on this argument, applied to the values of the strategy.
+    pub(crate) map: Option<SpannedExpr>,
+    /// Conditions given with `#[filter(...)]` on this argument.
+    pub(crate) filters: Vec<SpannedExpr>,
+    /// `#[no_shrink]` on this argument.
+    pub(crate) no_shrink: Option<Attribute>,
+    /// Function given with `#[shrink_with(...)]` on this argument, shrinking its values instead of
+    /// the strategy.
+    pub(crate) shrink_with: Option<SpannedExpr>,
+    /// Other attributes, such as `#[allow(unused_mut)]`, kept on the rebinding of the argument.
+    pub(crate) attrs: Vec<Attribute>,
+    /// Whether a reference to the generated value is passed as the argument, see
+    /// [`Input::own_borrowed`].
+    pub(crate) borrowed: bool,
+}
+
+impl Input {
+    /// The argument `arg` with the strategy and the other properties given with its attributes.
+    /// `krate` is the path to `proptest` in the strategies made from `#[range]` and `#[len]`.
+    pub(crate) fn from_fn_arg(arg: FnArg, krate: &Path) -> syn::Result<Self> {
+        let PatType { attrs, pat, ty, .. } = match arg {
+            FnArg::Typed(pat_type) => pat_type,
+            FnArg::Receiver(_) => {
+                return Err(Error::new(
+                    arg.span(),
+                    "receiver arguments are invalid in the testing context",
+                ))
+            }
+        };
+
+        let mut strategy = None;
+        let mut range = None;
+        let mut len = None;
+        let mut map = None;
+        let mut filters = Vec::new();
+        let mut no_shrink = None;
+        let mut shrink_with = None;
+        let mut other_attrs = Vec::new();
+
+        for attr in attrs {
+            if attr.path.is_ident("strategy") {
+                if strategy.is_some() {
+                    return Err(Error::new_spanned(
+                        attr,
+                        "multiple strategies are not allowed",
+                    ));
+                }
+                strategy = Some(parse_expr_attr("strategy", &attr)?);
+            } else if attr.path.is_ident("range") {
+                if range.is_some() {
+                    return Err(Error::new_spanned(
+                        attr,
+                        "multiple `#[range]` attributes are not allowed",
+                    ));
+                }
+                let expr = parse_expr_attr("range", &attr)?;
+                let bounded = match &expr.expr {
+                    Expr::Range(range) => range.from.is_some() || range.to.is_some(),
+                    _ => false,
+                };
+                if !bounded {
+                    return Err(Error::new(
+                        expr.span,
+                        "invalid range: must be a range expression, e.g. `#[range(0..10)]`",
+                    ));
+                }
+                range = Some(expr);
+            } else if attr.path.is_ident("len") {
+                if len.is_some() {
+                    return Err(Error::new_spanned(
+                        attr,
+                        "multiple `#[len]` attributes are not allowed",
+                    ));
+                }
+                len = Some(parse_expr_attr("len", &attr)?);
+            } else if attr.path.is_ident("map") {
+                if map.is_some() {
+                    return Err(Error::new_spanned(
+                        attr,
+                        "multiple `#[map]` attributes are not allowed",
+                    ));
+                }
+                map = Some(parse_expr_attr("map", &attr)?);
+            } else if attr.path.is_ident("filter") {
+                filters.push(parse_expr_attr("filter", &attr)?);
+            } else if attr.path.is_ident("no_shrink") {
+                if !attr.tokens.is_empty() {
+                    return Err(Error::new_spanned(
+                        &attr.tokens,
+                        "`#[no_shrink]` does not take arguments",
+                    ));
+                }
+                no_shrink = Some(attr);
+            } else if attr.path.is_ident("shrink_with") {
+                if shrink_with.is_some() {
+                    return Err(Error::new_spanned(
+                        attr,
+                        "multiple `#[shrink_with]` attributes are not allowed",
+                    ));
+                }
+                shrink_with = Some(parse_expr_attr("shrink_with", &attr)?);
+            } else {
+                other_attrs.push(attr);
+            }
+        }
+
+        if let (Some(_), Some(shorthand)) = (&strategy, range.as_ref().or(len.as_ref())) {
+            return Err(Error::new(
+                shorthand.span,
+                "`#[range]` and `#[len]` cannot be combined with `#[strategy]`",
+            ));
+        }
+        if let (Some(len), Some(_)) = (&len, &map) {
+            return Err(Error::new(
+                len.span,
+                "`#[len]` cannot be combined with `#[map]`",
+            ));
+        }
+
+        let strategy = match (strategy, len) {
+            (Some(strategy), _) => Some(strategy),
+            (None, Some(len)) => Some(len_strategy(krate, &ty, len, range)?),
+            // Without `#[map]` the range generates the values of the argument itself
+            (None, None) => match (range, &map) {
+                (Some(range), None) => Some(range_strategy(krate, &range, &ty)?),
+                (range, _) => range,
+            },
+        };
+
+        Ok(Input {
+            pat,
+            ty,
+            strategy,
+            map,
+            filters,
+            no_shrink,
+            shrink_with,
+            attrs: other_attrs,
+            borrowed: false,
+        })
+    }
+
+    /// Generate an owned value for an argument of type `&str` or `&[T]`, a `String` or a
+    /// `Vec<T>`, and pass a reference to it to the test instead. References with a lifetime, such
+    /// as `&'static str`, are generated as they are.
+    pub(crate) fn own_borrowed(&mut self, krate: &Path) {
+        let reference = match &*self.ty {
+            Type::Reference(reference)
+                if reference.lifetime.is_none() && reference.mutability.is_none() =>
+            {
+                reference
+            }
+            _ => return,
+        };
+        let span = self.ty.span();
+        let owned = match &*reference.elem {
+            Type::Path(path) if path.qself.is_none() && path.path.is_ident("str") => {
+                parse_quote_spanned!(span=> #krate::std_facade::String)
+            }
+            Type::Slice(slice) => {
+                let elem = &slice.elem;
+                parse_quote_spanned!(span=> #krate::std_facade::Vec<#elem>)
+            }
+            _ => return,
+        };
+        *self.ty = owned;
+        self.borrowed = true;
+    }
+
+    /// The argument passed to the test for the generated `value`.
+    pub(crate) fn arg(&self, value: &Ident) -> TokenStream {
+        if self.borrowed {
+            quote! { &#value }
+        } else {
+            quote! { #value }
+        }
+    }
+
+    /// The argument pattern suitable for binding a reference to the argument value.
+    pub(crate) fn ref_pat(&self) -> Pat {
+        let mut pat = (*self.pat).clone();
+        strip_binding_modes(&mut pat);
+        pat
+    }
+}
+
+/// Whether `attr` is one of the argument attributes processed by this crate.
+pub(crate) fn is_arg_attr(attr: &Attribute) -> bool {
+    is_strategy_attr(attr)
+        || attr.path.is_ident("map")
+        || attr.path.is_ident("filter")
+        || attr.path.is_ident("no_shrink")
+        || attr.path.is_ident("shrink_with")
+}
+
+/// Whether `attr` gives the strategy of the argument, so that it is generated rather than an
+/// `rstest` fixture.
+pub(crate) fn is_strategy_attr(attr: &Attribute) -> bool {
+    attr.path.is_ident("strategy") || attr.path.is_ident("range") || attr.path.is_ident("len")
+}
+
+/// The strategy of an argument of type `ty` with `#[len(...)]`, the strategy of
+/// `proptest::collection` for the type with the elements taken from the `range` given with
+/// `#[range(...)]`, if any.
+fn len_strategy(
+    krate: &Path,
+    ty: &Type,
+    len: SpannedExpr,
+    range: Option<SpannedExpr>,
+) -> syn::Result<SpannedExpr> {
+    let span = len.span;
+    let size = &len.expr;
+    let element = |ty: &Type| match &range {
+        Some(range) => range_strategy(krate, range, ty).map(|range| {
+            let range = &range.expr;
+            quote! { #range }
+        }),
+        None => Ok(quote! { #krate::arbitrary::any::<#ty>() }),
+    };
+    let (name, args) = match collection(ty) {
+        Some(collection) => collection,
+        None => {
+            return Err(Error::new(
+                span,
+                "`#[len]` requires an argument of a string or a collection type, such as \
+                 `String`, `Vec<T>` or `HashMap<K, V>`",
+            ))
+        }
+    };
+    let expr = match (name.as_str(), args.as_slice()) {
+        ("str" | "String", []) => {
+            let chars = element(&parse_quote!(char))?;
+            quote! {
+                #krate::strategy::Strategy::prop_map(
+                    #krate::collection::vec(#chars, #size),
+                    |chars| ::core::iter::Iterator::collect::<#krate::std_facade::String>(
+                        ::core::iter::IntoIterator::into_iter(chars),
+                    ),
+                )
+            }
+        }
+        (
+            "[]" | "Vec" | "VecDeque" | "LinkedList" | "BinaryHeap" | "HashSet" | "BTreeSet",
+            [elem],
+        ) => {
+            let function = match name.as_str() {
+                "[]" | "Vec" => "vec",
+                "VecDeque" => "vec_deque",
+                "LinkedList" => "linked_list",
+                "BinaryHeap" => "binary_heap",
+                "HashSet" => "hash_set",
+                _ => "btree_set",
+            };
+            let function = Ident::new(function, span);
+            let elem = element(elem)?;
+            quote! { #krate::collection::#function(#elem, #size) }
+        }
+        ("HashMap" | "BTreeMap", [key, value]) => {
+            if let Some(range) = &range {
+                return Err(Error::new(
+                    range.span,
+                    "`#[range]` cannot be combined with `#[len]` on a map",
+                ));
+            }
+            let function = match name.as_str() {
+                "HashMap" => "hash_map",
+                _ => "btree_map",
+            };
+            let function = Ident::new(function, span);
+            quote! {
+                #krate::collection::#function(
+                    #krate::arbitrary::any::<#key>(),
+                    #krate::arbitrary::any::<#value>(),
+                    #size,
+                )
+            }
+        }
+        _ => {
+            return Err(Error::new(
+                span,
+                "`#[len]` requires an argument of a string or a collection type, such as \
+                 `String`, `Vec<T>` or `HashMap<K, V>`",
+            ))
+        }
+    };
+    Ok(SpannedExpr {
+        expr: parse_quote_spanned!(span=> #expr),
+        span,
+    })
+}
+
+/// The strategy of the `range` given with `#[range(...)]` for values of type `ty`. The range is
+/// typed after `ty`, so that its bounds need no suffixes, and a range of `char` is generated with
+/// `proptest::char::range`, as ranges of `char` are not strategies.
+fn range_strategy(krate: &Path, range: &SpannedExpr, ty: &Type) -> syn::Result<SpannedExpr> {
+    let span = range.span;
+    let (expr, bounds) = match &range.expr {
+        Expr::Range(expr) => (expr, (&expr.from, &expr.to, &expr.limits)),
+        _ => unreachable!("`#[range]` is checked to be a range expression"),
+    };
+    let is_char =
+        matches!(ty, Type::Path(path) if path.qself.is_none() && path.path.is_ident("char"));
+    let expr =
+        match bounds {
+            (Some(from), Some(to), RangeLimits::Closed(_)) if is_char => {
+                quote_spanned!(span=> #krate::char::range(#from, #to))
+            }
+            _ if is_char => return Err(Error::new(
+                span,
+                "a range of `char` must be inclusive with both bounds, e.g. `#[range('a'..='z')]`",
+            )),
+            bounds => {
+                let range = match bounds {
+                    (Some(_), Some(_), RangeLimits::HalfOpen(_)) => "Range",
+                    (Some(_), Some(_), RangeLimits::Closed(_)) => "RangeInclusive",
+                    (Some(_), None, _) => "RangeFrom",
+                    (None, _, RangeLimits::HalfOpen(_)) => "RangeTo",
+                    (None, _, RangeLimits::Closed(_)) => "RangeToInclusive",
+                };
+                let range = Ident::new(range, span);
+                quote_spanned! {span=>
+                    {
+                        let range: ::core::ops::#range<#ty> = #expr;
+                        range
+                    }
+                }
+            }
+        };
+    Ok(SpannedExpr {
+        expr: parse_quote_spanned!(span=> #expr),
+        span,
+    })
+}
+
+/// The name of the string or collection type `ty` and the types of its generic arguments, with
+/// `"str"` and `"[]"` for the element types of `&str` and `&[T]`.
+fn collection(ty: &Type) -> Option<(String, Vec<&Type>)> {
+    match ty {
+        Type::Reference(reference) if reference.mutability.is_none() => match &*reference.elem {
+            Type::Path(path) if path.qself.is_none() && path.path.is_ident("str") => {
+                Some(("str".to_string(), Vec::new()))
+            }
+            Type::Slice(slice) => Some(("[]".to_string(), vec![&*slice.elem])),
+            _ => None,
+        },
+        Type::Path(path) if path.qself.is_none() => {
+            let segment = path.path.segments.last()?;
+            let args = match &segment.arguments {
+                PathArguments::None => Vec::new(),
+                PathArguments::AngleBracketed(args) => args
+                    .args
+                    .iter()
+                    .filter_map(|arg| match arg {
+                        GenericArgument::Type(ty) => Some(ty),
+                        _ => None,
+                    })
+                    .collect(),
+                PathArguments::Parenthesized(_) => return None,
+            };
+            Some((segment.ident.to_string(), args))
+        }
+        Type::Paren(paren) => collection(&paren.elem),
+        Type::Group(group) => collection(&group.elem),
+        _ => None,
+    }
+}
+
+/// A `let` statement binding `value` to the argument pattern. Arguments with attributes are
+/// passed as `value` and rebound in the body, because tuple patterns do not allow attributes.
+pub(crate) fn rebinding<'a>(
+    attrs: impl IntoIterator<Item = &'a Attribute>,
+    pat: &Pat,
+    value: &Ident,
+) -> TokenStream {
+    let attrs = attrs.into_iter();
+    quote! {
+        #(#attrs)*
+        let #pat = #value;
+    }
+}
+
+/// Remove `mut` and `ref` from the bindings of `pat`, including the nested ones. With a `mut`
+/// binding a pattern matched against a reference would move out of it instead of binding by
+/// reference, and a `ref` binding would add another reference.
+fn strip_binding_modes(pat: &mut Pat) {
+    match pat {
+        Pat::Ident(pat) => {
+            pat.by_ref = None;
+            pat.mutability = None;
+            if let Some((_, subpat)) = &mut pat.subpat {
+                strip_binding_modes(subpat);
+            }
+        }
+        Pat::Box(pat) => strip_binding_modes(&mut pat.pat),
+        Pat::Or(pat) => pat.cases.iter_mut().for_each(strip_binding_modes),
+        Pat::Reference(pat) => strip_binding_modes(&mut pat.pat),
+        Pat::Slice(pat) => pat.elems.iter_mut().for_each(strip_binding_modes),
+        Pat::Struct(pat) => pat
+            .fields
+            .iter_mut()
+            .for_each(|field| strip_binding_modes(&mut field.pat)),
+        Pat::Tuple(pat) => pat.elems.iter_mut().for_each(strip_binding_modes),
+        Pat::TupleStruct(pat) => pat.pat.elems.iter_mut().for_each(strip_binding_modes),
+        Pat::Type(pat) => strip_binding_modes(&mut pat.pat),
+        _ => {}
+    }
+}
+
+/// Parse an attribute of the form `#[name(expr)]` or `#[name("expr")]`.
+fn parse_expr_attr(name: &str, attr: &Attribute) -> syn::Result<SpannedExpr> {
+    let expr = attr.parse_args()?;
+    SpannedExpr::from_expr(name, &expr)
+}
@@ -0,0 +1,119 @@
+//! Generation of Kani proof harnesses checking the property for all values of the arguments.
+
+use crate::args::ProptestArgs;
+use crate::inputs::Input;
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{Expr, Ident, ItemFn, Visibility};
+
+/// A proof harness, compiled only by Kani, taking the arguments from `kani::any()` and checking the
+/// property. The filters and the range strategies are assumed, the other strategies are not used.
+/// `callee` is the property function if one is generated next to the test.
+pub(crate) fn proof(
+    args: &ProptestArgs,
+    input: &ItemFn,
+    inputs: &[Input],
+    name: &Ident,
+    callee: Option<&TokenStream>,
+    unit_output: bool,
+) -> syn::Result<TokenStream> {
+    let krate = args.krate();
+
+    let (nested_property_fn, callee) = match callee {
+        Some(callee) => (quote! {}, callee.clone()),
+        None => {
+            let property_name = format_ident!("property");
+            let nested_input = ItemFn {
+                vis: Visibility::Inherited,
+                ..input.clone()
+            };
+            let property_fn = crate::property_fn_item(&nested_input, &property_name)?;
+            (property_fn, quote! { #property_name })
+        }
+    };
+
+    let values: Vec<_> = (0..inputs.len())
+        .map(|i| format_ident!("arg{}", i))
+        .collect();
+    let tys = inputs.iter().map(|input| &input.ty);
+
+    // The values outside of the ranges and not satisfying the filters are not explored, the same
+    // way the strategies never generate them
+    let strategies: Vec<_> = match args.strategy.as_ref().map(|strategy| &strategy.expr) {
+        Some(Expr::Tuple(tuple)) if inputs.len() != 1 => tuple.elems.iter().map(Some).collect(),
+        Some(strategy) if inputs.len() == 1 => vec![Some(strategy)],
+        Some(_) => Vec::new(),
+        None => inputs
+            .iter()
+            .map(|input| input.strategy.as_ref().map(|strategy| &strategy.expr))
+            .collect(),
+    };
+    let mut assumptions = Vec::new();
+    for (i, (input, value)) in inputs.iter().zip(&values).enumerate() {
+        if let Some(Some(Expr::Range(range))) = strategies.get(i) {
+            assumptions.push(quote! { ::core::ops::RangeBounds::contains(&(#range), &#value) });
+        }
+        let pat = input.ref_pat();
+        assumptions.extend(input.filters.iter().map(|filter| {
+            quote! {
+                {
+                    #[allow(unused_variables)]
+                    let #pat = &#value;
+                    #filter
+                }
+            }
+        }));
+    }
+    if let Some(filter) = &args.filter {
+        let pats = inputs.iter().map(Input::ref_pat);
+        assumptions.push(quote! {
+            {
+                #[allow(unused_variables)]
+                let (#(#pats,)*) = (#(&#values,)*);
+                #filter
+            }
+        });
+    }
+
+    let call_args = inputs
+        .iter()
+        .zip(&values)
+        .map(|(input, value)| input.arg(value));
+    let call = quote! { #callee(#(#call_args),*) };
+    // Kani drives the futures itself instead of the runtime of the test
+    let call = match &args.async_runtime {
+        Some(_) => quote! { ::kani::block_on(#call) },
+        None => call,
+    };
+    let check = if unit_output {
+        quote! { let () = #call; }
+    } else {
+        let result = crate::output::convert(call, Span::call_site());
+        let conversion_items = crate::output::conversion_items(&krate);
+        quote! {
+            #conversion_items
+            if let ::core::result::Result::Err(
+                #krate::test_runner::TestCaseError::Fail(reason)
+            ) = #result {
+                ::core::panic!("{}", reason);
+            }
+        }
+    };
+    let check = crate::hooks(args, check);
+
+    let attrs = crate::propagated_attrs(&input.attrs);
+    let vis = &input.vis;
+
+    Ok(quote! {
+        #(#attrs)*
+        #[cfg(kani)]
+        #[kani::proof]
+        #vis fn #name() {
+            #nested_property_fn
+
+            #(let #values: #tys = ::kani::any();)*
+            #(::kani::assume(#assumptions);)*
+            #check
+        }
+    })
+}
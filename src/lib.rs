@@ -11,7 +11,7 @@
 //! use proptest::prelude::*;
 //! use proptest_attr::proptest;
 //!
-//! #[proptest(strategy = "0..=10u8")]
+//! #[proptest(strategy = 0..=10u8)]
 //! fn example_test(value: u8) -> prop::test_runner::TestCaseResult {
 //!     // do your tests...
 //!     Ok(())
@@ -21,9 +21,9 @@
 //! Note a few things here:
 //!
 //! * You still need to import the `proptest` prelude.
-//! * A `Strategy` is provided as an attribute argument. It should be a valid Rust expression
-//!   enclosed in quotes.
-//! * The test function takes the value type produced by `Strategy` and returns `TestCaseResult`.
+//! * A `Strategy` is provided as an attribute argument. It can be any Rust expression.
+//! * The test function takes the value type produced by `Strategy` and returns `TestCaseResult`
+//!   (or another `Result`, or nothing, see below).
 //!
 //! Compare this to the default `proptest! {}`:
 //!
@@ -60,7 +60,7 @@
 //! use proptest::prelude::*;
 //! use proptest_attr::proptest;
 //!
-//! #[proptest(strategy = "(0..=10u8, 10..100u32)")]
+//! #[proptest(strategy = (0..=10u8, 10..100u32))]
 //! fn example_test(a: u8, b: u32) -> prop::test_runner::TestCaseResult {
 //!     // do your tests...
 //!     Ok(())
@@ -70,120 +70,1938 @@
 //! Note that while you are able to specify multiple arguments to your test function, you are
 //! required to define strategy as a tuple of respective arguments for such case.
 //!
+//! Arguments can be destructured with any irrefutable pattern, e.g. `(a, b): (u8, u8)`,
+//! `[first, .., last]: [u8; 4]` or `Point { x, mut y }: Point`. The bindings of the patterns are
+//! available in filters and other expressions referring to the arguments. `mut` and `ref`
+//! bindings work as in any other function, and attributes other than the ones of this crate, such
+//! as `#[allow(unused_mut)]`, are kept on the arguments:
+//!
+//! ```rust
+//! use proptest::prelude::*;
+//! use proptest_attr::proptest;
+//!
+//! #[proptest]
+//! fn example_test(#[strategy(prop::collection::vec(any::<u8>(), 0..8))] mut buf: Vec<u8>) {
+//!     buf.push(0);
+//!     assert!(!buf.is_empty());
+//! }
+//! ```
+//!
+//! ### Tests without a return type
+//!
+//! Tests relying on plain assertions can omit the return type. Such tests fail when the body
+//! panics:
+//!
+//! ```rust
+//! use proptest::prelude::*;
+//! use proptest_attr::proptest;
+//!
+//! #[proptest(strategy = 0..=10u8)]
+//! fn example_test(value: u8) {
+//!     assert!(value <= 10);
+//! }
+//! ```
+//!
+//! ### Custom errors
+//!
+//! Test functions may return `Result<(), E>` for any `E: Debug`, so that `?` can be used with
+//! domain errors or `anyhow::Error`. Such errors fail the test case with their `Debug`
+//! representation, while `TestCaseError`s are passed to the runner as is, so `prop_assume!` keeps
+//! rejecting cases:
+//!
+//! ```rust
+//! use proptest::prelude::*;
+//! use proptest_attr::proptest;
+//!
+//! #[proptest]
+//! fn example_test(value: u16) -> Result<(), std::num::ParseIntError> {
+//!     let parsed: u16 = value.to_string().parse()?;
+//!     assert_eq!(parsed, value);
+//!     Ok(())
+//! }
+//! ```
+//!
+//! The return type may be spelled in any way the compiler resolves to `()` or `Result<(), E>`:
+//! `TestCaseResult`, `io::Result<()>`, `core::result::Result<...>` or a type alias of your own.
+//! Other return types such as `bool` or `Option<()>` are rejected with an error pointing at the
+//! return type, as is a `Result` whose success type is not `()`.
+//!
+//! ### Postconditions
+//!
+//! A check shared by many properties can be moved out of their bodies with
+//! `postcondition = path::to::check`. The property then returns a value of any type `T`, or
+//! `Result<T, E>` whose error fails the test case as above, and `check` is called with a reference
+//! to the inputs (a tuple if there are several) and `T`. It may use assertions or return a
+//! `Result` like a test function. The inputs are cloned before the property is called, so they
+//! have to implement `Clone`:
+//!
+//! ```rust
+//! use proptest::prelude::*;
+//! use proptest_attr::proptest;
+//!
+//! fn is_sorted_permutation(input: &Vec<u8>, output: Vec<u8>) -> Result<(), TestCaseError> {
+//!     prop_assert!(output.windows(2).all(|pair| pair[0] <= pair[1]));
+//!     prop_assert_eq!(output.len(), input.len());
+//!     Ok(())
+//! }
+//!
+//! #[proptest(postcondition = is_sorted_permutation)]
+//! fn sort(mut values: Vec<u8>) -> Vec<u8> {
+//!     values.sort();
+//!     values
+//! }
+//! ```
+//!
+//! ### Per-argument strategies
+//!
+//! Instead of a single tuple strategy, each argument can be annotated with its own strategy. The
+//! macro combines them into a tuple in the order of declaration:
+//!
+//! ```rust
+//! use proptest::prelude::*;
+//! use proptest_attr::proptest;
+//!
+//! #[proptest]
+//! fn example_test(
+//!     #[strategy(0..=10u8)] a: u8,
+//!     #[strategy(any::<String>())] s: String,
+//! ) -> prop::test_runner::TestCaseResult {
+//!     // do your tests...
+//!     Ok(())
+//! }
+//! ```
+//!
+//! Per-argument strategies cannot be combined with the `strategy` argument, unless the strategies
+//! in its tuple are named after the arguments. The macro then builds the tuple in the order of
+//! declaration, so the strategies may be listed in any order, and the arguments left out use their
+//! own strategies or `any::<T>()`:
+//!
+//! ```rust
+//! use proptest::prelude::*;
+//! use proptest_attr::proptest;
+//!
+//! #[proptest(strategy = (s = "[a-z]+", a = 0..=10u8))]
+//! fn example_test(a: u8, s: String, #[strategy(1..5usize)] count: usize, flag: bool) {
+//!     assert!(a <= 10 && !s.is_empty() && count < 5);
+//!     let _ = flag;
+//! }
+//! ```
+//!
+//! Simple strategies have shorthands. `#[range(0..=10)]` is the same as `#[strategy(0..=10)]`,
+//! and `#[len(0..32)]` generates a string or a collection (`Vec`, slices, `VecDeque`,
+//! `LinkedList`, `BinaryHeap`, `HashSet`, `BTreeSet`, `HashMap` or `BTreeMap`) of the given size
+//! with the strategies of `proptest::collection`. The elements are generated with `any::<T>()`,
+//! or taken from the range given with `#[range(...)]` next to `#[len(...)]`. A range has the type
+//! of the argument, or of the elements, so its bounds need no suffixes, and a range of `char` has
+//! to be inclusive, e.g. `'a'..='z'`. With `#[map(...)]` the range keeps its own type:
+//!
+//! ```rust
+//! use proptest_attr::proptest;
+//!
+//! #[proptest]
+//! fn example_test(
+//!     #[range(0..=10)] a: u8,
+//!     #[len(1..8)] #[range('a'..='z')] s: String,
+//!     #[len(0..32)] #[range(b'a'..=b'z')] bytes: Vec<u8>,
+//! ) {
+//!     assert!(a <= 10);
+//!     assert!((1..8).contains(&s.len()));
+//!     assert!(bytes.iter().all(u8::is_ascii_lowercase));
+//! }
+//! ```
+//!
+//! Values that are expensive to shrink, such as large generated blobs, can be kept as generated
+//! when a case fails by marking the argument with `#[no_shrink]`. The other arguments are still
+//! shrunk. Use the `no_shrink` argument to disable shrinking of all arguments, which also works
+//! with the `strategy` argument.
+//!
+//! The values of the strategy of an argument can be transformed before reaching the body with
+//! `#[map(...)]`, which takes a closure or a path to a function and is applied with `prop_map`.
+//! Without `#[strategy(...)]` the values are generated with `any::<T>()` for the type `T` the
+//! function takes. Filters of the argument see the transformed values:
+//!
+//! ```rust
+//! use proptest::prelude::*;
+//! use proptest_attr::proptest;
+//!
+//! #[proptest]
+//! fn example_test(
+//!     #[map(|s: String| s.to_lowercase())] s: String,
+//!     #[strategy(0..100u32)] #[map(|n| n * 2)] even: u32,
+//! ) {
+//!     assert_eq!(s, s.to_lowercase());
+//!     assert_eq!(even % 2, 0);
+//! }
+//! ```
+//!
+//! The shrinking of the strategy of an argument can be replaced with domain-aware shrinking by
+//! `#[shrink_with(...)]`, which takes a function (or a closure) from a reference to a failing
+//! value to the simpler values to try instead, e.g. a `Vec`. The first of them the test still
+//! fails for is shrunk the same way, until the test passes for all the simpler values of the
+//! failing one. The values have to implement `Clone`, and the filters of the argument apply to
+//! the simpler values too:
+//!
+//! ```rust
+//! use proptest::prelude::*;
+//! use proptest_attr::proptest;
+//!
+//! /// Removing one of the items keeps the list sorted.
+//! fn shrink_sorted(items: &Vec<u32>) -> Vec<Vec<u32>> {
+//!     (0..items.len())
+//!         .map(|i| [&items[..i], &items[i + 1..]].concat())
+//!         .collect()
+//! }
+//!
+//! #[proptest]
+//! fn example_test(
+//!     #[map(|mut items: Vec<u32>| { items.sort(); items })]
+//!     #[shrink_with(shrink_sorted)]
+//!     items: Vec<u32>,
+//! ) {
+//!     assert!(items.windows(2).all(|pair| pair[0] <= pair[1]));
+//! }
+//! ```
+//!
+//! ### Quoted expressions
+//!
+//! Expressions in the attribute arguments (strategies, filters and `config`) can also be enclosed
+//! in quotes, e.g. `strategy = "0..=10u8"`, which is how they had to be written in the earlier
+//! versions of this crate. To use a regular expression as a strategy, put it in parentheses:
+//! `strategy = ("[a-z]+")`.
+//!
+//! A strategy generating values of a type other than the type of its argument is reported at the
+//! strategy expression, or at the string literal for a quoted one, with both types named.
+//!
+//! ### Setup of the strategies
+//!
+//! Values the strategies depend on can be computed by the statements given with `setup`, either
+//! in quotes or in braces. They run when the test starts, before the strategies are constructed,
+//! so that their bindings are in scope for the strategies and the filters. The body of the test
+//! cannot see them:
+//!
+//! ```rust
+//! use proptest::prelude::*;
+//! use proptest_attr::proptest;
+//!
+//! mod limits {
+//!     pub const MAX: u32 = 1000;
+//! }
+//!
+//! #[proptest(setup = "let max = limits::MAX; let half = max / 2;")]
+//! fn example_test(
+//!     #[strategy(0..half)] lower: u32,
+//!     #[strategy(half..max)] #[filter(upper % half != 0)] upper: u32,
+//! ) {
+//!     assert!(lower < upper);
+//! }
+//! ```
+//!
+//! ### Inferred strategies
+//!
+//! When no strategy is given for an argument, `any::<T>()` is used, so the argument type has to
+//! implement `Arbitrary`:
+//!
+//! ```rust
+//! use proptest::prelude::*;
+//! use proptest_attr::proptest;
+//!
+//! #[proptest]
+//! fn example_test(a: u8, s: String) -> prop::test_runner::TestCaseResult {
+//!     // do your tests...
+//!     Ok(())
+//! }
+//! ```
+//!
+//! The collections and strings generated by `Arbitrary` can get large, which makes the tests slow.
+//! The `size = "0..256"` argument (also `"1..=8"`, or `"16"` for an exact size) limits the length
+//! of the collections (`Vec`, `VecDeque`, `LinkedList`, `BinaryHeap`, `HashSet`, `BTreeSet`,
+//! `HashMap` and `BTreeMap`) and `String`s of the inferred strategies by passing it in the
+//! parameters of `any_with`. This includes the collections nested in these types, in tuples,
+//! arrays, `Option`, `Box`, `Rc` and `Arc`. Other types, such as structs containing collections,
+//! are generated with their default parameters:
+//!
+//! ```rust
+//! use proptest::prelude::*;
+//! use proptest_attr::proptest;
+//! use std::collections::HashMap;
+//!
+//! #[proptest(size = "0..16")]
+//! fn example_test(values: Vec<u8>, names: HashMap<String, Vec<u8>>) {
+//!     assert!(values.len() < 16);
+//!     assert!(names.keys().all(|name| name.chars().count() < 16));
+//! }
+//! ```
+//!
+//! ### Borrowed arguments
+//!
+//! Arguments of type `&str` and `&[T]` are generated as a `String` and a `Vec<T>`, and the test
+//! gets references to them, so the body can pass them on as they are. The strategies of these
+//! arguments, the filters and the `example` values work with the owned types. References with a
+//! lifetime, such as `&'static str`, are generated as they are:
+//!
+//! ```rust
+//! use proptest::prelude::*;
+//! use proptest_attr::proptest;
+//!
+//! fn parse(s: &str, bytes: &[u8]) -> usize {
+//!     s.len() + bytes.len()
+//! }
+//!
+//! #[proptest(strategy = (s = "[a-z]*"))]
+//! fn example_test(s: &str, bytes: &[u8]) {
+//!     assert_eq!(parse(s, bytes), s.len() + bytes.len());
+//! }
+//! ```
+//!
+//! ### Filters
+//!
+//! Generated values can be filtered with `#[filter(...)]` on an argument or with the
+//! `filter = ...` argument for conditions involving several arguments. Values not satisfying
+//! the condition are rejected with `prop_filter`. Similarly to `prop_filter`, the arguments are
+//! available by reference in filter expressions:
+//!
+//! ```rust
+//! use proptest::prelude::*;
+//! use proptest_attr::proptest;
+//!
+//! #[proptest(filter = a < b)]
+//! fn example_test(#[filter(a % 2 == 0)] a: u8, b: u8) {
+//!     assert!(a < b);
+//! }
+//! ```
+//!
+//! ### Examples
+//!
+//! Known edge cases can be given with `example = ...`, once per case, so that they are always
+//! covered. The value is written like the arguments of the function: as is for a single argument
+//! and as a tuple for several of them. The examples run in the given order before the random
+//! cases, are neither filtered nor shrunk, and a failing one is reported like any other failure:
+//!
+//! ```rust
+//! use proptest_attr::proptest;
+//!
+//! #[proptest(example = (0, 0), example = (u32::MAX, 1))]
+//! fn add_commutes(a: u32, b: u32) {
+//!     assert_eq!(a.wrapping_add(b), b.wrapping_add(a));
+//! }
+//! ```
+//!
+//! ### Auxiliary randomness
+//!
+//! Randomness used by the body itself, e.g. to shuffle operations, should come from the case
+//! rather than from `rand::thread_rng()`, so that a failure can be reproduced. With `with_rng`
+//! the last argument of the function takes a `TestRng` derived from the RNG generating the rest
+//! of the case, so it is the same whenever the case is replayed from its seed or from a regression
+//! file. The RNG is not shrunk, and the other strategies given with `strategy` have to be named:
+//!
+//! ```rust
+//! use proptest::prelude::*;
+//! use proptest::test_runner::TestRng;
+//! use proptest_attr::proptest;
+//!
+//! #[proptest(with_rng)]
+//! fn shuffled_sum(values: Vec<u8>, mut rng: TestRng) {
+//!     let mut shuffled = values.clone();
+//!     for i in (1..shuffled.len()).rev() {
+//!         shuffled.swap(i, rng.next_u32() as usize % (i + 1));
+//!     }
+//!     let sum = |values: &[u8]| values.iter().map(|&value| u32::from(value)).sum::<u32>();
+//!     assert_eq!(sum(&shuffled), sum(&values));
+//! }
+//! ```
+//!
+//! ### Case context
+//!
+//! With `with_context` the last argument of the function takes the `Context` of the case from
+//! the `proptest-attr-runtime` crate, which has to be a dependency of your crate. The argument can
+//! take it by reference or by value, and comes after the RNG of `with_rng`. `ctx.case()` is the
+//! index of the case, counting the rejected cases and the shrinking iterations, `ctx.seed()` is
+//! the seed of the run reported on failure, if any, and `ctx.reject(reason)` rejects the case
+//! like `prop_assume!` does, whatever the function returns:
+//!
+//! ```rust
+//! use proptest_attr::proptest;
+//! use proptest_attr_runtime::Context;
+//!
+//! #[proptest(with_context)]
+//! fn checked_division(a: u32, b: u32, ctx: &Context) {
+//!     if b == 0 {
+//!         ctx.reject("division by zero");
+//!     }
+//!     assert!(a / b <= a, "case {}", ctx.case());
+//! }
+//! ```
+//!
+//! This cannot be combined with `parallel`, `engine`, `loom`, `fuzz_target`, `kani`, `bench`,
+//! `iai`, `no_std` or `rstest` fixtures.
+//!
+//! ### Classification
+//!
+//! The `classify = ...` argument labels each test case with the result of an expression of the
+//! arguments, which are available by reference like in filters. After the test passes, the share
+//! of the cases with each label is printed, which helps to check that the interesting cases are
+//! actually generated. The labels are counted by the `proptest-attr-runtime` crate, which has to
+//! be a dependency of your crate:
+//!
+//! ```rust
+//! use proptest_attr::proptest;
+//!
+//! #[proptest(classify = if a < b { "less" } else { "not less" })]
+//! fn example_test(a: u8, b: u8) {
+//!     assert_eq!(a < b, b > a);
+//! }
+//! ```
+//!
+//! ### Generic tests
+//!
+//! A generic test function is instantiated with each of the types given with the `types`
+//! argument. The tests are placed in a module named after the function, e.g. `roundtrip::u8` and
+//! `roundtrip::vec_u8` below, while the generic function itself is kept as is. Only a single type
+//! or const parameter is supported. Paths in the arguments and the strategies are resolved where
+//! the function is, so a strategy may be written as `self::helper()` or `super::helpers::small()`.
+//!
+//! ```rust
+//! use proptest::prelude::*;
+//! use proptest_attr::proptest;
+//! use std::fmt::Debug;
+//!
+//! #[proptest(types = (u8, String, Vec<u8>))]
+//! fn roundtrip<T: Clone + Debug + PartialEq + Arbitrary>(value: T) {
+//!     assert_eq!(value.clone(), value);
+//! }
+//! ```
+//!
+//! A function with a const parameter is instantiated with each of the constants given with the
+//! `consts` argument instead. The tests are named after the parameter and the constant, e.g.
+//! `array::n_1` and `array::n_64` below:
+//!
+//! ```rust
+//! use proptest::prelude::*;
+//! use proptest_attr::proptest;
+//!
+//! #[proptest(consts = [1, 8, 64])]
+//! fn array<const N: usize>(values: [u8; N]) {
+//!     assert_eq!(values.to_vec().len(), N);
+//! }
+//! ```
+//!
+//! ### Multiple strategies
+//!
+//! To run the same property against several kinds of inputs as separate tests, list the
+//! strategies with the `strategies` argument. A test is generated for each of them, named after
+//! the function with the position of the strategy appended (`roundtrip_1` below), or with the name
+//! given as `name = strategy` (`roundtrip_small` and `roundtrip_large`). The function itself is
+//! kept as is and called by the tests.
+//!
+//! ```rust
+//! use proptest::prelude::*;
+//! use proptest_attr::proptest;
+//!
+//! #[proptest(strategies = [small = 0..10u32, any::<u32>(), large = u32::MAX - 10..])]
+//! fn roundtrip(value: u32) {
+//!     assert_eq!(value.to_string().parse::<u32>().unwrap(), value);
+//! }
+//! ```
+//!
+//! ### Weighted unions
+//!
+//! A value picked from one of several strategies, as with `prop_oneof!`, can be generated by
+//! listing the strategies with the `oneof` argument instead of `strategy`. Each of them can be
+//! given an integer weight in quotes as `"weight => strategy"`, and the ones without a weight are
+//! picked with the weight of 1. Without any weights all of the strategies are equally likely.
+//!
+//! ```rust
+//! use proptest::prelude::*;
+//! use proptest_attr::proptest;
+//!
+//! #[proptest(oneof = ["3 => 0..10u32", "1 => Just(u32::MAX)", any::<u32>()])]
+//! fn roundtrip(value: u32) {
+//!     assert_eq!(value.to_string().parse::<u32>().unwrap(), value);
+//! }
+//! ```
+//!
+//! ### Strategy functions
+//!
+//! The `#[strategy_fn]` attribute is the counterpart of `prop_compose!`. It turns a function
+//! computing a value from generated arguments into a function returning a strategy for such
+//! values. The arguments are generated the same way as the arguments of `#[proptest]` functions,
+//! while the arguments marked with `#[param]` become the arguments of the strategy function and
+//! can be used in the strategies of the other arguments. The `filter` and `crate` arguments are
+//! supported as well.
+//!
+//! ```rust
+//! use proptest::prelude::*;
+//! use proptest_attr::{proptest, strategy_fn};
+//!
+//! #[derive(Debug, Clone)]
+//! struct Point {
+//!     x: i32,
+//!     y: i32,
+//! }
+//!
+//! #[strategy_fn]
+//! fn point(#[param] max: i32, #[strategy(0..max)] x: i32, #[strategy(0..max)] y: i32) -> Point {
+//!     Point { x, y }
+//! }
+//!
+//! #[proptest]
+//! fn example_test(#[strategy(point(10))] point: Point) {
+//!     assert!(point.x < 10 && point.y < 10);
+//! }
+//! ```
+//!
+//! ### Named strategies
+//!
+//! `proptest_strategies!` defines named strategies shared between tests. Each definition has the
+//! form `name: Type = expr;`, where `Type` is the type of the generated values, and becomes a
+//! function `name()` returning the strategy. Tests refer to it with `strategy = "@name"` or
+//! `#[strategy("@name")]`, and `"@path::to::name"` works for strategies defined in other modules:
+//!
+//! ```rust
+//! use proptest::prelude::*;
+//! use proptest_attr::{proptest, proptest_strategies};
+//!
+//! proptest_strategies! {
+//!     small: u8 = 0..10u8;
+//!     /// Identifiers used by the domain model.
+//!     pub identifier: String = "[a-z][a-z0-9_]{0,15}";
+//! }
+//!
+//! #[proptest(strategy = "@small")]
+//! fn example_test(value: u8) {
+//!     assert!(value < 10);
+//! }
+//!
+//! #[proptest]
+//! fn another_test(#[strategy("@identifier")] name: String, #[strategy("@small")] len: u8) {
+//!     assert!(!name.is_empty() && len < 10);
+//! }
+//! ```
+//!
+//! ### Arbitrary types
+//!
+//! `#[proptest_arbitrary]` implements `Arbitrary` for a struct or an enum, so that it can be used
+//! as a test argument without a strategy. Fields take the same `#[strategy(...)]` and
+//! `#[filter(...)]` attributes as test arguments, fields without a strategy use their `Arbitrary`
+//! implementation. Every variant of an enum is generated with the same probability. For structs,
+//! the `filter` argument can refer to the fields by their names. The type must implement `Debug`
+//! and the attribute must be placed before `#[derive(...)]`.
+//!
+//! ```rust
+//! use proptest::prelude::*;
+//! use proptest_attr::{proptest, proptest_arbitrary};
+//!
+//! #[proptest_arbitrary(filter = min <= max)]
+//! #[derive(Debug)]
+//! struct Range {
+//!     #[strategy(0..100)]
+//!     min: i32,
+//!     #[strategy(0..100)]
+//!     max: i32,
+//! }
+//!
+//! #[proptest]
+//! fn example_test(range: Range) {
+//!     assert!(range.min <= range.max);
+//! }
+//! ```
+//!
+//! ## Configuration
+//!
+//! The test runner configuration can be tuned with the following attribute arguments:
+//!
+//! * `cases = N` sets the number of test cases that must pass.
+//! * `timeout = N` fails a test case if it takes longer than `N` milliseconds. This runs the test
+//!   in a subprocess and requires the `fork` and `timeout` features of `proptest`.
+//! * `total_timeout = "..."` limits the duration of the whole test, including shrinking, to a
+//!   duration such as `"60s"` (with the `ms`, `s`, `m` or `h` unit). When the limit is exceeded,
+//!   the remaining cases are skipped and the test fails with the number of the cases executed so
+//!   far, or with the failure found before the limit, shrunk no further. A case that is still
+//!   running cannot be interrupted though: if the test has not finished 10 seconds later, the
+//!   watchdog thread prints the number of the cases executed and exits the test process, which
+//!   aborts the other tests of the binary without a result and loses their captured output.
+//!   Requires `proptest-attr-runtime` to be a dependency of your crate.
+//! * `max_shrink_iters = N` limits the number of shrinking iterations after a failure.
+//! * `max_shrink_time = N` limits the time spent on shrinking to `N` milliseconds.
+//! * `no_shrink = true` (or just `no_shrink`) reports a failing case with the values as they
+//!   were generated, without shrinking them.
+//! * `exhaustive_small = true` (or just `exhaustive_small`) first runs the smallest values of the
+//!   strategy (e.g. `0`, an empty string or an empty vector), found by simplifying the values of a
+//!   deterministic runner, and then the usual random cases. `small_values = N` sets how many
+//!   distinct values are run this way (16 by default). Boundary bugs fail on the first few cases
+//!   then. Cannot be combined with `parallel`, `replay`, `engine` or `runner`.
+//! * `cache_strategy = true` (or just `cache_strategy`) builds the strategy once per process and
+//!   keeps it in a static, so that strategies which are expensive to build (e.g. regular
+//!   expressions) are not rebuilt every time the test runs. The strategy has to be `Send`, `Sync`
+//!   and `'static`, and should not depend on anything that changes between the runs.
+//! * `max_local_rejects = N` and `max_global_rejects = N` set how many values may be rejected by
+//!   filters and by `prop_assume!` respectively before the test is aborted.
+//! * `max_flat_map_regens = N` limits how many times strategies built with `prop_flat_map`
+//!   may regenerate their values while shrinking.
+//! * `result_cache = true` (or just `result_cache`) caches the results of the test cases by the
+//!   `Debug` representation of the values, so that the values generated again are not re-run.
+//!   Only the cases with new values count towards `cases` then.
+//! * `dedup = true` (or just `dedup`) skips the values that were already tested, by their `Debug`
+//!   representation, without running the test or the `before_each` and `after_each` hooks for
+//!   them, so that a small input space is not tested with the same values over and over. The
+//!   skipped values are rejected, so they do not count towards `cases`. `max_global_rejects` is
+//!   doubled for them, so that they take the added rejects and the cases rejected by the test keep
+//!   their own limit. When the input space runs out of new values, or the next skipped value would
+//!   reach the limit, the rest of the skipped values count as passing cases. Once a case fails,
+//!   nothing is skipped, so that shrinking runs every candidate. Cannot be combined with `fork`,
+//!   `timeout`, `parallel`, `engine` or `runner`.
+//! * `verbose = N` sets the verbosity of the test runner: `1` logs the failure messages (and the
+//!   transitions of state machine tests), `2` also traces low-level details.
+//! * `log_inputs` prints the generated arguments of every test case with `eprintln!` before running
+//!   it, which helps to see what the runner tried while shrinking. `log_inputs = "tracing"` uses
+//!   `tracing::debug!` instead and requires `tracing` to be a dependency of your crate.
+//! * `trace = true` (or just `trace`) runs every test case in a
+//!   `tracing::info_span!("proptest_case", test = ..., input = ...)` with the generated arguments,
+//!   so that the events logged by the code under test can be correlated with the input of the
+//!   case. Requires `tracing` to be a dependency of your crate.
+//! * `stats_out = "..."` writes the statistics of the run as JSON to the given file (relative to
+//!   the crate root, with `<test>` replaced by the name of the test, e.g.
+//!   `"target/proptest-stats/<test>.json"`) when the run finishes, whether it passes or not: the
+//!   numbers of passed, rejected and failed cases and of shrinking iterations, a histogram of the
+//!   sizes of the values (the lengths of their `Debug` representations, in power-of-two buckets)
+//!   and the counts of the `classify` labels. Cannot be combined with `fork`, `parallel` or
+//!   `engine`. Requires `proptest-attr-runtime` to be a dependency of your crate.
+//! * `json_report = "..."` appends a record of each run to the given file (relative to the crate
+//!   root, with `<test>` replaced by the name of the test) as a line of JSON, for tools collecting
+//!   the results of the tests: the name of the test, `"passed"` or `"failed"`, the seed of the
+//!   run, the numbers of cases, rejected cases and shrinking steps, the error and the `Debug`
+//!   representation of the minimal failing input (`null` when there is none). Cannot be
+//!   combined with `fork`, `parallel` or `engine`. Requires `proptest-attr-runtime` to be a
+//!   dependency of your crate.
+//! * `fork = true` (or just `fork`) runs the test cases in a subprocess, so that crashes and aborts
+//!   are caught and shrunk like usual failures. Requires the `fork` feature of `proptest`.
+//! * `parallel = true` (or just `parallel`) splits the cases between threads, each with its own
+//!   runner seeded from the main one. `threads = N` sets the number of threads, which defaults to
+//!   the available parallelism. The first failure is reported after all the threads finish. The
+//!   strategy must be `Sync` and its values `Send`, and the hooks run concurrently. Cannot be
+//!   combined with `fork` or `timeout`.
+//! * `config = ...` provides the whole `ProptestConfig` as an expression. Other configuration
+//!   arguments are applied on top of it.
+//! * `runner = ...` provides the whole `TestRunner` as an expression (e.g. a call to a factory
+//!   function), which makes it possible to use a custom failure persistence or RNG. The runner
+//!   is used as is, so it cannot be combined with the configuration arguments, `seed`, `rng`,
+//!   `persistence`, `replay`, `fork`, `parallel`, `engine`, `loom`, `dedup` or `exhaustive_small`.
+//!   No seed is reported on failure.
+//! * `env_overrides = true` (or just `env_overrides`) lets the `PROPTEST_*` environment variables
+//!   (e.g. `PROPTEST_CASES` or `PROPTEST_MAX_SHRINK_ITERS`) override the configuration given in the
+//!   attribute and in `config`, the same way they do for the `proptest!` macro. Without it the
+//!   variables only change the defaults. A test with `replay` ignores the variables.
+//! * `persistence = "..."` controls where failing cases are saved to be re-run first next time:
+//!   `"file"` uses the default `proptest-regressions` directory next to the source directory,
+//!   `"off"` disables persistence and any other value is used as the name of the directory
+//!   instead of `proptest-regressions`. Without it, failing cases are persisted the way the
+//!   `config` (or the default one) says, relative to the test source file like with `proptest!`.
+//!   The failures persisted in the default location are replayed before the random cases even
+//!   if the `config` does not persist failures, unless `PROPTEST_DISABLE_FAILURE_PERSISTENCE` is
+//!   set.
+//! * `seed = "..."` makes the test runner deterministic by seeding its RNG. The seed is written as
+//!   hexadecimal digits, optionally prefixed with the RNG algorithm (e.g. `"cc 0123..."` for
+//!   ChaCha), or the way it appears in the `proptest` regression files, which write XorShift seeds
+//!   as four decimal dwords (e.g. `"xs 1 2 3 4"`). When a test fails, its panic message contains
+//!   the minimal failing input, the number of executed cases (including the shrinking ones) and the
+//!   seed of the run, ready to be pasted as `seed = "..."`. If the minimal case failed with a
+//!   panic, e.g. of an `assert_eq!`, the test panics at the location of that panic, so `cargo test`
+//!   points at the assertion in the body rather than at the attribute (except with `parallel`,
+//!   `falsify`, `continue_on_failure`, `fail_fast = false`, `engine`, `no_std` or a `report` other
+//!   than the default panic).
+//! * `format_failure = path::to::format` writes the failure in the panic message with the given
+//!   function instead of the `Debug` representation of the minimal failing input, e.g. as a hex
+//!   dump of a large buffer. The function takes a reference to the input (a tuple for several
+//!   arguments) and the `TestCaseError` of the failure, and returns a value implementing
+//!   `Display`, such as a `String`. The number of executed cases and the seed still follow it.
+//!   Cannot be combined with `falsify`, `continue_on_failure`, `fail_fast = false` or `engine`.
+//! * `rng = "..."` selects the RNG algorithm: `"XorShift"`, `"ChaCha"` (the default),
+//!   `"PassThrough"` or `"Recorder"`. The `"PassThrough"` RNG returns the bytes of the
+//!   `rng_bytes = ...` expression (e.g. `include_bytes!("corpus/case")`) instead of random ones,
+//!   so that the inputs of external corpus-driven tools can be replayed deterministically.
+//! * `corpus = "..."` runs every file in the given directory (relative to the crate root, e.g.
+//!   `"fuzz/corpus/roundtrip"`) as a test case before the random ones. The contents of a file are
+//!   fed to the strategies with the `"PassThrough"` RNG, so a corpus collected by a fuzzer (e.g.
+//!   with `engine = "bolero"`) is replayed deterministically. A failing file panics with its path
+//!   and the generated values without shrinking. A missing directory has no cases, and the
+//!   argument cannot be combined with `parallel` or `replay`.
+//! * `regressions = "..."` points at a shared corpus of regression files: a file in the format of
+//!   the `proptest-regressions` files, or a directory of them (relative to the crate root, e.g.
+//!   `"../regressions"`). Every seed in the corpus is replayed before the random cases, so one
+//!   directory committed next to several crates can hold the failures found in any of them. The
+//!   corpus is only read, new failures are persisted as usual. A missing path has no seeds.
+//! * `replay = "..."` runs only the failing case persisted in a regression file, for example
+//!   `replay = "cc 0123... # shrinks to value = 42"` (the comment after `#` is optional). Other
+//!   cases are not generated and nothing is persisted, so this cannot be combined with
+//!   `persistence`.
+//!
+//! ```rust
+//! use proptest::prelude::*;
+//! use proptest_attr::proptest;
+//!
+//! #[proptest(strategy = 0..=10u8, cases = 1000)]
+//! fn example_test(value: u8) -> prop::test_runner::TestCaseResult {
+//!     // do your tests...
+//!     Ok(())
+//! }
+//!
+//! #[proptest(
+//!     strategy = 0..=10u8,
+//!     config = ProptestConfig { max_shrink_iters: 100, ..ProptestConfig::default() }
+//! )]
+//! fn example_test_with_config(value: u8) -> prop::test_runner::TestCaseResult {
+//!     // do your tests...
+//!     Ok(())
+//! }
+//! ```
+//!
+//! ### Module-level configuration
+//!
+//! The `#[proptest_config(...)]` attribute on an inline module sets default arguments for all
+//! `#[proptest]` functions inside it, including the functions in nested modules. It accepts the
+//! same arguments as `#[proptest]` except the ones describing a particular function (`strategy`,
+//! `filter`, `classify`, `property_fn`, `types` and `consts`). Arguments given to `#[proptest]`
+//! take precedence:
+//!
+//! ```rust
+//! use proptest_attr::proptest_config;
+//!
+//! #[proptest_config(cases = 1000)]
+//! mod tests {
+//!     use proptest::prelude::*;
+//!     use proptest_attr::proptest;
+//!
+//!     #[proptest(strategy = 0..=10u8)]
+//!     fn example_test(value: u8) {
+//!         assert!(value <= 10);
+//!     }
+//!
+//!     #[proptest(strategy = 0..=10u8, cases = 10)]
+//!     fn example_test_with_fewer_cases(value: u8) {
+//!         assert!(value <= 10);
+//!     }
+//! }
+//! ```
+//!
+//! ### Associated functions
+//!
+//! `#[proptest]` can also be used on associated functions without a receiver when the `impl` block
+//! is marked with `#[proptest_config]` (which may set default arguments as well). The functions
+//! stay in the `impl` block and are called like `Type::function` by the tests generated in a
+//! `#[cfg(test)]` module next to it, named after the type (`parser_proptest` below). The
+//! strategies are still written as if they were in the `impl` block, so they may refer to `Self`
+//! and to `super::` paths. The `#[proptest]` attributes are handled by `#[proptest_config]`, so
+//! they need not be imported:
+//!
+//! ```rust
+//! use proptest_attr::proptest_config;
+//!
+//! struct Parser;
+//!
+//! #[proptest_config(cases = 100)]
+//! impl Parser {
+//!     fn parse(input: &str) -> Option<u32> {
+//!         input.parse().ok()
+//!     }
+//!
+//!     fn inputs() -> impl proptest::strategy::Strategy<Value = u32> {
+//!         0..1000u32
+//!     }
+//!
+//!     #[proptest(strategy = Self::inputs())]
+//!     fn roundtrip(value: u32) {
+//!         assert_eq!(Self::parse(&value.to_string()), Some(value));
+//!     }
+//! }
+//! ```
+//!
+//! ## Expected panics
+//!
+//! `#[should_panic]` on a property test makes it pass as soon as any case panics. Use the
+//! `expect_panic` argument instead to require every case to panic. With `expect_panic = "..."` the
+//! panic message must also contain the given text:
+//!
+//! ```rust
+//! use proptest::prelude::*;
+//! use proptest_attr::proptest;
+//!
+//! #[proptest(strategy = Just(0u8), expect_panic = "divide by zero")]
+//! fn example_test(divisor: u8) {
+//!     let _ = 100 / divisor;
+//! }
+//! ```
+//!
+//! This requires the standard library.
+//!
+//! ## Negative tests
+//!
+//! `falsify = true` (or just `falsify`) inverts the test: it passes as soon as some input makes
+//! the property fail, and fails with a sample input the property held for if every case passes.
+//! The failing input is still shrunk, but it is not reported nor persisted unless `persistence` is
+//! given:
+//!
+//! ```rust
+//! use proptest_attr::proptest;
+//!
+//! // Not every `u8` is even
+//! #[proptest(falsify)]
+//! fn example_test(value: u8) {
+//!     assert_eq!(value % 2, 0);
+//! }
+//! ```
+//!
+//! This requires the standard library and cannot be combined with `fork`, `parallel`, `engine`
+//! or `expect_panic`.
+//!
+//! ## Collecting several failures
+//!
+//! A change can break a property in more than one way. With `continue_on_failure = N` the test
+//! does not stop at the first failure: once a failing input is shrunk, the test runs again with
+//! the inputs found so far treated as passing, so that shrinking ends at a different input. The
+//! test fails after up to `N` distinct minimal failing inputs are found or the cases run out, and
+//! reports all of them:
+//!
+//! ```rust
+//! use proptest_attr::proptest;
+//!
+//! #[proptest(continue_on_failure = 3)]
+//! fn example_test(a: u8, b: u8) {
+//!     let _ = a.checked_add(b);
+//! }
+//! ```
+//!
+//! The cases of all runs count towards `cases`. The inputs are compared by their `Debug`
+//! representation. This requires the standard library and cannot be combined with `fork`,
+//! `timeout`, `parallel`, `engine`, `falsify` or `exhaustive_small`.
+//!
+//! To gauge how widespread a violation is instead, `fail_fast = false` runs all the cases without
+//! stopping at failures, and then fails with the share of the failing cases and the first failing
+//! input. Failing cases count towards `cases`, rejected ones do not, and nothing is shrunk:
+//!
+//! ```rust
+//! use proptest_attr::proptest;
+//!
+//! #[proptest(fail_fast = false, cases = 1000)]
+//! fn example_test(value: u8) {
+//!     assert!(value < 200);
+//! }
+//! ```
+//!
+//! `fail_fast = false` also requires the standard library and cannot be combined with `fork`,
+//! `timeout`, `parallel`, `engine`, `falsify`, `continue_on_failure` or `stats_out`.
+//!
+//! ## Setup and teardown
+//!
+//! `before_each = path::to::setup` and `after_each = path::to::teardown` call the given functions
+//! without arguments before and after every test case, including the cases run while shrinking.
+//! The teardown function also runs when the case fails or panics, so it should not panic itself.
+//!
+//! ```rust
+//! use proptest::prelude::*;
+//! use proptest_attr::proptest;
+//!
+//! fn create_temp_dir() {
+//!     // ...
+//! }
+//!
+//! fn remove_temp_dir() {
+//!     // ...
+//! }
+//!
+//! #[proptest(before_each = create_temp_dir, after_each = remove_temp_dir)]
+//! fn example_test(value: u8) {
+//!     // use the temporary directory...
+//! }
+//! ```
+//!
+//! `before_all = path::to::init` calls the given function once before the test runner starts, e.g.
+//! to install a `tracing` subscriber or to start a service shared by the cases. It runs once per
+//! test even if the test function is called again. With `fork` or `timeout` it runs in the test
+//! process only, not in the subprocesses running the cases, however many of them are started after
+//! crashes and timeouts, so its effects must be visible to other processes (e.g. a started
+//! service). This requires the standard library.
+//!
+//! ```rust
+//! use proptest::prelude::*;
+//! use proptest_attr::proptest;
+//!
+//! fn init_logging() {
+//!     // ...
+//! }
+//!
+//! #[proptest(before_all = init_logging)]
+//! fn example_test(value: u8) {
+//!     // ...
+//! }
+//! ```
+//!
+//! `on_complete` is called with the summary of the run, `prop_summary`, once all the cases have
+//! passed, so that a test can fail when it has explored too little. The summary has the number of
+//! `cases` run (including the rejected ones and the shrinking iterations), the number of cases
+//! `passed`, the number of cases `rejected` by the test, such as with `prop_assume!`, and the
+//! number of values `filtered` out by the filters of the strategies. The type of the summary is
+//! local to the test, so `on_complete` is a closure, which may pass the counts on to a function
+//! shared by the tests:
+//!
+//! ```rust
+//! use proptest::prelude::*;
+//! use proptest_attr::proptest;
+//!
+//! fn explored_enough(cases: u32, rejected: u32) {
+//!     assert!(rejected * 10 < cases * 9, "more than 90% of the cases were rejected");
+//! }
+//!
+//! #[proptest(on_complete = |summary| explored_enough(summary.cases, summary.rejected))]
+//! fn example_test(value: u8) -> Result<(), TestCaseError> {
+//!     prop_assume!(value % 2 == 0);
+//!     Ok(())
+//! }
+//! ```
+//!
+//! `on_complete` requires the standard library and cannot be combined with `fork`, `parallel` and
+//! `engine`.
+//!
+//! ## Inline properties
+//!
+//! `proptest_case!` runs a property written as a closure, for example to check a few small
+//! properties inside a single test. The strategy can be given first without its name, followed by
+//! any other arguments of `#[proptest]` as `name = value`. The arguments of the closure must have
+//! types, and, like a nested function, it cannot capture local variables:
+//!
+//! ```rust
+//! use proptest::prelude::*;
+//! use proptest_attr::proptest_case;
+//!
+//! fn parsing() {
+//!     proptest_case!(0..1000u32, |value: u32| {
+//!         assert_eq!(value.to_string().parse::<u32>().unwrap(), value);
+//!     });
+//!     proptest_case!(cases = 32, |s: String| -> Result<(), TestCaseError> {
+//!         prop_assert_eq!(s.chars().rev().collect::<String>().chars().count(), s.chars().count());
+//!         Ok(())
+//!     });
+//! }
+//! ```
+//!
+//! The arguments configuring the test function itself, such as `ignore` or `test_attr`, cannot be
+//! used with `proptest_case!`.
+//!
+//! ## The `#[test]` attribute
+//!
+//! The generated function is annotated with `#[test]` unless it already has this attribute. Use
+//! the `no_test_attr` flag to generate a plain function instead, for example to call it from
+//! another test or to use a different test harness.
+//!
+//! The attributes below `#[proptest]` can be in any order: test attributes of other harnesses,
+//! such as `#[test_log::test]` or `#[wasm_bindgen_test]`, are moved above the rest of the
+//! attributes (e.g. `#[ignore]` or `#[should_panic]`) and replace `#[test]`. `#[tokio::test(...)]`
+//! and `#[async_std::test]` are the same as the `async` argument with the options of the
+//! attribute, so the async runtime drives the cases of the generated test:
+//!
+//! ```rust
+//! use proptest_attr::proptest;
+//!
+//! #[proptest]
+//! #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+//! async fn example_test(value: u8) {
+//!     tokio::task::yield_now().await;
+//!     let _ = value;
+//! }
+//! ```
+//!
+//! Attributes above `#[proptest]` are expanded before it: `#[test]` there rejects the arguments
+//! of the function, so `#[proptest]` has to come first.
+//!
+//! Use `test_attr = "..."` to annotate the generated function with a different harness attribute
+//! instead of `#[test]`, for example `test_attr = "wasm_bindgen_test"` or
+//! `test_attr = "test_log::test"`. Since the generated function is synchronous, the harness has
+//! to accept synchronous functions.
+//!
+//! ## Ignoring tests
+//!
+//! `ignore` (or `ignore = "reason"`) places `#[ignore]` on the generated test. `ignore_if = ...`
+//! makes it conditional:
+//!
+//! * `ignore_if = cfg(...)` ignores the test when the `cfg` predicate holds, the same way as
+//!   `#[cfg_attr(..., ignore)]`. The test can still be run with `cargo test -- --ignored`.
+//! * Any other boolean expression is evaluated when the test starts, and the test returns early
+//!   without running the cases if it is `true`. The reason given with `ignore` is printed then.
+//!
+//! ```rust
+//! use proptest::prelude::*;
+//! use proptest_attr::proptest;
+//!
+//! #[proptest(
+//!     ignore = "too slow for pull requests",
+//!     ignore_if = std::env::var_os("PR_BUILD").is_some(),
+//!     cases = 100_000,
+//! )]
+//! fn example_test(value: u64) {
+//!     // ...
+//! }
+//! ```
+//!
+//! ## Serial tests
+//!
+//! `serial = true` (or just `serial`) places [`serial_test`](https://docs.rs/serial_test)'s
+//! `#[serial]` on the generated test, so that properties touching global state such as
+//! environment variables do not run at the same time as other serial tests. `serial = "group"`
+//! only serializes the test with the tests of the same group, like `#[serial(group)]`. The
+//! `serial_test` crate has to be a dependency.
+//!
+//! The lock is taken for the whole run, including shrinking. With `fork` it is held by the test
+//! process while the subprocesses run the cases, so other serial tests cannot run in between:
+//!
+//! ```rust
+//! use proptest_attr::proptest;
+//!
+//! #[proptest(serial = "env")]
+//! fn example_test(value: u8) {
+//!     std::env::set_var("EXAMPLE_VALUE", value.to_string());
+//!     assert_eq!(std::env::var("EXAMPLE_VALUE").unwrap(), value.to_string());
+//! }
+//! ```
+//!
+//! ## `rstest` fixtures
+//!
+//! `#[proptest]` can be combined with [`rstest`](https://docs.rs/rstest) by placing `#[rstest]`
+//! below it. In this case only the arguments with a `#[strategy(...)]` are generated, while the
+//! others are left to `rstest` and can be fixtures, `#[case]` or `#[values(...)]` arguments. They
+//! are cloned for every test case and can be used in the strategies. `rstest` adds the test
+//! attribute itself.
+//!
+//! ```rust
+//! use proptest::prelude::*;
+//! use proptest_attr::proptest;
+//! use rstest::{fixture, rstest};
+//!
+//! #[fixture]
+//! fn limit() -> u8 {
+//!     10
+//! }
+//!
+//! #[proptest]
+//! #[rstest]
+//! fn example_test(limit: u8, #[strategy(0..limit)] value: u8) {
+//!     assert!(value < limit);
+//! }
+//! ```
+//!
+//! ## Fixed cases with `#[test_case]`
+//!
+//! Fixed inputs, such as regression examples, can be kept next to the property by placing
+//! `#[test_case(...)]` attributes below `#[proptest]`, with the values of all the arguments and
+//! an optional name after a `;`, as with the [`test-case`](https://docs.rs/test-case) crate. Each
+//! case becomes a separate test running the body of the property test with its values, in a
+//! module named after the test: `example_test::case_1` and `example_test::zero_divisor` below.
+//! The values are the arguments themselves, so `#[map]` and the filters do not apply to them.
+//! The `test-case` crate is not needed:
+//!
+//! ```rust
+//! use proptest_attr::proptest;
+//!
+//! #[proptest]
+//! #[test_case(10, 3)]
+//! #[test_case(7, 0 ; "zero divisor")]
+//! fn example_test(a: u32, b: u32) {
+//!     assert_eq!(a.checked_div(b).is_none(), b == 0);
+//! }
+//! ```
+//!
+//! ## `googletest`
+//!
+//! `report = "googletest"` reports a failure the way [`googletest`](https://docs.rs/googletest)
+//! assertions do: the generated test returns `googletest::Result<()>` with the failure (including
+//! the minimal failing input and the seed) as a `googletest` assertion failure, and runs under
+//! `#[googletest::gtest]`. A harness attribute written below `#[proptest]`, such as
+//! `#[googletest::test]` or `#[gtest]`, is used instead. The properties can use `verify_that!`
+//! and the other fatal assertions with `?` by returning `googletest::Result<()>`, whose failures
+//! fail the test case and are shrunk like any other error:
+//!
+//! ```rust
+//! use googletest::prelude::*;
+//! use proptest_attr::proptest;
+//!
+//! #[proptest(report = "googletest")]
+//! fn example_test(value: u8) -> googletest::Result<()> {
+//!     verify_that!(value.checked_mul(1), some(eq(value)))
+//! }
+//! ```
+//!
+//! Non-fatal assertions such as `expect_that!` fail the whole test rather than a test case, so
+//! they are not shrunk. The `googletest` crate has to be a dependency. This requires the standard
+//! library and cannot be combined with `engine = "bolero"` or `target = "wasm"`.
+//!
+//! ## Calling the property directly
+//!
+//! The test function is replaced with the generated test. To keep the original function callable
+//! (e.g. from fuzz targets or benchmarks), use the `property_fn` argument. It generates the
+//! original function named `<test name>_property` next to the test, or under the given name with
+//! `property_fn = "name"`:
+//!
+//! ```rust
+//! use proptest::prelude::*;
+//! use proptest_attr::proptest;
+//!
+//! #[proptest(strategy = 0..=10u8, property_fn = "check_value")]
+//! fn example_test(value: u8) {
+//!     assert!(value <= 10);
+//! }
+//!
+//! fn main() {
+//!     check_value(5);
+//! }
+//! ```
+//!
+//! ## Fuzzing
+//!
+//! The `fuzz_target` argument generates a function named `<test name>_fuzz` (or the given name
+//! with `fuzz_target = "name"`) taking the raw fuzzer input. The input is converted to the test
+//! arguments with [`arbitrary`](https://docs.rs/arbitrary), so the argument types have to
+//! implement `arbitrary::Arbitrary` and `arbitrary` has to be a dependency of your crate.
+//! Strategies are not used by the fuzz target, filters are. A failing case panics, so the
+//! function can be used with `cargo fuzz` directly:
+//!
+//! ```rust,ignore
+//! // src/lib.rs
+//! #[cfg(any(test, fuzzing))]
+//! mod properties {
+//!     use proptest::prelude::*;
+//!     use proptest_attr::proptest;
+//!
+//!     #[proptest(fuzz_target)]
+//!     pub fn roundtrip(value: u32) {
+//!         assert_eq!(crate::decode(crate::encode(value)), value);
+//!     }
+//! }
+//!
+//! // fuzz/fuzz_targets/roundtrip.rs
+//! #![no_main]
+//! libfuzzer_sys::fuzz_target!(|data: &[u8]| my_crate::properties::roundtrip_fuzz(data));
+//! ```
+//!
+//! ### AFL++
+//!
+//! The `afl` argument generates a function named `<test name>_afl` (or the given name with
+//! `afl = "name"`) running the property with [`afl::fuzz!`](https://docs.rs/afl). Unlike the
+//! fuzz target, the harness feeds the fuzzer input to the strategies of the test through the
+//! `"PassThrough"` RNG, so the argument types need no `Arbitrary` implementation and the values
+//! are generated, mapped and filtered the same way as in the test. A failing case panics with the
+//! reason and the input, which AFL reports as a crash. The harness is only compiled with the `afl`
+//! feature of your crate, which should enable the optional `afl` dependency, so it can be called
+//! from the `main` of a binary built with `cargo afl build --features afl`:
+//!
+//! ```rust,ignore
+//! // src/lib.rs
+//! #[cfg(any(test, feature = "afl"))]
+//! pub mod properties {
+//!     use proptest::prelude::*;
+//!     use proptest_attr::proptest;
+//!
+//!     #[proptest(afl)]
+//!     pub fn roundtrip(#[strategy("[a-z]{1,16}")] name: String) {
+//!         assert_eq!(crate::decode(&crate::encode(&name)), name);
+//!     }
+//! }
+//!
+//! // src/bin/afl_roundtrip.rs, with `required-features = ["afl"]`
+//! fn main() {
+//!     my_crate::properties::roundtrip_afl();
+//! }
+//! ```
+//!
+//! ### Kani
+//!
+//! The `kani` argument generates a [Kani](https://model-checking.github.io/kani/) proof harness
+//! named `<test name>_kani` (or the given name with `kani = "name"`), so that the same property
+//! is also checked for all values of its arguments. The harness is only compiled under
+//! `cfg(kani)`, and its arguments are taken from `kani::any()`, so their types have to implement
+//! `kani::Arbitrary`. Strategies are not used by the harness, except that the values are assumed
+//! to be in the ranges given as strategies (such as `0..10u8`). Filters are assumed as well, and
+//! async properties are run with `kani::block_on`:
+//!
+//! ```rust,ignore
+//! #[proptest(kani)]
+//! fn roundtrip(#[strategy(0..1000u32)] value: u32) {
+//!     assert_eq!(decode(encode(value)), value);
+//! }
+//! ```
+//!
+//! `cargo kani` then finds the harness as `roundtrip_kani`. To silence the `unexpected_cfgs` lint,
+//! add `cfg(kani)` to the `check-cfg` list of the lint in your `Cargo.toml`.
+//!
+//! ### Loom
+//!
+//! With `loom = true` (or just `loom`), every case is run in [`loom::model`](https://docs.rs/loom)
+//! when the crate is built with `cfg(loom)`, so that the property is checked for all the
+//! interleavings of the threads it spawns for each of the generated inputs. As the interleavings
+//! of a single case take a while to explore, only 4 cases are run under `cfg(loom)` unless `cases`
+//! is given. Without `cfg(loom)`, the test runs as usual, so the same test covers both:
+//!
+//! ```rust,ignore
+//! #[proptest(loom)]
+//! fn concurrent_pushes(#[strategy(prop::collection::vec(any::<u8>(), 1..4))] items: Vec<u8>) {
+//!     let queue = Arc::new(Queue::new());
+//!     let pusher = {
+//!         let (queue, items) = (Arc::clone(&queue), items.clone());
+//!         thread::spawn(move || items.into_iter().for_each(|item| queue.push(item)))
+//!     };
+//!     pusher.join().unwrap();
+//!     assert_eq!(queue.len(), items.len());
+//! }
+//! ```
+//!
+//! where `Arc`, `thread` and the synchronization inside `Queue` come from `loom` under
+//! `cfg(loom)` and from `std` otherwise. The test is then run with
+//! `RUSTFLAGS="--cfg loom" cargo test`. The model runs the case for every interleaving, so the
+//! values of the arguments have to be `Clone + Send + Sync + 'static`. `loom` cannot be combined
+//! with `async`, `parallel` and `runner`.
+//!
+//! ### Bolero
+//!
+//! With `engine = "bolero"` the test is a [`bolero`](https://docs.rs/bolero) harness instead. The
+//! input of the engine is the source of randomness for the strategies, so the same test runs
+//! with random inputs under `cargo test` and under libFuzzer, AFL or Kani with `cargo bolero`.
+//! `bolero` has to be a dependency of your crate. `cases` sets the number of iterations under
+//! `cargo test`, while `fork`, `timeout`, `parallel`, `persistence`, `replay`, `seed`, `rng` and
+//! `target` are not supported. A failing case panics with the generated values:
+//!
+//! ```rust,ignore
+//! #[proptest(engine = "bolero")]
+//! fn roundtrip(#[strategy(0..1000u32)] value: u32) {
+//!     assert_eq!(my_crate::decode(my_crate::encode(value)), value);
+//! }
+//! ```
+//!
+//! ## Benchmarks
+//!
+//! The `bench` argument generates a function named `<test name>_bench` (or the given name with
+//! `bench = "name"`) taking `&mut criterion::Criterion`. It benchmarks the property with values
+//! generated by the strategy of the test from a fixed seed, so `criterion` has to be a dependency
+//! of your crate. Generating the values is not measured:
+//!
+//! ```rust,ignore
+//! // benches/properties.rs
+//! use proptest::prelude::*;
+//! use proptest_attr::proptest;
+//!
+//! #[proptest(bench)]
+//! fn roundtrip(#[strategy(0..1000u32)] value: u32) {
+//!     assert_eq!(my_crate::decode(my_crate::encode(value)), value);
+//! }
+//!
+//! criterion::criterion_group!(benches, roundtrip_bench);
+//! criterion::criterion_main!(benches);
+//! ```
+//!
+//! To track the instruction counts of the code under test with iai-callgrind instead, the `iai`
+//! argument generates a library benchmark named `<test name>_iai` (or the given name with
+//! `iai = "name"`). It runs the property with a fixed sample of 32 values generated from a fixed
+//! seed, which can be changed with `iai_inputs = K`, and `iai-callgrind` has to be a dependency
+//! of your crate. Generating the values is not counted:
+//!
+//! ```rust,ignore
+//! // benches/properties.rs
+//! use proptest::prelude::*;
+//! use proptest_attr::proptest;
+//!
+//! #[proptest(iai, iai_inputs = 100)]
+//! fn roundtrip(#[strategy(0..1000u32)] value: u32) {
+//!     assert_eq!(my_crate::decode(my_crate::encode(value)), value);
+//! }
+//!
+//! iai_callgrind::library_benchmark_group!(name = properties; benchmarks = roundtrip_iai);
+//! iai_callgrind::main!(library_benchmark_groups = properties);
+//! ```
+//!
+//! ## Property suites
+//!
+//! `#[proptest_suite]` on a trait turns its provided associated functions into properties of the
+//! implementors, such as algebraic laws. The arguments of the attribute apply to every property,
+//! and a `#[proptest(...)]` attribute on a function adds its own. Functions without a body are
+//! left to the implementors, and properties cannot take `self`.
+//!
+//! `#[proptest_suite_impl]` on an implementation of the trait generates a test for each property,
+//! with `Self` replaced by the implementing type. The tests are placed in a module named after the
+//! type and the trait, e.g. `sum_monoid::associativity` below:
+//!
+//! ```rust
+//! use proptest::prelude::*;
+//! use proptest_attr::{proptest_suite, proptest_suite_impl};
+//! use std::fmt::Debug;
+//!
+//! #[proptest_suite(cases = 64)]
+//! trait Monoid: Arbitrary + Clone + Debug + PartialEq {
+//!     fn empty() -> Self;
+//!     fn combine(a: Self, b: Self) -> Self;
+//!
+//!     fn associativity(a: Self, b: Self, c: Self) {
+//!         assert_eq!(
+//!             Self::combine(Self::combine(a.clone(), b.clone()), c.clone()),
+//!             Self::combine(a, Self::combine(b, c)),
+//!         );
+//!     }
+//!
+//!     fn identity(value: Self) {
+//!         assert_eq!(Self::combine(Self::empty(), value.clone()), value);
+//!     }
+//! }
+//!
+//! #[derive(Clone, Debug, PartialEq)]
+//! struct Sum(u64);
+//!
+//! impl Arbitrary for Sum {
+//!     type Parameters = ();
+//!     type Strategy = BoxedStrategy<Self>;
+//!
+//!     fn arbitrary_with((): ()) -> Self::Strategy {
+//!         (0..1_000_000u64).prop_map(Sum).boxed()
+//!     }
+//! }
+//!
+//! #[proptest_suite_impl]
+//! impl Monoid for Sum {
+//!     fn empty() -> Self {
+//!         Sum(0)
+//!     }
+//!
+//!     fn combine(a: Self, b: Self) -> Self {
+//!         Sum(a.0 + b.0)
+//!     }
+//! }
+//! ```
+//!
+//! The trait has to be in scope where the suite is instantiated, in the same crate. The strategies
+//! written in the trait are resolved there as well.
+//!
+//! ## State machine tests
+//!
+//! `#[proptest_state_machine]` generates a [`proptest-state-machine`] test from an `impl` block of
+//! a reference model, which has to implement `Clone` and `Debug`. The block contains:
+//!
+//! * `fn init_state(...) -> Self` creating the initial model. Its arguments are generated the same
+//!   way as the arguments of `#[proptest]` functions.
+//! * `fn init_test(&self) -> T` creating the system under test of type `T` from the model.
+//! * Transitions: methods marked with `#[transition]` taking `&mut self` and generated arguments.
+//!   Each transition is applied to both the model and the system under test, which must have a
+//!   method with the same name and arguments. If the transition returns a value, the results are
+//!   compared with `assert_eq!`.
+//! * Optionally, `fn check_invariants(&self, sut: &T)` called after each transition.
+//!
+//! The macro generates the `<Model>Transition` enum, the `<Model>StateMachine` type implementing
+//! `ReferenceStateMachine` and `StateMachineTest`, and a test named `<model>_state_machine` (or the
+//! name given with `test = name`). The number of transitions in each case is set with
+//! `transitions = 1..50` (`1..20` by default). The configuration arguments listed below and
+//! `report` are supported as well. `proptest-state-machine` has to be a dependency of your crate.
+//!
+//! ```rust
+//! use proptest::prelude::*;
+//! use proptest_attr::proptest_state_machine;
+//!
+//! #[derive(Clone, Debug, Default)]
+//! struct Counter {
+//!     value: u32,
+//! }
+//!
+//! impl Counter {
+//!     fn add(&mut self, value: u8) -> u32 {
+//!         self.value += u32::from(value);
+//!         self.value
+//!     }
+//! }
+//!
+//! #[derive(Clone, Debug)]
+//! struct CounterModel {
+//!     value: u64,
+//! }
+//!
+//! #[proptest_state_machine(transitions = 1..50)]
+//! impl CounterModel {
+//!     fn init_state() -> Self {
+//!         CounterModel { value: 0 }
+//!     }
+//!
+//!     fn init_test(&self) -> Counter {
+//!         Counter::default()
+//!     }
+//!
+//!     #[transition]
+//!     fn add(&mut self, value: u8) -> u32 {
+//!         self.value += u64::from(value);
+//!         self.value as u32
+//!     }
+//! }
+//! ```
+//!
+//! [`proptest-state-machine`]: https://docs.rs/proptest-state-machine
+//!
+//! ## Async tests
+//!
+//! `async` test functions are supported with the `async` argument, which selects how each case is
+//! driven to completion:
+//!
+//! * `async = "tokio"` uses a current-thread `tokio` runtime shared by all cases of the test.
+//! * `async = "async-std"` uses `async_std::task::block_on`.
+//! * Any other value is treated as a path to a `block_on`-like function taking a future and
+//!   returning its output, for example `async = "futures::executor::block_on"`.
+//!
+//! ```rust,ignore
+//! use proptest::prelude::*;
+//! use proptest_attr::proptest;
+//!
+//! #[proptest(strategy = 0..=10u8, async = "tokio")]
+//! async fn example_test(value: u8) -> prop::test_runner::TestCaseResult {
+//!     // do your tests...
+//!     Ok(())
+//! }
+//! ```
+//!
+//! The `tokio` runtime accepts the same options as `#[tokio::test]`:
+//!
+//! * `flavor = "multi_thread"` uses a multi-threaded runtime instead of a current-thread one.
+//! * `worker_threads = N` sets the number of worker threads of a multi-threaded runtime, which is
+//!   selected by this argument unless `flavor` says otherwise.
+//! * `start_paused = true` (or just `start_paused`) pauses the clock of a current-thread runtime,
+//!   so that timers complete instantly once all tasks are idle. This requires the `test-util`
+//!   feature of `tokio`.
+//! * `runtime_per_case = true` (or just `runtime_per_case`) builds a new runtime for every case
+//!   instead of sharing one between the cases, so that the paused clock and spawned tasks do not
+//!   leak from one case to the next.
+//!
+//! ```rust,ignore
+//! use proptest::prelude::*;
+//! use proptest_attr::proptest;
+//! use std::time::Duration;
+//!
+//! #[proptest(async = "tokio", start_paused, runtime_per_case)]
+//! async fn example_test(#[strategy(0..3600u64)] secs: u64) {
+//!     let start = tokio::time::Instant::now();
+//!     tokio::time::sleep(Duration::from_secs(secs)).await;
+//!     assert_eq!(start.elapsed().as_secs(), secs);
+//! }
+//! ```
+//!
+//! ## WebAssembly
+//!
+//! With `target = "wasm"` the test is marked with `#[wasm_bindgen_test]` instead of `#[test]`, so
+//! that it can run with `wasm-bindgen-test` (e.g. in a headless browser). Failing cases are not
+//! persisted because there is no file system, and the `fork`, `timeout`, `parallel`,
+//! `persistence`, `test_attr` and `no_test_attr` arguments are not available. The argument can be
+//! given to `#[proptest_config]` for a whole module of tests:
+//!
+//! ```rust,ignore
+//! use proptest_attr::proptest_config;
+//!
+//! #[cfg(target_arch = "wasm32")]
+//! #[proptest_config(target = "wasm")]
+//! mod tests {
+//!     use proptest_attr::proptest;
+//!
+//!     #[proptest]
+//!     fn example_test(value: u8) {
+//!         assert_eq!(u16::from(value) * 2, u16::from(value) + u16::from(value));
+//!     }
+//! }
+//! ```
+//!
+//! ## Renamed or re-exported `proptest`
+//!
+//! The generated code refers to the `proptest` crate as `::proptest`. If `proptest` is renamed or
+//! only available through a re-export, provide the path to it with the `crate` argument:
+//!
+//! ```rust
+//! mod test_utils {
+//!     pub use proptest as reexported_proptest;
+//! }
+//!
+//! use proptest_attr::proptest;
+//!
+//! #[proptest(strategy = 0..=10u8, crate = test_utils::reexported_proptest)]
+//! fn example_test(value: u8) {
+//!     assert!(value <= 10);
+//! }
+//! ```
+//!
 //! ## `no_std` support
 //!
 //! Aside from `proptest` this macro only uses the `core` library. When `proptest` is configured
 //! correctly the generated code is `no_std`-compatible.
 //!
+//! Some arguments need `std` though. `no_std = true` (or just `no_std`) guarantees that the
+//! expansion only refers to `core` and `proptest`: the arguments that need `std` (`fork`,
+//! `timeout`, `total_timeout`, `persistence`, `replay`, `corpus`, `regressions`, `env_overrides`,
+//! `parallel`, `engine`, `async`, `expect_panic`, `ignore_if` with an expression, `before_all`,
+//! `cache_strategy`, `classify`, `stats_out`, `json_report`, `falsify`, `continue_on_failure`,
+//! `dedup`, `serial`, `log_inputs` without `"tracing"`, `fuzz_target`, `bench` and `iai`) are
+//! rejected, and failing cases are not persisted because there may be no file system:
+//!
+//! ```rust,ignore
+//! #![no_std]
+//!
+//! use proptest_attr::proptest;
+//!
+//! #[proptest(no_std)]
+//! fn example_test(value: u8) {
+//!     assert_eq!(u16::from(value) * 2, u16::from(value) + u16::from(value));
+//! }
+//! ```
+//!
+//! ## Inspecting the generated code
+//!
+//! `debug_expand = true` (or just `debug_expand`) attaches the code generated for a test as
+//! documentation to the generated functions, so that it shows up on hover in an editor, without
+//! installing `cargo-expand`. If the crate has a build script, the code is also written to
+//! `$OUT_DIR/proptest-attr/<test name>.rs`. The code is laid out with a statement per line, but
+//! is not formatted otherwise.
+//!
+//! `document = true` (or just `document`) describes what a test explores in its documentation
+//! instead: the type and the strategy of every argument with its `#[map]`, `#[filter]` and
+//! shrinking, the `strategy` and `filter` arguments, the inputs given with `example` and the rest
+//! of the arguments of `#[proptest]`. The description follows the doc comment of the test, if any,
+//! and shows up in `cargo doc` and on hover in an editor.
+//!
+//! ```rust
+//! use proptest_attr::proptest;
+//!
+//! /// Parsing a formatted number gives it back.
+//! #[proptest(document, cases = 1000)]
+//! fn parse_roundtrip(#[strategy(0..1000u32)] value: u32) {
+//!     assert_eq!(value.to_string().parse::<u32>().unwrap(), value);
+//! }
+//! ```
+//!
 //! [proptest-link]: https://altsysrq.github.io/rustdoc/proptest/latest/proptest/macro.proptest.html
 
-extern crate proc_macro;
+extern crate proc_macro;
+
+mod afl;
+mod aggregate;
+mod arbitrary;
+mod args;
+mod async_runtime;
+mod bench;
+mod bolero;
+mod case;
+mod classify;
+mod context;
+mod corpus;
+mod debug_expand;
+mod dedup;
+mod document;
+mod examples;
+mod exhaustive;
+mod failure;
+mod falsify;
+mod fuzz;
+mod generics;
+mod harness;
+mod impl_block;
+mod inputs;
+mod kani;
+mod location;
+mod loom;
+mod matrix;
+mod module_config;
+mod nested;
+mod output;
+mod pass_through;
+mod regressions;
+mod shrink_with;
+mod state_machine;
+mod stats;
+mod strategies;
+mod strategy;
+mod strategy_fn;
+mod suite;
+mod summary;
+mod test_case;
+mod watchdog;
+
+use args::{Engine, IgnoreIf, ProptestArgs, Serial, Target};
+use async_runtime::AsyncRuntime;
+use inputs::{is_arg_attr, is_strategy_attr, Input};
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote, quote_spanned, ToTokens};
+use syn::{
+    parse_macro_input, parse_quote, punctuated::Punctuated, spanned::Spanned, Attribute, Error,
+    FnArg, Ident, Item, ItemFn, ItemImpl, ItemTrait, LitStr, Pat, Path, Signature, Visibility,
+};
+
+#[proc_macro_attribute]
+pub fn proptest(args: TokenStream, input: TokenStream) -> TokenStream {
+    let mut args = parse_macro_input!(args with args::parse_args);
+    let mut input = parse_macro_input!(input as ItemFn);
+    let args =
+        harness::extract_async(&mut args, &mut input).and_then(|()| ProptestArgs::parse(args));
+    let args = match args {
+        Ok(args) => args,
+        Err(err) => return err.to_compile_error().into(),
+    };
+
+    expand(args, input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+#[proc_macro_attribute]
+pub fn proptest_config(args: TokenStream, input: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(args with args::parse_args);
+    let item = parse_macro_input!(input as Item);
+
+    module_config::expand(args, item)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+#[proc_macro_attribute]
+pub fn strategy_fn(args: TokenStream, input: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(args with args::parse_args);
+    let input = parse_macro_input!(input as ItemFn);
+
+    strategy_fn::expand(args, input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+#[proc_macro]
+pub fn proptest_strategies(input: TokenStream) -> TokenStream {
+    let strategies = parse_macro_input!(input as strategies::Strategies);
+
+    strategies::expand(strategies).into()
+}
 
-use proc_macro::TokenStream;
-use quote::{quote, quote_spanned};
-use syn::{
-    parse_macro_input, punctuated::Punctuated, spanned::Spanned, AttributeArgs, Error, Expr, FnArg,
-    ItemFn, Lit, Meta, MetaNameValue, NestedMeta, PatType, ReturnType, Signature,
-};
+#[proc_macro]
+pub fn proptest_case(input: TokenStream) -> TokenStream {
+    let case = parse_macro_input!(input as case::Case);
+
+    case::expand(case)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
 
 #[proc_macro_attribute]
-pub fn proptest(args: TokenStream, input: TokenStream) -> TokenStream {
-    // Parse attribute arguments and search for `strategy = "..."`
-    let args = parse_macro_input!(args as AttributeArgs);
-
-    let mut maybe_strategy = None;
-
-    for arg in args {
-        if let NestedMeta::Meta(Meta::NameValue(MetaNameValue { path, lit, .. })) = &arg {
-            if path.is_ident("strategy") && maybe_strategy.is_none() {
-                if let Lit::Str(strategy_str) = lit {
-                    maybe_strategy = match strategy_str.parse::<Expr>() {
-                        Ok(strategy) => Some((strategy, strategy_str.span())),
-                        Err(err) => {
-                            return Error::new_spanned(
-                                strategy_str,
-                                format!("strategy is not a valid Rust expression: {}", err),
-                            )
-                            .to_compile_error()
-                            .into()
-                        }
-                    };
-                } else {
-                    return Error::new_spanned(lit, "invalid strategy: must be a string literal")
-                        .to_compile_error()
-                        .into();
-                }
-            } else if maybe_strategy.is_some() {
-                return Error::new_spanned(arg, "multiple strategies are not allowed")
-                    .to_compile_error()
-                    .into();
-            } else {
-                return Error::new_spanned(arg, "unknown argument")
-                    .to_compile_error()
-                    .into();
+pub fn proptest_arbitrary(args: TokenStream, input: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(args with args::parse_args);
+    let input = parse_macro_input!(input as Item);
+
+    arbitrary::expand(args, input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+#[proc_macro_attribute]
+pub fn proptest_suite(args: TokenStream, input: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(args with args::parse_args);
+    let input = parse_macro_input!(input as ItemTrait);
+
+    suite::expand_trait(args, input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+#[proc_macro_attribute]
+pub fn proptest_suite_impl(args: TokenStream, input: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(args with args::parse_args);
+    let input = parse_macro_input!(input as ItemImpl);
+
+    suite::expand_impl(args, input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+#[proc_macro_attribute]
+pub fn proptest_state_machine(args: TokenStream, input: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(args with args::parse_args);
+    let input = parse_macro_input!(input as ItemImpl);
+
+    state_machine::expand(args, input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+fn expand(args: ProptestArgs, input: ItemFn) -> syn::Result<TokenStream2> {
+    if args.debug_expand.unwrap_or(false) {
+        let test_name = input.sig.ident.clone();
+        let expansion = expand_item(args, input)?;
+        return debug_expand::document(&test_name, expansion);
+    }
+    expand_item(args, input)
+}
+
+fn expand_item(args: ProptestArgs, mut input: ItemFn) -> syn::Result<TokenStream2> {
+    let cases = test_case::extract(&mut input)?;
+    let cases = test_case::tests(&args, &input, cases)?;
+    let tests = expand_tests(args, input)?;
+    Ok(quote! {
+        #tests
+        #cases
+    })
+}
+
+fn expand_tests(args: ProptestArgs, input: ItemFn) -> syn::Result<TokenStream2> {
+    match generics::param(&input.sig.generics)? {
+        Some(_) if args.strategies.is_some() => Err(Error::new_spanned(
+            &input.sig.generics,
+            "`strategies` cannot be used with generic test functions",
+        )),
+        Some(param) => generics::expand(&args, input, param),
+        None if args.types.is_some() => Err(Error::new_spanned(
+            input.sig.fn_token,
+            "the `types` argument requires a generic test function",
+        )),
+        None if args.consts.is_some() => Err(Error::new_spanned(
+            input.sig.fn_token,
+            "the `consts` argument requires a generic test function",
+        )),
+        None if args.strategies.is_some() => matrix::expand(args, input),
+        None => expand_test(&args, input, None),
+    }
+}
+
+/// Generate a test running `input`. If `callee` is given, the runner calls it instead of
+/// inlining the function body.
+fn expand_test(
+    args: &ProptestArgs,
+    input: ItemFn,
+    callee: Option<TokenStream2>,
+) -> syn::Result<TokenStream2> {
+    // The body of the test is elsewhere when it calls a function
+    let mut attrs: Vec<_> = if callee.is_some() || args.property_fn.is_some() {
+        input.attrs.iter().map(allow_expectation).collect()
+    } else {
+        input.attrs.clone()
+    };
+    let vis = &input.vis;
+
+    let signature_span = input.sig.span();
+
+    match (&input.sig.asyncness, &args.async_runtime) {
+        (Some(asyncness), None) => {
+            return Err(Error::new_spanned(
+                asyncness,
+                "async test functions require the `async` argument",
+            ))
+        }
+        (None, Some(_)) => {
+            return Err(Error::new_spanned(
+                input.sig.fn_token,
+                "the `async` argument requires an async test function",
+            ))
+        }
+        _ => {}
+    }
+
+    // With `#[rstest]`, the arguments without a strategy are fixtures resolved by `rstest` and
+    // passed to every test case, the rest are generated
+    let rstest = attrs.iter().any(is_rstest);
+    let mut fixtures = Punctuated::new();
+    let mut fixture_idents = Vec::new();
+    let mut generated = Vec::new();
+    for arg in &input.sig.inputs {
+        match arg {
+            FnArg::Typed(pat_type) if rstest && !pat_type.attrs.iter().any(is_strategy_attr) => {
+                let ident = match &*pat_type.pat {
+                    Pat::Ident(pat) => pat.ident.clone(),
+                    pat => {
+                        return Err(Error::new_spanned(
+                            pat,
+                            "fixture arguments must be identifiers",
+                        ))
+                    }
+                };
+                fixtures.push(arg.clone());
+                fixture_idents.push(Some(ident));
             }
-        } else {
-            return Error::new_spanned(arg, "unknown argument")
-                .to_compile_error()
-                .into();
+            arg => {
+                generated.push(arg.clone());
+                fixture_idents.push(None);
+            }
+        }
+    }
+    if rstest
+        && (args.fuzz_target.is_some()
+            || args.kani.is_some()
+            || args.bench.is_some()
+            || args.iai.is_some()
+            || args.afl.is_some())
+    {
+        return Err(Error::new_spanned(
+            input.sig.fn_token,
+            "`fuzz_target`, `kani`, `bench`, `iai` and `afl` cannot be used with `rstest` fixtures",
+        ));
+    }
+    // The argument taking the RNG would be a fixture
+    if rstest && args.with_rng.unwrap_or(false) {
+        return Err(Error::new_spanned(
+            input.sig.fn_token,
+            "`with_rng` cannot be used with `rstest` fixtures",
+        ));
+    }
+    if rstest && args.with_context.unwrap_or(false) {
+        return Err(Error::new_spanned(
+            input.sig.fn_token,
+            "`with_context` cannot be used with `rstest` fixtures",
+        ));
+    }
+    // The context is passed after the generated arguments, including the RNG of `with_rng`
+    let context_arg = match args.with_context {
+        Some(true) => {
+            fixture_idents.pop();
+            Some(context::take_argument(
+                &mut generated,
+                input.sig.paren_token.span,
+            )?)
         }
+        _ => None,
+    };
+
+    // Make a signature for the test function
+    let test_function_signature = Signature {
+        // The only inputs are fixtures, and failures are reported by the output with `report`
+        inputs: fixtures,
+        output: args.report().output(),
+        // The test function itself is synchronous, cases are driven by the async runtime
+        asyncness: None,
+        ..input.sig.clone()
+    };
+
+    let krate = args.krate();
+    let mut inputs = generated
+        .into_iter()
+        .map(|arg| Input::from_fn_arg(arg, &krate))
+        .collect::<syn::Result<Vec<_>>>()?;
+    if let Some(true) = args.with_rng {
+        strategy::rng_input(args, &mut inputs, input.sig.paren_token.span)?;
+    }
+    if let Some(true) = args.document {
+        let doc = document::document(args, &inputs, &attrs)?;
+        attrs.extend(doc);
     }
 
-    let strategy = match maybe_strategy {
-        Some((strategy, strategy_span)) => quote_spanned!(strategy_span=> #strategy),
+    for input in &mut inputs {
+        input.own_borrowed(&krate);
+    }
+    let strategy = strategy::strategy(args, &inputs)?;
+    let strategy = match args.cache_strategy {
+        Some(true) => strategy::cached(&krate, strategy, &inputs),
+        _ => strategy,
+    };
+    // A runner given as an expression is created with its own configuration
+    let runner_settings = match &args.runner {
+        Some(_) => quote! {},
         None => {
-            let output = quote! { compile_error!("no strategy specified for this proptest"); };
-            return output.into();
+            let runner_settings = runner_settings(args, &test_function_signature.ident);
+            quote! { let runner_settings = #runner_settings; }
         }
     };
 
-    let input = parse_macro_input!(input as ItemFn);
+    // The value returned by the property is checked by the postcondition, whatever its type
+    if args.postcondition.is_none() {
+        output::check_return_type(&input.sig.output)?;
+    }
+    // Tests without a return type only use assertions, so they always succeed unless they panic
+    let unit_output = output::returns_unit(&input.sig.output);
+    let inner_output = quote! { -> #krate::test_runner::TestCaseResult };
 
-    let attrs = input.attrs;
-    let vis = input.vis;
+    let inner_inputs_types: Vec<_> = inputs.iter().map(|input| &input.ty).collect();
 
-    let signature_span = input.sig.span();
+    let (callee, property_fn, nested_property_fn) = match (callee, &args.property_fn) {
+        (Some(callee), _) => (callee, quote! {}, quote! {}),
+        (None, Some(property_fn)) => {
+            let property_name = property_fn.ident(&test_function_signature.ident, "property");
+            let property_fn = property_fn_item(&input, &property_name)?;
+            (quote! { #property_name }, property_fn, quote! {})
+        }
+        // The body is placed into a function nested into the test, so that it shows up in
+        // backtraces as `<test name>::property` instead of an anonymous closure
+        (None, None) => {
+            let property_name = format_ident!("property");
+            let nested_input = ItemFn {
+                vis: Visibility::Inherited,
+                ..input.clone()
+            };
+            let property_fn = property_fn_item(&nested_input, &property_name)?;
+            (quote! { #property_name }, quote! {}, property_fn)
+        }
+    };
 
-    // Make a signature for the test function
-    let test_function_signature = Signature {
-        // No inputs or outputs in test functions
-        inputs: Punctuated::new(),
-        output: ReturnType::Default,
-        ..input.sig
+    let fuzz_target = match &args.fuzz_target {
+        Some(fuzz_target) => {
+            let name = fuzz_target.ident(&test_function_signature.ident, "fuzz");
+            // The property nested into the test cannot be called from the fuzz target
+            let callee = args.property_fn.as_ref().map(|_| &callee);
+            fuzz::fuzz_target(args, &input, &inputs, &name, callee, unit_output)?
+        }
+        None => quote! {},
     };
 
-    // Convert multiple inputs to a tuple for use in the test runner
-    let mut inner_inputs_pats = Vec::new();
-    let mut inner_inputs_types = Vec::new();
-    for arg in input.sig.inputs.into_iter() {
-        let arg_span = arg.span();
-        if let FnArg::Typed(PatType { attrs, pat, ty, .. }) = arg {
-            // We need to collect arguments into a tuple pattern, and patterns do not allow to use
-            // attributes.
-            if !attrs.is_empty() {
-                return Error::new_spanned(
-                    &attrs[0],
-                    "proptest-attr does not allow to have attributes for function arguments",
-                )
-                .to_compile_error()
-                .into();
-            }
+    let kani = match &args.kani {
+        Some(kani) => {
+            let name = kani.ident(&test_function_signature.ident, "kani");
+            let callee = args.property_fn.as_ref().map(|_| &callee);
+            kani::proof(args, &input, &inputs, &name, callee, unit_output)?
+        }
+        None => quote! {},
+    };
 
-            inner_inputs_pats.push(pat);
-            inner_inputs_types.push(ty);
-        } else {
-            return Error::new(
-                arg_span,
-                "receiver arguments are invalid in the testing context",
-            )
-            .to_compile_error()
-            .into();
+    let bench = match &args.bench {
+        Some(bench) => {
+            let name = bench.ident(&test_function_signature.ident, "bench");
+            let callee = args.property_fn.as_ref().map(|_| &callee);
+            bench::bench(args, &input, &inputs, &strategy, &name, callee)?
         }
-    }
+        None => quote! {},
+    };
+
+    let iai = match &args.iai {
+        Some(iai) => {
+            let name = iai.ident(&test_function_signature.ident, "iai");
+            let callee = args.property_fn.as_ref().map(|_| &callee);
+            bench::iai(args, &input, &inputs, &strategy, &name, callee)?
+        }
+        None => quote! {},
+    };
 
-    let inner_inputs = if inner_inputs_pats.is_empty() {
-        quote! {}
-    } else if inner_inputs_pats.len() == 1 {
+    let afl = match &args.afl {
+        Some(afl) => {
+            let name = afl.ident(&test_function_signature.ident, "afl");
+            let callee = args.property_fn.as_ref().map(|_| &callee);
+            afl::harness(args, &input, &inputs, &strategy, &name, callee, unit_output)?
+        }
+        None => quote! {},
+    };
+
+    // The runner calls the property function with values bound to fresh identifiers
+    let inner_inputs_pats: Vec<_> = (0..inputs.len())
+        .map(|i| format_ident!("arg{}", i, span = signature_span))
+        .collect();
+    let mut generated_pats = inputs.iter().zip(&inner_inputs_pats);
+    let call_args = fixture_idents.iter().map(|fixture| match fixture {
+        Some(fixture) => quote! { ::core::clone::Clone::clone(&#fixture) },
+        None => match generated_pats.next() {
+            Some((input, pat)) => input.arg(pat),
+            None => quote! {},
+        },
+    });
+    let call_args = call_args.chain(context_arg.as_ref().map(context::value));
+    let call = quote_spanned!(signature_span=> #callee(#(#call_args),*));
+    let inner_block = match &args.log_inputs {
+        Some(log_inputs) => {
+            let names: Vec<_> = inputs
+                .iter()
+                .map(|input| input.pat.to_token_stream().to_string())
+                .collect();
+            let log = log_inputs.log(&test_function_signature.ident, &names, &inner_inputs_pats);
+            quote_spanned!(signature_span=> { #log #call })
+        }
+        None => call,
+    };
+
+    // Convert multiple inputs to a tuple for use in the test runner
+    let inner_inputs = if inputs.is_empty() {
+        quote! { (): () }
+    } else if inputs.len() == 1 {
         let pat = &inner_inputs_pats[0];
         let ty = &inner_inputs_types[0];
         quote! { #pat: #ty }
@@ -191,22 +2009,830 @@ pub fn proptest(args: TokenStream, input: TokenStream) -> TokenStream {
         quote! { ( #(#inner_inputs_pats),* ): ( #(#inner_inputs_types),* ) }
     };
 
-    let inner_output = input.sig.output;
-    let inner_block = input.block;
+    let inner_block = match &args.async_runtime {
+        Some(runtime) => {
+            let block_on = runtime.block_on(inner_block);
+            quote! { { #block_on } }
+        }
+        None => inner_block,
+    };
+    let (inner_block, error_conversion) = if let Some(postcondition) = &args.postcondition {
+        (
+            output::postcondition(
+                postcondition,
+                &inner_inputs_pats,
+                inner_block,
+                output::returns_result(&input.sig.output),
+                signature_span,
+            ),
+            output::conversion_items(&krate),
+        )
+    } else if unit_output {
+        let inner_block = quote! {
+            {
+                let () = #inner_block;
+                ::core::result::Result::Ok(())
+            }
+        };
+        (inner_block, quote! {})
+    } else {
+        (
+            output::convert(inner_block, signature_span),
+            output::conversion_items(&krate),
+        )
+    };
+    // A rejection is not a panic expected by `expect_panic`
+    let inner_block = match args.with_context {
+        Some(true) => context::catch_rejection(&krate, inner_block),
+        _ => inner_block,
+    };
+    let inner_block = match &args.expect_panic {
+        Some(message) => expect_panic(&krate, message.as_ref(), inner_block),
+        None => inner_block,
+    };
+    // Every interleaving is checked for the expected panic
+    let inner_block = match args.loom {
+        Some(true) => loom::model(&krate, &inner_inputs_pats, inner_block),
+        _ => inner_block,
+    };
+    let inner_block = match &args.classify {
+        Some(classify) => classify::record(classify, &inputs, &inner_inputs_pats, inner_block),
+        None => inner_block,
+    };
+    let inner_block = match args.falsify {
+        Some(true) => falsify::record(&inner_inputs_pats, inner_block),
+        _ => inner_block,
+    };
+    let inner_block = match args.continue_on_failure {
+        Some(_) => aggregate::skip_found(&inner_inputs_pats, inner_block),
+        None => inner_block,
+    };
+    let inner_block = match args.fail_fast {
+        Some(false) => aggregate::record_failure(&krate, &inner_inputs_pats, inner_block),
+        _ => inner_block,
+    };
+    // The report of the run takes the counts of the cases from the statistics
+    let recorded = args.stats_out.is_some() || args.json_report.is_some();
+    let inner_block = if recorded {
+        stats::record(&krate, &inner_inputs_pats, inner_block)
+    } else {
+        inner_block
+    };
+    let inner_block = hooks(args, inner_block);
+    // The duplicates are skipped before the hooks run for them
+    let inner_block = match args.dedup {
+        Some(true) => dedup::skip_tested(&krate, &inner_inputs_pats, inner_block),
+        _ => inner_block,
+    };
+    let inner_block = match args.trace {
+        Some(true) => trace(
+            &test_function_signature.ident,
+            &inner_inputs_pats,
+            inner_block,
+        ),
+        _ => inner_block,
+    };
+
+    let runner = runner(args);
+
+    let async_setup = args.async_runtime.as_ref().map(AsyncRuntime::setup);
 
     let inner_signature = quote_spanned!(signature_span=> |#inner_inputs| #inner_output);
 
-    let output = quote! {
+    let run = if let Some(Engine::Bolero) = &args.engine {
+        let run = bolero::run(args, quote! { #inner_signature #inner_block });
+        quote! {
+            #async_setup
+            #run
+        }
+    } else if args.parallel.unwrap_or(false) {
+        let inner_block = failure::count(inner_block, signature_span);
+        let inner_block = match args.total_timeout {
+            Some(_) => watchdog::skip_timed_out(&krate, inner_block),
+            None => inner_block,
+        };
+        let test_name = &test_function_signature.ident;
+        parallel_run(
+            args,
+            test_name,
+            &runner,
+            quote! { #inner_signature #inner_block },
+        )
+    } else {
+        let inner_block = match args.on_complete {
+            Some(_) => summary::count(&krate, inner_block),
+            None => inner_block,
+        };
+        let located = location::enabled(args);
+        let inner_block = if located {
+            location::record(&krate, inner_block)
+        } else {
+            inner_block
+        };
+        let inner_block = failure::count(inner_block, signature_span);
+        let inner_block = match args.total_timeout {
+            Some(_) => watchdog::skip_timed_out(&krate, inner_block),
+            None => inner_block,
+        };
+        let mut cases_setup = failure::setup();
+        if args.total_timeout.is_some() {
+            cases_setup.extend(watchdog::setup());
+        }
+        if args.on_complete.is_some() {
+            cases_setup.extend(summary::setup());
+        }
+        if located {
+            cases_setup.extend(location::setup());
+        }
+        let report = match args.falsify {
+            Some(true) => {
+                cases_setup.extend(falsify::setup());
+                falsify::report(
+                    &krate,
+                    &test_function_signature.ident,
+                    quote! { result },
+                    args.report(),
+                )
+            }
+            _ => failure::report(
+                &krate,
+                &test_function_signature.ident,
+                quote! { result },
+                quote! { runner.config().fork() },
+                quote! { &seed },
+                args.report(),
+                args.format_failure.as_ref(),
+                located,
+            ),
+        };
+        if args.continue_on_failure.is_some() {
+            cases_setup.extend(aggregate::setup());
+        }
+        if let Some(false) = args.fail_fast {
+            cases_setup.extend(aggregate::failures_setup());
+        }
+        if let Some(true) = args.dedup {
+            cases_setup.extend(dedup::setup());
+        }
+        let test = quote! { #inner_signature #inner_block };
+        let test = match &args.corpus {
+            Some(corpus) => corpus::run(&krate, corpus, test),
+            None => test,
+        };
+        // The deterministic cases run before the random ones, each only if the previous ones pass
+        let mut steps = Vec::new();
+        if !args.examples.is_empty() {
+            let tys = &inner_inputs_types;
+            let value_type = match tys.as_slice() {
+                [ty] => quote! { #ty },
+                tys => quote! { ( #(#tys,)* ) },
+            };
+            steps.push(examples::run(&krate, &args.examples, value_type));
+        }
+        // The persisted failures are replayed first whatever the configuration says
+        if let (false, None) = (without_regression_files(args), &args.replay) {
+            steps.push(regressions::replay(&krate, args.regressions.as_ref()));
+        }
+        if let Some(true) = args.exhaustive_small {
+            let values = args
+                .small_values
+                .unwrap_or(exhaustive::DEFAULT_SMALL_VALUES);
+            steps.push(exhaustive::sweep(&krate, values));
+        }
+        // The runners of the persisted and the random cases share a single call site, so that a
+        // strategy of a wrong type is reported once, at the signature of the test
+        let test_ref = quote_spanned!(signature_span=> &test);
+        let run = match args.continue_on_failure {
+            Some(limit) => aggregate::run(&krate, &test_function_signature.ident, limit, test),
+            None if steps.is_empty() => quote! { let result = runner.run(&strategy, #test); },
+            None => quote! {
+                let test = #test;
+                let run = |runner: &mut #krate::test_runner::TestRunner| {
+                    runner.run(&strategy, #test_ref)
+                };
+                let result = ::core::result::Result::Ok(());
+                #(
+                    let result = match result {
+                        ::core::result::Result::Ok(()) => #steps,
+                        result => result,
+                    };
+                )*
+                let result = result.and_then(|()| run(&mut runner));
+            },
+        };
+        let run = match args.fail_fast {
+            Some(false) => {
+                let result = aggregate::failures_result(&test_function_signature.ident);
+                quote! { #run #result }
+            }
+            _ => run,
+        };
+        let run = match &args.total_timeout {
+            Some(timeout) => {
+                let run = watchdog::guard(
+                    &test_function_signature.ident,
+                    timeout,
+                    quote! { #run result },
+                );
+                quote! { let result = #run; }
+            }
+            None => run,
+        };
+        let stats_write = args.stats_out.as_ref().map(|stats_out| {
+            stats::write(
+                &test_function_signature.ident,
+                stats_out,
+                args.classify.is_some(),
+            )
+        });
+        let json_report = args.json_report.as_ref().map(|json_report| {
+            stats::append_report(
+                &krate,
+                &test_function_signature.ident,
+                json_report,
+                args.continue_on_failure.is_some(),
+            )
+        });
+        let timed_out = args
+            .total_timeout
+            .as_ref()
+            .map(|timeout| watchdog::fail(&test_function_signature.ident, timeout, args.report()));
+        let on_complete = args.on_complete.as_ref().map(summary::complete);
+        quote! {
+            let (mut runner, seed) = #runner;
+            #cases_setup
+            #async_setup
+            #run
+            #stats_write
+            #json_report
+            #timed_out
+            #on_complete
+            #report
+        }
+    };
+
+    let (classify_setup, classify_report) = match &args.classify {
+        Some(_) => (
+            classify::setup(),
+            classify::report(&test_function_signature.ident),
+        ),
+        None => (quote! {}, quote! {}),
+    };
+
+    let stats_setup = if recorded { Some(stats::setup()) } else { None };
+
+    // Custom harness attributes go first so that they can see the rest of the attributes, wherever
+    // the user has placed them. Do not duplicate `#[test]` if the user has already specified it.
+    let (mut user_harness_attrs, attrs): (Vec<_>, Vec<_>) =
+        attrs.into_iter().partition(is_test_attr);
+    // The harness given with the arguments replaces the one of the attributes
+    if args.test_attr.is_some() || matches!(args.target, Some(Target::Wasm)) {
+        user_harness_attrs.clear();
+    }
+    let (harness_attr, test_attr) = if let Some(Target::Wasm) = &args.target {
+        (
+            quote! { #[::wasm_bindgen_test::wasm_bindgen_test] },
+            quote! {},
+        )
+    } else if let Some(test_attr) = &args.test_attr {
+        (quote! { #[#test_attr] }, quote! {})
+    } else if args.no_test_attr.unwrap_or(false) || !user_harness_attrs.is_empty() || rstest {
+        (quote! {}, quote! {})
+    } else {
+        (quote! {}, args.report().test_attr())
+    };
+
+    let (ignore_attr, skip) = ignore(args, &test_function_signature.ident);
+    let pass = args.report().pass();
+    let setup = &args.setup;
+    let serial_attr = args.serial.as_ref().map(Serial::attr);
+    let before_all = before_all(args);
+
+    Ok(quote! {
+        #property_fn
+        #fuzz_target
+        #kani
+        #bench
+        #iai
+        #afl
+
+        #harness_attr
+        #(#user_harness_attrs)*
         #(#attrs)*
-        #[cfg_attr(not(trybuild), test)]
+        #test_attr
+        #ignore_attr
+        #serial_attr
         #vis #test_function_signature {
+            #skip
+            #nested_property_fn
+            #error_conversion
+
+            #setup
+            #before_all
             let strategy = #strategy;
-            let runner_settings = ::core::default::Default::default();
-            let mut runner = ::proptest::test_runner::TestRunner::new(runner_settings);
-            let result = runner.run(&strategy, #inner_signature #inner_block);
-            result.unwrap();
+            #runner_settings
+            #classify_setup
+            #stats_setup
+            #run
+            #classify_report
+            #pass
+        }
+    })
+}
+
+/// The `#[ignore]` attribute placed on the test and the statement skipping it when it starts.
+fn ignore(args: &ProptestArgs, test_name: &Ident) -> (TokenStream2, TokenStream2) {
+    let reason = args.ignore.as_ref().and_then(Option::as_ref);
+    let ignore = match reason {
+        Some(reason) => quote! { ignore = #reason },
+        None => quote! { ignore },
+    };
+    match &args.ignore_if {
+        Some(IgnoreIf::Cfg(predicate)) => (quote! { #[cfg_attr(#predicate, #ignore)] }, quote! {}),
+        Some(IgnoreIf::Expr(condition)) => {
+            let test_name = test_name.to_string();
+            let reason = match reason {
+                Some(reason) => reason.value(),
+                None => format!("`{}`", condition.expr.to_token_stream()),
+            };
+            let pass = args.report().pass();
+            let skip = quote! {
+                if #condition {
+                    ::std::eprintln!("{}: skipped: {}", #test_name, #reason);
+                    return #pass;
+                }
+            };
+            (quote! {}, skip)
+        }
+        None if args.ignore.is_some() => (quote! { #[#ignore] }, quote! {}),
+        None => (quote! {}, quote! {}),
+    }
+}
+
+/// Make a test case returning `TestCaseResult` succeed only if it panics with a message containing
+/// `message`.
+fn expect_panic(krate: &Path, message: Option<&LitStr>, block: TokenStream2) -> TokenStream2 {
+    let check_message = match message {
+        Some(message) => quote! {
+            let payload = &*payload;
+            let message = payload
+                .downcast_ref::<&str>()
+                .copied()
+                .or_else(|| payload.downcast_ref::<::std::string::String>().map(|s| s.as_str()))
+                .unwrap_or_default();
+            if message.contains(#message) {
+                ::core::result::Result::Ok(())
+            } else {
+                ::core::result::Result::Err(#krate::test_runner::TestCaseError::fail(
+                    ::std::format!(
+                        "panic message {:?} does not contain the expected {:?}",
+                        message,
+                        #message
+                    ),
+                ))
+            }
+        },
+        None => quote! {
+            let _ = payload;
+            ::core::result::Result::Ok(())
+        },
+    };
+
+    quote! {
+        {
+            let result = ::std::panic::catch_unwind(::std::panic::AssertUnwindSafe(|| #block));
+            match result {
+                ::core::result::Result::Ok(::core::result::Result::Ok(())) => {
+                    ::core::result::Result::Err(#krate::test_runner::TestCaseError::fail(
+                        "test case did not panic",
+                    ))
+                }
+                ::core::result::Result::Ok(::core::result::Result::Err(err)) => {
+                    ::core::result::Result::Err(err)
+                }
+                ::core::result::Result::Err(payload) => {
+                    #check_message
+                }
+            }
+        }
+    }
+}
+
+/// Run `block` in a `tracing` span with the generated `values`, so that the events of the code
+/// under test can be correlated with the input of the case.
+fn trace(test_name: &Ident, values: &[Ident], block: TokenStream2) -> TokenStream2 {
+    let test_name = test_name.to_string();
+    let input = match values {
+        [value] => quote! { &#value },
+        values => quote! { ( #(&#values,)* ) },
+    };
+    quote! {
+        ::tracing::info_span!("proptest_case", test = #test_name, input = ?#input)
+            .in_scope(|| #block)
+    }
+}
+
+/// Call the `before_all` function the first time the test runs, before the runner is created. The
+/// subprocesses running the cases with `fork` are started by the test process, which has called it
+/// already, so they skip it. `proptest` passes them the path of the file recording their cases.
+fn before_all(args: &ProptestArgs) -> Option<TokenStream2> {
+    args.before_all.as_ref().map(|before_all| {
+        quote! {
+            if ::std::env::var_os("_PROPTEST_FORKFILE").is_none() {
+                static BEFORE_ALL: ::std::sync::Once = ::std::sync::Once::new();
+                BEFORE_ALL.call_once(#before_all);
+            }
+        }
+    })
+}
+
+/// Call the `before_each` and `after_each` functions around `block`. The latter is called from a
+/// guard, so that it also runs if the test case panics.
+fn hooks(args: &ProptestArgs, block: TokenStream2) -> TokenStream2 {
+    if args.before_each.is_none() && args.after_each.is_none() {
+        return block;
+    }
+
+    let before_each = args.before_each.as_ref().map(|before_each| {
+        quote! { #before_each(); }
+    });
+    let after_each = args.after_each.as_ref().map(|after_each| {
+        quote! {
+            struct AfterEach;
+            impl ::core::ops::Drop for AfterEach {
+                fn drop(&mut self) {
+                    #after_each();
+                }
+            }
+            let _after_each = AfterEach;
+        }
+    });
+
+    quote! {
+        {
+            #before_each
+            #after_each
+            #block
+        }
+    }
+}
+
+/// The original test function under a different name, without the argument attributes.
+fn property_fn_item(input: &ItemFn, ident: &Ident) -> syn::Result<TokenStream2> {
+    // The attributes of fixtures are processed by `rstest`, the attributes of generated arguments
+    // not processed by this crate are kept on their rebinding
+    let rstest = input.attrs.iter().any(is_rstest);
+    let mut rebindings = Vec::new();
+    let property_inputs = input
+        .sig
+        .inputs
+        .iter()
+        .enumerate()
+        .map(|(i, arg)| match arg {
+            FnArg::Typed(arg) => {
+                let pat = &arg.pat;
+                let ty = &arg.ty;
+                let fixture = rstest && !arg.attrs.iter().any(is_strategy_attr);
+                let attrs: Vec<_> = arg.attrs.iter().filter(|attr| !is_arg_attr(attr)).collect();
+                if fixture || attrs.is_empty() {
+                    return quote! { #pat: #ty };
+                }
+                let value = format_ident!("arg{}", i, span = Span::mixed_site());
+                rebindings.push(inputs::rebinding(attrs, pat, &value));
+                quote! { #value: #ty }
+            }
+            arg => quote! { #arg },
+        });
+    let property_inputs: Vec<_> = property_inputs.collect();
+    let property_signature = Signature {
+        ident: ident.clone(),
+        inputs: parse_quote! { #(#property_inputs),* },
+        ..input.sig.clone()
+    };
+    let property_attrs = propagated_attrs(&input.attrs);
+    let vis = &input.vis;
+    let block = &input.block;
+    let block = if rebindings.is_empty() {
+        quote! { #block }
+    } else {
+        quote! {
+            {
+                #(#rebindings)*
+                #block
+            }
+        }
+    };
+    Ok(quote! {
+        #(#property_attrs)*
+        #vis #property_signature #block
+    })
+}
+
+/// Whether `attr` marks the function as a test, e.g. `#[test]`, `#[googletest::test]`,
+/// `#[test_log::test]` or `#[wasm_bindgen_test]`.
+fn is_test_attr(attr: &Attribute) -> bool {
+    attr.path.segments.last().is_some_and(|segment| {
+        ["test", "gtest", "wasm_bindgen_test"]
+            .iter()
+            .any(|name| segment.ident == name)
+    })
+}
+
+/// Whether `attr` is `#[rstest]`, which adds the test attribute itself.
+fn is_rstest(attr: &Attribute) -> bool {
+    attr.path
+        .segments
+        .last()
+        .is_some_and(|segment| segment.ident == "rstest")
+}
+
+/// Whether an attribute of the test function also applies to the items generated next to the
+/// test.
+fn is_propagated(attr: &Attribute) -> bool {
+    [
+        "cfg", "cfg_attr", "allow", "warn", "deny", "forbid", "expect", "doc",
+    ]
+    .iter()
+    .any(|name| attr.path.is_ident(name))
+}
+
+/// The attributes of `attrs` to put on an item generated next to the test. The lints expected
+/// with `#[expect]` are only allowed there, since they are only raised where the body of the test
+/// ends up.
+fn propagated_attrs(attrs: &[Attribute]) -> impl Iterator<Item = Attribute> + '_ {
+    attrs
+        .iter()
+        .filter(|attr| is_propagated(attr))
+        .map(allow_expectation)
+}
+
+/// The `cfg` and lint attributes of `attrs`, for the helper items generated from an item which its
+/// documentation does not describe.
+fn cfg_and_lint_attrs(attrs: &[Attribute]) -> Vec<Attribute> {
+    propagated_attrs(attrs)
+        .filter(|attr| !attr.path.is_ident("doc"))
+        .collect()
+}
+
+/// `#[allow(...)]` in place of `#[expect(...)]`, and any other attribute unchanged.
+fn allow_expectation(attr: &Attribute) -> Attribute {
+    let mut attr = attr.clone();
+    if attr.path.is_ident("expect") {
+        attr.path = parse_quote! { allow };
+    }
+    attr
+}
+
+/// Run the cases of `test` in multiple threads, each with its own runner. The runners are seeded
+/// from the runner that would otherwise run the test, so the `seed` argument still applies.
+fn parallel_run(
+    args: &ProptestArgs,
+    test_name: &Ident,
+    runner: &TokenStream2,
+    test: TokenStream2,
+) -> TokenStream2 {
+    let krate = args.krate();
+    let async_setup = args.async_runtime.as_ref().map(AsyncRuntime::setup);
+    let threads = match args.threads {
+        Some(threads) => quote! { #threads },
+        None => quote! {
+            ::std::thread::available_parallelism().map_or(1, |threads| threads.get()) as u32
+        },
+    };
+
+    let mut cases_setup = failure::setup();
+    if args.total_timeout.is_some() {
+        cases_setup.extend(watchdog::setup());
+    }
+    // `parallel` cannot be combined with `fork`
+    let report = failure::report(
+        &krate,
+        test_name,
+        quote! { result },
+        quote! { false },
+        quote! { &seed },
+        args.report(),
+        args.format_failure.as_ref(),
+        false,
+    );
+
+    let run = quote! {
+        ::std::thread::scope(|scope| {
+            let handles: ::std::vec::Vec<_> = (0..threads)
+                .map(|thread| {
+                    let mut config = ::core::clone::Clone::clone(&runner_settings);
+                    // The default shrinking budget depends on the number of cases, every thread
+                    // keeps the budget of the whole run
+                    config.max_shrink_iters = runner_settings.max_shrink_iters();
+                    config.cases = cases / threads + u32::from(thread < cases % threads);
+                    let mut runner = #krate::test_runner::TestRunner::new_with_rng(
+                        config,
+                        base_runner.new_rng(),
+                    );
+                    let (strategy, test) = (&strategy, &test);
+                    scope.spawn(move || runner.run(strategy, test))
+                })
+                .collect();
+            handles
+                .into_iter()
+                .map(|handle| handle.join())
+                .collect::<::std::vec::Vec<_>>()
+        })
+    };
+    let run = match &args.total_timeout {
+        Some(timeout) => watchdog::guard(test_name, timeout, run),
+        None => run,
+    };
+    let timed_out = args
+        .total_timeout
+        .as_ref()
+        .map(|timeout| watchdog::fail(test_name, timeout, args.report()));
+
+    quote! {
+        #async_setup
+        #cases_setup
+        let test = #test;
+        let threads: u32 = #threads;
+        let cases = runner_settings.cases;
+        let (mut base_runner, seed) = {
+            let runner_settings = ::core::clone::Clone::clone(&runner_settings);
+            #runner
+        };
+        let results = #run;
+        #timed_out
+        // Report the failure of the first thread that failed
+        for result in results {
+            match result {
+                ::core::result::Result::Ok(result) => {
+                    #report
+                }
+                ::core::result::Result::Err(payload) => ::std::panic::resume_unwind(payload),
+            }
+        }
+    }
+}
+
+/// Construct the test runner from the `runner_settings` variable (or the `runner` argument)
+/// together with the seed of its RNG, which is reported when the test fails.
+fn runner(args: &ProptestArgs) -> TokenStream2 {
+    let krate = args.krate();
+    if let Some(runner) = &args.runner {
+        return quote! {
+            (
+                {
+                    let runner: #krate::test_runner::TestRunner = #runner;
+                    runner
+                },
+                ::core::option::Option::<#krate::std_facade::String>::None,
+            )
+        };
+    }
+    match (&args.seed, &args.rng_bytes) {
+        (Some(seed), _) => {
+            let rng = seed.rng(&krate);
+            let seed = seed.text();
+            quote! {
+                (
+                    #krate::test_runner::TestRunner::new_with_rng(runner_settings, #rng),
+                    ::core::option::Option::Some(#krate::std_facade::String::from(#seed)),
+                )
+            }
         }
+        (None, Some(bytes)) => quote! {
+            (
+                #krate::test_runner::TestRunner::new_with_rng(
+                    runner_settings,
+                    #krate::test_runner::TestRng::from_seed(
+                        #krate::test_runner::RngAlgorithm::PassThrough,
+                        &(#bytes)[..],
+                    ),
+                ),
+                ::core::option::Option::<#krate::std_facade::String>::None,
+            )
+        },
+        // Only the persisted case is run, its seed is already known
+        (None, None) if args.replay.is_some() => quote! {
+            (
+                #krate::test_runner::TestRunner::new(runner_settings),
+                ::core::option::Option::<#krate::std_facade::String>::None,
+            )
+        },
+        (None, None) => failure::seeded_runner(&krate),
+    }
+}
+
+/// Whether the failures of the test are neither persisted nor replayed from the regression files.
+fn without_regression_files(args: &ProptestArgs) -> bool {
+    let no_file_system = matches!(args.target, Some(Target::Wasm)) || args.no_std.unwrap_or(false);
+    // The failures of negative tests are expected, so they are not persisted unless asked to
+    let falsify = args.falsify.unwrap_or(false) && args.persistence.is_none();
+    no_file_system || falsify
+}
+
+/// Build the `Config` for the test runner from the attribute arguments.
+fn runner_settings(args: &ProptestArgs, test_name: &Ident) -> TokenStream2 {
+    let krate = args.krate();
+
+    // Forking requires the name of the test to run it in a subprocess, and the regression files
+    // are placed relative to the test source like with `proptest!`
+    let mut fields = vec![
+        quote! {
+            test_name: ::core::option::Option::Some(::core::concat!(
+                ::core::module_path!(),
+                "::",
+                ::core::stringify!(#test_name)
+            ))
+        },
+        quote! { source_file: ::core::option::Option::Some(::core::file!()) },
+    ];
+
+    if let Some(replay) = &args.replay {
+        // Only the persisted case is run
+        let persistence = replay.failure_persistence(&krate);
+        fields.push(quote! { cases: 0 });
+        fields.push(quote! { failure_persistence: #persistence });
+    } else if let Some(cases) = args.cases {
+        fields.push(quote! { cases: #cases });
+    }
+
+    if let Some(timeout) = args.timeout {
+        fields.push(quote! { timeout: #timeout });
+    }
+
+    if let Some(max_shrink_iters) = args.max_shrink_iters {
+        fields.push(quote! { max_shrink_iters: #max_shrink_iters });
+    }
+
+    if let Some(max_shrink_time) = args.max_shrink_time {
+        fields.push(quote! { max_shrink_time: #max_shrink_time });
+    }
+
+    if let Some(max_local_rejects) = args.max_local_rejects {
+        fields.push(quote! { max_local_rejects: #max_local_rejects });
+    }
+
+    if let Some(max_global_rejects) = args.max_global_rejects {
+        fields.push(quote! { max_global_rejects: #max_global_rejects });
+    }
+
+    if let Some(max_flat_map_regens) = args.max_flat_map_regens {
+        fields.push(quote! { max_flat_map_regens: #max_flat_map_regens });
+    }
+
+    if let Some(result_cache) = args.result_cache {
+        let result_cache = if result_cache {
+            quote! { basic_result_cache }
+        } else {
+            quote! { noop_result_cache }
+        };
+        fields.push(quote! { result_cache: #krate::test_runner::#result_cache });
+    }
+
+    if let Some(verbose) = args.verbose {
+        fields.push(quote! { verbose: #verbose });
+    }
+
+    if let Some(rng) = &args.rng {
+        let rng = rng.path(&krate);
+        fields.push(quote! { rng_algorithm: #rng });
+    }
+
+    if let Some(persistence) = &args.persistence {
+        let persistence = persistence.failure_persistence(&krate);
+        fields.push(quote! { failure_persistence: #persistence });
+    }
+
+    if let (true, None) = (without_regression_files(args), &args.replay) {
+        // The regression files cannot be read or written
+        fields.push(quote! { failure_persistence: ::core::option::Option::None });
+    }
+
+    if let Some(fork) = args.fork {
+        fields.push(quote! { fork: #fork });
+    }
+
+    let base = match &args.config {
+        Some(config) => quote! { #config },
+        None => quote! { ::core::default::Default::default() },
     };
 
-    output.into()
+    let config = quote! {
+        #krate::test_runner::Config {
+            #(#fields,)*
+            ..#base
+        }
+    };
+    // The replayed case is run regardless of the environment
+    let config = match (args.env_overrides, &args.replay) {
+        (Some(true), None) => quote! { #krate::test_runner::contextualize_config(#config) },
+        _ => config,
+    };
+    let config = match args.dedup {
+        Some(true) => dedup::config(config),
+        _ => config,
+    };
+    match args.loom {
+        Some(true) => loom::config(args, config),
+        _ => config,
+    }
 }
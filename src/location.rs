@@ -0,0 +1,103 @@
+//! Reporting a failure at the location of the panic that failed the minimal case rather than at
+//! the attribute.
+
+use crate::args::{ProptestArgs, Report, Target};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Path;
+
+/// Whether the location of the failing panic is recorded for the failure of the test. The panic
+/// hook requires the standard library, the reports other than the panic of the test write the
+/// failures themselves, and the threads of `parallel` fail cases at the same time.
+pub(crate) fn enabled(args: &ProptestArgs) -> bool {
+    matches!(args.report(), Report::Panic)
+        && !args.no_std.unwrap_or(false)
+        && !args.falsify.unwrap_or(false)
+        && args.continue_on_failure.is_none()
+        && args.fail_fast != Some(false)
+        && args.engine.is_none()
+        && !args.parallel.unwrap_or(false)
+        && !matches!(args.target, Some(Target::Wasm))
+}
+
+/// The location of the panic that failed the last failing case, and the panic hook recording the
+/// location of every panic of the thread before passing it on to the previous hook. The hook is
+/// installed once by every test, as it cannot be shared between them; a hook replaced by another
+/// test installing its own at the same time only loses the location.
+pub(crate) fn setup() -> TokenStream {
+    quote! {
+        ::std::thread_local! {
+            static PANIC_LOCATION: ::core::cell::RefCell<
+                ::core::option::Option<::std::string::String>
+            > = ::core::cell::RefCell::new(::core::option::Option::None);
+        }
+        static PANIC_HOOK: ::std::sync::Once = ::std::sync::Once::new();
+        PANIC_HOOK.call_once(|| {
+            let previous = ::std::panic::take_hook();
+            ::std::panic::set_hook(::std::boxed::Box::new(move |info| {
+                let location = info.location().map(::std::string::ToString::to_string);
+                // The thread may be exiting with its locals already destroyed
+                let _ = PANIC_LOCATION.try_with(|cell| *cell.borrow_mut() = location);
+                previous(info);
+            }));
+        });
+        let failure_location = ::std::sync::Mutex::new(
+            ::core::option::Option::<::std::string::String>::None,
+        );
+    }
+}
+
+/// Keep the location of the panic of `block` if it fails the case by panicking, and forget the
+/// location of an earlier failure if it fails otherwise, e.g. with `prop_assert!`, whose message
+/// has the location already.
+pub(crate) fn record(krate: &Path, block: TokenStream) -> TokenStream {
+    quote! {
+        {
+            PANIC_LOCATION.with(|cell| *cell.borrow_mut() = ::core::option::Option::None);
+            let outcome = ::std::panic::catch_unwind(::std::panic::AssertUnwindSafe(|| #block));
+            let location = match &outcome {
+                ::core::result::Result::Err(_) => {
+                    ::core::option::Option::Some(PANIC_LOCATION.with(|cell| cell.borrow_mut().take()))
+                }
+                ::core::result::Result::Ok(::core::result::Result::Err(
+                    #krate::test_runner::TestCaseError::Fail(_),
+                )) => ::core::option::Option::Some(::core::option::Option::None),
+                ::core::result::Result::Ok(_) => ::core::option::Option::None,
+            };
+            if let ::core::option::Option::Some(location) = location {
+                *failure_location
+                    .lock()
+                    .unwrap_or_else(::std::sync::PoisonError::into_inner) = location;
+            }
+            match outcome {
+                ::core::result::Result::Ok(result) => result,
+                ::core::result::Result::Err(payload) => ::std::panic::resume_unwind(payload),
+            }
+        }
+    }
+}
+
+/// Fail with `message` as if the panic came from the location of the panic that failed the
+/// minimal case, which is the last failing case, if the failure of `error` is such a panic. The
+/// message is written the way the default panic hook does, and the panic resumed without calling
+/// the hook again.
+pub(crate) fn resume(krate: &Path) -> TokenStream {
+    quote! {
+        let location = failure_location
+            .lock()
+            .unwrap_or_else(::std::sync::PoisonError::into_inner)
+            .take();
+        if let (#krate::test_runner::TestError::Fail(..), ::core::option::Option::Some(location)) =
+            (&error, location)
+        {
+            let thread = ::std::thread::current();
+            ::std::eprintln!(
+                "\nthread '{}' panicked at {}:\n{}",
+                thread.name().unwrap_or("<unnamed>"),
+                location,
+                message,
+            );
+            ::std::panic::resume_unwind(::std::boxed::Box::new(message));
+        }
+    }
+}
@@ -0,0 +1,63 @@
+//! Checking every interleaving of the threads of a case with `loom = true`.
+
+use crate::args::ProptestArgs;
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use syn::Path;
+
+/// The number of cases run with `cfg(loom)` when `cases` is not given, as each of them is checked
+/// for every interleaving of its threads.
+const LOOM_CASES: u32 = 4;
+
+/// Run `block` for the `values` of a case in `loom::model` when the crate is built with
+/// `cfg(loom)`, so that it is run for every interleaving of the threads it spawns, and directly
+/// otherwise. The model takes a copy of the values for every interleaving, and the first error
+/// returned by `block` is the result of the case. Panics are passed on by `loom` itself.
+pub(crate) fn model(krate: &Path, values: &[Ident], block: TokenStream) -> TokenStream {
+    quote! {
+        {
+            #[cfg(loom)]
+            let result = {
+                let loom_inputs = ( #(#values,)* );
+                let loom_failure = ::std::sync::Arc::new(::std::sync::Mutex::new(
+                    ::core::option::Option::<#krate::test_runner::TestCaseError>::None,
+                ));
+                {
+                    let loom_failure = ::std::sync::Arc::clone(&loom_failure);
+                    ::loom::model(move || {
+                        let ( #(#values,)* ) = ::core::clone::Clone::clone(&loom_inputs);
+                        let result: #krate::test_runner::TestCaseResult = #block;
+                        if let ::core::result::Result::Err(err) = result {
+                            loom_failure.lock().unwrap().get_or_insert(err);
+                        }
+                    });
+                }
+                let failure = loom_failure.lock().unwrap().take();
+                match failure {
+                    ::core::option::Option::Some(err) => ::core::result::Result::Err(err),
+                    ::core::option::Option::None => ::core::result::Result::Ok(()),
+                }
+            };
+            #[cfg(not(loom))]
+            let result = #block;
+            result
+        }
+    }
+}
+
+/// The configuration of the runner running a few cases with `cfg(loom)`, unless the number of cases
+/// is given with `args`.
+pub(crate) fn config(args: &ProptestArgs, config: TokenStream) -> TokenStream {
+    if args.cases.is_some() || args.replay.is_some() {
+        return config;
+    }
+    quote! {
+        {
+            let mut config = #config;
+            if ::core::cfg!(loom) {
+                config.cases = #LOOM_CASES;
+            }
+            config
+        }
+    }
+}
@@ -0,0 +1,85 @@
+//! Support for the `strategies` argument generating a test for each of the strategies.
+
+use crate::args::ProptestArgs;
+use crate::inputs::is_strategy_attr;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Error, FnArg, ItemFn};
+
+/// Keep the test function as a property called by a test for each of the strategies.
+pub(crate) fn expand(args: ProptestArgs, input: ItemFn) -> syn::Result<TokenStream> {
+    if args.property_fn.is_some() {
+        return Err(Error::new_spanned(
+            input.sig.fn_token,
+            "`property_fn` cannot be used with `strategies`, the property is always callable",
+        ));
+    }
+    if args.fuzz_target.is_some()
+        || args.kani.is_some()
+        || args.bench.is_some()
+        || args.iai.is_some()
+    {
+        return Err(Error::new_spanned(
+            input.sig.fn_token,
+            "`fuzz_target`, `kani`, `bench` and `iai` cannot be used with `strategies`",
+        ));
+    }
+
+    let name = input.sig.ident.clone();
+    let property_fn = crate::property_fn_item(&input, &name)?;
+    let tests = tests(args, input, quote! { #name })?;
+
+    Ok(quote! {
+        #property_fn
+        #tests
+    })
+}
+
+/// Generate the tests named after the strategies, or after their positions for the unnamed ones,
+/// calling `callee`.
+pub(crate) fn tests(
+    mut args: ProptestArgs,
+    input: ItemFn,
+    callee: TokenStream,
+) -> syn::Result<TokenStream> {
+    let strategies = args.strategies.take().unwrap_or_default();
+    if let Some(attr) = input
+        .sig
+        .inputs
+        .iter()
+        .filter_map(|arg| match arg {
+            FnArg::Typed(arg) => Some(&arg.attrs),
+            FnArg::Receiver(_) => None,
+        })
+        .flatten()
+        .find(|attr| is_strategy_attr(attr))
+    {
+        return Err(Error::new_spanned(
+            attr,
+            "per-argument strategies cannot be combined with the `strategies` argument",
+        ));
+    }
+
+    let mut tests = Vec::with_capacity(strategies.len());
+    let mut test_names = Vec::with_capacity(strategies.len());
+    for (i, strategy) in strategies.into_iter().enumerate() {
+        let test_name = match &strategy.name {
+            Some(suffix) => format_ident!("{}_{}", input.sig.ident, suffix, span = suffix.span()),
+            None => format_ident!("{}_{}", input.sig.ident, i),
+        };
+        if test_names.contains(&test_name) {
+            return Err(Error::new_spanned(
+                &strategy.name,
+                "duplicate strategy name in `strategies`",
+            ));
+        }
+
+        let mut test = input.clone();
+        test.sig.ident = test_name.clone();
+        args.strategy = Some(strategy.strategy);
+        tests.push(crate::expand_test(&args, test, Some(callee.clone()))?);
+        test_names.push(test_name);
+    }
+
+    Ok(quote! { #(#tests)* })
+}
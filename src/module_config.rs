@@ -0,0 +1,133 @@
+//! The `#[proptest_config(...)]` attribute setting default arguments for a module or an `impl`
+//! block.
+
+use crate::args::{self, Arg, ProptestArgs};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Attribute, Error, Item};
+
+/// Arguments that only make sense for a particular test function.
+const FUNCTION_ONLY_ARGS: &[&str] = &[
+    "strategy",
+    "strategies",
+    "oneof",
+    "filter",
+    "example",
+    "property_fn",
+    "fuzz_target",
+    "kani",
+    "bench",
+    "iai",
+    "iai_inputs",
+    "types",
+    "consts",
+    "replay",
+    "classify",
+];
+
+pub(crate) fn expand(args: Vec<Arg>, item: Item) -> syn::Result<TokenStream> {
+    for arg in &args {
+        if let Some(name) = FUNCTION_ONLY_ARGS.iter().find(|name| arg.name == name) {
+            return Err(Error::new_spanned(
+                arg,
+                format!(
+                    "`{}` cannot be specified for a module or an `impl` block",
+                    name
+                ),
+            ));
+        }
+    }
+    // Report invalid arguments once instead of on every test function
+    ProptestArgs::parse(args.clone())?;
+
+    let mut module = match item {
+        Item::Mod(module) => module,
+        Item::Impl(item) => return crate::impl_block::expand(&args, item),
+        item => {
+            return Err(Error::new_spanned(
+                item,
+                "`proptest_config` can only be applied to inline modules and `impl` blocks",
+            ))
+        }
+    };
+    let content = match &mut module.content {
+        Some((_, content)) => content,
+        None => {
+            return Err(Error::new_spanned(
+                &module,
+                "`proptest_config` can only be applied to inline modules",
+            ))
+        }
+    };
+
+    apply_defaults(content, &args)?;
+
+    Ok(quote! { #module })
+}
+
+/// Add the default arguments to the `#[proptest]` attributes of the functions in `items` and to
+/// the `#[proptest_config]` attributes of the nested modules and `impl` blocks.
+fn apply_defaults(items: &mut [Item], defaults: &[Arg]) -> syn::Result<()> {
+    for item in items {
+        match item {
+            Item::Fn(item) => {
+                for attr in &mut item.attrs {
+                    if is_attr(attr, "proptest") {
+                        merge(attr, defaults)?;
+                    }
+                }
+            }
+            Item::Impl(item) => {
+                for attr in &mut item.attrs {
+                    if is_attr(attr, "proptest_config") {
+                        merge(attr, defaults)?;
+                    }
+                }
+            }
+            Item::Mod(item) => {
+                let mut nested_config = false;
+                for attr in &mut item.attrs {
+                    if is_attr(attr, "proptest_config") {
+                        merge(attr, defaults)?;
+                        nested_config = true;
+                    }
+                }
+                // The nested `#[proptest_config]` passes the defaults further down
+                if !nested_config {
+                    if let Some((_, content)) = &mut item.content {
+                        apply_defaults(content, defaults)?;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+/// Prepend the `defaults` not overridden by the arguments of `attr` to those arguments.
+pub(crate) fn merge(attr: &mut Attribute, defaults: &[Arg]) -> syn::Result<()> {
+    let args = if attr.tokens.is_empty() {
+        Vec::new()
+    } else {
+        attr.parse_args_with(args::parse_args)?
+    };
+
+    let defaults = defaults
+        .iter()
+        .filter(|default| !args.iter().any(|arg| arg.name == default.name));
+
+    attr.tokens = quote! { ( #(#defaults,)* #(#args),* ) };
+
+    Ok(())
+}
+
+/// Whether `attr` is the attribute of this crate with the given name (possibly referred to with a
+/// path like `proptest_attr::proptest`).
+pub(crate) fn is_attr(attr: &Attribute, name: &str) -> bool {
+    attr.path
+        .segments
+        .last()
+        .is_some_and(|segment| segment.ident == name)
+}
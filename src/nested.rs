@@ -0,0 +1,89 @@
+//! Paths written next to a test function that are moved into a module generated for its tests.
+
+use proc_macro2::{Group, Literal, Spacing, TokenStream, TokenTree};
+use quote::{quote, quote_spanned, ToTokens};
+use syn::{parse::Parse, LitStr, Type};
+
+/// `node` written in the module generated next to it: `self::` and `super::` paths go up one more
+/// module, and `Self` is replaced with `self_ty`, the type of the `impl` block the tokens were
+/// written in. The code in string literals, such as `strategy = "super::strategy()"`, is written
+/// the same way.
+pub(crate) fn nest<T>(node: &T, self_ty: Option<&Type>) -> syn::Result<T>
+where
+    T: ToTokens + Parse,
+{
+    syn::parse2(nest_tokens(node.to_token_stream(), self_ty, &mut false))
+}
+
+/// Set `changed` if any of the tokens are replaced.
+fn nest_tokens(tokens: TokenStream, self_ty: Option<&Type>, changed: &mut bool) -> TokenStream {
+    let tokens: Vec<_> = tokens.into_iter().collect();
+    let mut result = TokenStream::new();
+    for (i, token) in tokens.iter().enumerate() {
+        // `a::super::b` is not a relative path
+        let starts_path = i < 2 || !is_path_separator(&tokens[i - 2], &tokens[i - 1]);
+        let is_path = matches!(tokens.get(i + 1..i + 3), Some([first, second]) if is_path_separator(first, second));
+        match token {
+            TokenTree::Ident(ident) if ident == "self" && starts_path && is_path => {
+                result.extend(quote_spanned!(ident.span()=> super));
+                *changed = true;
+            }
+            TokenTree::Ident(ident) if ident == "super" && starts_path && is_path => {
+                result.extend(quote_spanned!(ident.span()=> super::super));
+                *changed = true;
+            }
+            TokenTree::Ident(ident) if ident == "Self" => match self_ty {
+                Some(self_ty) if is_path => {
+                    result.extend(quote! { <#self_ty> });
+                    *changed = true;
+                }
+                Some(self_ty) => {
+                    result.extend(quote! { #self_ty });
+                    *changed = true;
+                }
+                None => result.extend(Some(token.clone())),
+            },
+            TokenTree::Group(group) => {
+                let stream = nest_tokens(group.stream(), self_ty, changed);
+                let mut nested = Group::new(group.delimiter(), stream);
+                nested.set_span(group.span());
+                result.extend(Some(TokenTree::Group(nested)));
+            }
+            TokenTree::Literal(literal) => {
+                result.extend(Some(nest_literal(literal, self_ty, changed)))
+            }
+            token => result.extend(Some(token.clone())),
+        }
+    }
+    result
+}
+
+fn is_path_separator(first: &TokenTree, second: &TokenTree) -> bool {
+    matches!(
+        (first, second),
+        (TokenTree::Punct(first), TokenTree::Punct(second))
+            if first.as_char() == ':' && first.spacing() == Spacing::Joint && second.as_char() == ':'
+    )
+}
+
+/// The code in a string literal written in the module, or the literal as is if it is not a string
+/// or does not change, as with a regular expression or a file name.
+fn nest_literal(literal: &Literal, self_ty: Option<&Type>, changed: &mut bool) -> TokenTree {
+    let lit = match syn::parse2::<LitStr>(literal.to_token_stream()) {
+        Ok(lit) => lit,
+        Err(_) => return TokenTree::Literal(literal.clone()),
+    };
+    let tokens = match lit.value().parse::<TokenStream>() {
+        Ok(tokens) => tokens,
+        Err(_) => return TokenTree::Literal(literal.clone()),
+    };
+    let mut literal_changed = false;
+    let nested = nest_tokens(tokens, self_ty, &mut literal_changed);
+    if !literal_changed {
+        return TokenTree::Literal(literal.clone());
+    }
+    *changed = true;
+    let mut nested = Literal::string(&nested.to_string());
+    nested.set_span(literal.span());
+    TokenTree::Literal(nested)
+}
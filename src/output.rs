@@ -0,0 +1,216 @@
+//! Conversion of the results returned by test functions into `TestCaseResult`.
+
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, quote_spanned};
+use syn::{spanned::Spanned, Error, GenericArgument, Ident, Path, PathArguments, ReturnType, Type};
+
+/// Types that are certainly not a `Result`. Other paths may be aliases of a `Result`, so they are
+/// left for the compiler to check.
+const NON_RESULT_TYPES: &[&str] = &[
+    "bool", "char", "str", "String", "Option", "Vec", "Box", "i8", "i16", "i32", "i64", "i128",
+    "isize", "u8", "u16", "u32", "u64", "u128", "usize", "f32", "f64",
+];
+
+/// Report a return type of a test function that cannot be converted into `TestCaseResult`, which
+/// would otherwise fail with a type error inside the generated code.
+pub(crate) fn check_return_type(output: &ReturnType) -> syn::Result<()> {
+    let ty = match output {
+        ReturnType::Default => return Ok(()),
+        ReturnType::Type(_, ty) => ty,
+    };
+    let is_result = match ungroup(ty) {
+        Type::Tuple(tuple) => tuple.elems.is_empty(),
+        Type::Macro(_) | Type::Verbatim(_) => true,
+        Type::Path(path) => !path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| NON_RESULT_TYPES.iter().any(|name| segment.ident == name)),
+        _ => false,
+    };
+    if !is_result {
+        return Err(Error::new_spanned(
+            ty,
+            "test functions must return `()` or a `Result`: remove the return type to test with \
+             assertions, or return `Result<(), TestCaseError>` to use `prop_assert!`",
+        ));
+    }
+
+    // The success value is not used, so it has to be `()`
+    if let Some(ok_ty) = result_ok_type(ty) {
+        if !is_unit(ok_ty) {
+            return Err(Error::new_spanned(
+                ok_ty,
+                "test functions must return `Result<(), _>`: the success value is not used, \
+                 return `Ok(())` instead",
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Whether the return type of a test function is `()`, so that it only uses assertions. Other
+/// types, including aliases of `()`, are converted with the items from `conversion_items`.
+pub(crate) fn returns_unit(output: &ReturnType) -> bool {
+    match output {
+        ReturnType::Default => true,
+        ReturnType::Type(_, ty) => is_unit(ty),
+    }
+}
+
+/// Whether the return type of a test function is named `Result`, so that the value checked by a
+/// postcondition is its success value.
+pub(crate) fn returns_result(output: &ReturnType) -> bool {
+    match output {
+        ReturnType::Default => false,
+        ReturnType::Type(_, ty) => result_ok_type(ty).is_some(),
+    }
+}
+
+fn is_unit(ty: &Type) -> bool {
+    matches!(ungroup(ty), Type::Tuple(tuple) if tuple.elems.is_empty())
+}
+
+/// The type inside parentheses and invisible groups (such as `$ty` of `macro_rules!`).
+fn ungroup(ty: &Type) -> &Type {
+    match ty {
+        Type::Paren(paren) => ungroup(&paren.elem),
+        Type::Group(group) => ungroup(&group.elem),
+        ty => ty,
+    }
+}
+
+/// The first type argument of a type named `Result`, such as `T` in `io::Result<T>`.
+fn result_ok_type(ty: &Type) -> Option<&Type> {
+    let segment = match ungroup(ty) {
+        Type::Path(path) => path.path.segments.last()?,
+        _ => return None,
+    };
+    if segment.ident != "Result" {
+        return None;
+    }
+    match &segment.arguments {
+        PathArguments::AngleBracketed(args) => args.args.iter().find_map(|arg| match arg {
+            GenericArgument::Type(ty) => Some(ty),
+            _ => None,
+        }),
+        _ => None,
+    }
+}
+
+/// Items converting the result of a test function into `TestCaseResult`. Aliases (such as
+/// `TestCaseResult` or a user `type`) are only resolved by the compiler, so `TestOutput` accepts
+/// both `()` and `Result<(), E>` whatever their spelling. Errors that already are `TestCaseError`
+/// are kept as is (so that rejections still work), other errors fail the test case with their
+/// `Debug` representation. The choice is made with autoref-based specialization: the method of
+/// `TestCaseErrorKind` takes precedence over the one of `DebugErrorKind` because it needs one less
+/// reference.
+pub(crate) fn conversion_items(krate: &Path) -> TokenStream {
+    quote! {
+        trait TestOutput {
+            type Error;
+            fn into_result(self) -> ::core::result::Result<(), Self::Error>;
+        }
+        impl TestOutput for () {
+            type Error = #krate::test_runner::TestCaseError;
+            fn into_result(self) -> ::core::result::Result<(), Self::Error> {
+                ::core::result::Result::Ok(())
+            }
+        }
+        impl<E> TestOutput for ::core::result::Result<(), E> {
+            type Error = E;
+            fn into_result(self) -> ::core::result::Result<(), E> {
+                self
+            }
+        }
+
+        struct TestCaseErrorTag;
+        impl TestCaseErrorTag {
+            fn convert(
+                self,
+                err: #krate::test_runner::TestCaseError,
+            ) -> #krate::test_runner::TestCaseError {
+                err
+            }
+        }
+        trait TestCaseErrorKind {
+            fn proptest_attr_error_kind(&self) -> TestCaseErrorTag {
+                TestCaseErrorTag
+            }
+        }
+        impl TestCaseErrorKind for #krate::test_runner::TestCaseError {}
+
+        struct DebugErrorTag;
+        impl DebugErrorTag {
+            fn convert<E: ::core::fmt::Debug>(
+                self,
+                err: E,
+            ) -> #krate::test_runner::TestCaseError {
+                let mut message = #krate::std_facade::String::new();
+                let _ = ::core::fmt::Write::write_fmt(
+                    &mut message,
+                    ::core::format_args!("{:?}", err),
+                );
+                #krate::test_runner::TestCaseError::fail(message)
+            }
+        }
+        trait DebugErrorKind {
+            fn proptest_attr_error_kind(&self) -> DebugErrorTag {
+                DebugErrorTag
+            }
+        }
+        impl<E: ::core::fmt::Debug> DebugErrorKind for &E {}
+    }
+}
+
+/// Convert the error of `result` with the items from `conversion_items`.
+pub(crate) fn convert(result: TokenStream, span: Span) -> TokenStream {
+    quote_spanned! {span=>
+        {
+            let result = TestOutput::into_result(#result);
+            ::core::result::Result::map_err(result, |err| {
+                (&err).proptest_attr_error_kind().convert(err)
+            })
+        }
+    }
+}
+
+/// Check the value returned by `call` with `postcondition`, which takes a reference to the
+/// `values` the property is called with (a tuple if there are several) and the returned value.
+/// The values are cloned before the call moves them. The error of a property returning a `Result`
+/// fails the test case the same way as with `convert`, and so does the one of the postcondition.
+pub(crate) fn postcondition(
+    postcondition: &Path,
+    values: &[Ident],
+    call: TokenStream,
+    returns_result: bool,
+    span: Span,
+) -> TokenStream {
+    let inputs = match values {
+        [value] => quote! { ::core::clone::Clone::clone(&#value) },
+        values => quote! { ( #(::core::clone::Clone::clone(&#values),)* ) },
+    };
+    let output = if returns_result {
+        quote_spanned! {span=>
+            ::core::result::Result::map_err(#call, |err| {
+                (&err).proptest_attr_error_kind().convert(err)
+            })
+        }
+    } else {
+        quote! { ::core::result::Result::Ok(#call) }
+    };
+    let check = convert(
+        quote! { #postcondition(&inputs, output) },
+        postcondition.span(),
+    );
+    quote! {
+        {
+            let inputs = #inputs;
+            match #output {
+                ::core::result::Result::Ok(output) => #check,
+                ::core::result::Result::Err(err) => ::core::result::Result::Err(err),
+            }
+        }
+    }
+}
@@ -0,0 +1,35 @@
+//! Driving the strategies with raw bytes (e.g. fuzzer inputs) through the `PassThrough` RNG.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Path;
+
+/// The number of bytes the RNG of each case reads from. Strategies deriving RNGs split the bytes
+/// between them, so there should be enough for a few levels of nesting.
+const PASS_THROUGH_LEN: usize = 1 << 16;
+
+/// A `TestRng` returning the bytes of `input` (an expression of type `&[u8]`).
+pub(crate) fn rng(krate: &Path, input: TokenStream) -> TokenStream {
+    quote! {
+        {
+            let input: &[u8] = #input;
+            // The RNG returns zeros once its bytes are exhausted, with which uniform sampling
+            // never terminates, so the input is extended with bytes derived from it
+            let mut bytes = ::std::vec::Vec::with_capacity(#PASS_THROUGH_LEN);
+            bytes.extend_from_slice(input);
+            let mut state = input.iter().fold(0xcbf2_9ce4_8422_2325u64, |state, byte| {
+                (state ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3)
+            }) | 1;
+            while bytes.len() < #PASS_THROUGH_LEN {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                bytes.extend_from_slice(&state.to_le_bytes());
+            }
+            #krate::test_runner::TestRng::from_seed(
+                #krate::test_runner::RngAlgorithm::PassThrough,
+                &bytes,
+            )
+        }
+    }
+}
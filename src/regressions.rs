@@ -0,0 +1,112 @@
+//! Replaying the failures persisted in regression files before the random test cases.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{LitStr, Path};
+
+/// Run each seed persisted for the test before the random cases with the `run` closure (taking a
+/// runner), using a runner with the configuration of the `runner` variable and no random cases.
+/// The seeds are read from the regression file of the test unless the configuration persists
+/// failures, in which case the runner replays them itself, and from the files given with
+/// `regressions` (relative to the crate root). Evaluates to the result of the run.
+pub(crate) fn replay(krate: &Path, regressions: Option<&LitStr>) -> TokenStream {
+    let shared = regressions.map(|regressions| {
+        quote! {
+            let path = ::std::path::Path::new(::core::env!("CARGO_MANIFEST_DIR")).join(#regressions);
+            // A corpus that has not been created yet is empty
+            let mut paths: ::std::vec::Vec<_> = match ::std::fs::read_dir(&path) {
+                ::core::result::Result::Ok(entries) => entries
+                    .map(|entry| match entry {
+                        ::core::result::Result::Ok(entry) => entry.path(),
+                        ::core::result::Result::Err(err) => ::core::panic!(
+                            "cannot read the regressions directory {}: {}",
+                            path.display(),
+                            err,
+                        ),
+                    })
+                    .filter(|path| path.is_file())
+                    .collect(),
+                ::core::result::Result::Err(_) if path.is_file() => ::std::vec![path],
+                ::core::result::Result::Err(err)
+                    if err.kind() == ::std::io::ErrorKind::NotFound =>
+                {
+                    ::std::vec::Vec::new()
+                }
+                ::core::result::Result::Err(err) => ::core::panic!(
+                    "cannot read the regressions directory {}: {}",
+                    path.display(),
+                    err,
+                ),
+            };
+            paths.sort();
+            for path in paths {
+                let contents = match ::std::fs::read_to_string(&path) {
+                    ::core::result::Result::Ok(contents) => contents,
+                    ::core::result::Result::Err(err) => ::core::panic!(
+                        "cannot read the regression file {}: {}",
+                        path.display(),
+                        err,
+                    ),
+                };
+                for line in contents.lines() {
+                    // The value the seed shrinks to is written after the seed as a comment
+                    let seed = line.split('#').next().unwrap_or_default().trim();
+                    if seed.is_empty() {
+                        continue;
+                    }
+                    match ::core::str::FromStr::from_str(seed) {
+                        ::core::result::Result::Ok(seed) => seeds.push(seed),
+                        ::core::result::Result::Err(_) => ::core::panic!(
+                            "invalid seed in the regression file {}: {}",
+                            path.display(),
+                            line,
+                        ),
+                    }
+                }
+            }
+        }
+    });
+
+    quote! {
+        {
+            let config = runner.config();
+            let mut seeds = ::std::vec::Vec::<#krate::test_runner::PersistedSeed>::new();
+            // Persistence disabled for the whole run with the environment variable is respected
+            let disabled = ::std::env::var_os("PROPTEST_DISABLE_FAILURE_PERSISTENCE").is_some();
+            if config.failure_persistence.is_none() && !disabled {
+                seeds.extend(#krate::test_runner::FailurePersistence::load_persisted_failures2(
+                    &<#krate::test_runner::FileFailurePersistence as ::core::default::Default>::default(),
+                    config.source_file,
+                ));
+            }
+            #shared
+            if seeds.is_empty() {
+                ::core::result::Result::Ok(())
+            } else {
+                let mut persistence = #krate::test_runner::MapFailurePersistence::default();
+                for seed in seeds {
+                    #krate::test_runner::FailurePersistence::save_persisted_failure2(
+                        &mut persistence,
+                        config.source_file,
+                        seed,
+                        &(),
+                    );
+                }
+                // The RNG is only used for the random cases, each persisted one has its own
+                let mut regressions_runner = #krate::test_runner::TestRunner::new_with_rng(
+                    #krate::test_runner::Config {
+                        cases: 0,
+                        failure_persistence: ::core::option::Option::Some(
+                            #krate::std_facade::Box::new(persistence),
+                        ),
+                        ..::core::clone::Clone::clone(config)
+                    },
+                    #krate::test_runner::TestRng::deterministic_rng(
+                        #krate::test_runner::RngAlgorithm::ChaCha,
+                    ),
+                );
+                run(&mut regressions_runner)
+            }
+        }
+    }
+}
@@ -0,0 +1,132 @@
+//! Shrinking the values of an argument with the function given with `#[shrink_with(...)]`.
+
+use crate::args::SpannedExpr;
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+use syn::Path;
+
+/// Shrink the values of `strategy` with `shrinker`, a function taking a failing value and
+/// returning simpler values to try instead of the shrinking of the strategy itself. The first of
+/// them that still fails is shrunk the same way, until none of the simpler values fail.
+pub(crate) fn shrink_with(
+    krate: &Path,
+    strategy: TokenStream,
+    shrinker: &SpannedExpr,
+) -> TokenStream {
+    // A shrinker of the wrong type is reported at the shrinker
+    let wrapped = quote_spanned!(shrinker.span=> shrink_with(#strategy, #shrinker));
+    quote! {
+        {
+            struct ShrinkWith<S, F>(S, F);
+
+            impl<S: ::core::fmt::Debug, F> ::core::fmt::Debug for ShrinkWith<S, F> {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    f.debug_tuple("ShrinkWith").field(&self.0).finish()
+                }
+            }
+
+            impl<S, F, I> #krate::strategy::Strategy for ShrinkWith<S, F>
+            where
+                S: #krate::strategy::Strategy,
+                S::Value: ::core::clone::Clone,
+                F: ::core::ops::Fn(&S::Value) -> I + ::core::clone::Clone,
+                I: ::core::iter::IntoIterator<Item = S::Value>,
+            {
+                type Tree = ShrinkWithTree<S::Value, F>;
+                type Value = S::Value;
+
+                fn new_tree(
+                    &self,
+                    runner: &mut #krate::test_runner::TestRunner,
+                ) -> #krate::strategy::NewTree<Self> {
+                    let tree = #krate::strategy::Strategy::new_tree(&self.0, runner)?;
+                    ::core::result::Result::Ok(ShrinkWithTree {
+                        current: #krate::strategy::ValueTree::current(&tree),
+                        failing: ::core::option::Option::None,
+                        candidates: ::core::iter::IntoIterator::into_iter(
+                            #krate::std_facade::Vec::new(),
+                        ),
+                        shrinker: ::core::clone::Clone::clone(&self.1),
+                        shrunk: false,
+                    })
+                }
+            }
+
+            struct ShrinkWithTree<T, F> {
+                current: T,
+                /// The last value the test failed for, which the candidates simplify.
+                failing: ::core::option::Option<T>,
+                candidates: #krate::std_facade::vec::IntoIter<T>,
+                shrinker: F,
+                /// Whether the test passes for all the simpler values of the current one.
+                shrunk: bool,
+            }
+
+            impl<T, F, I> #krate::strategy::ValueTree for ShrinkWithTree<T, F>
+            where
+                T: ::core::clone::Clone + ::core::fmt::Debug,
+                F: ::core::ops::Fn(&T) -> I,
+                I: ::core::iter::IntoIterator<Item = T>,
+            {
+                type Value = T;
+
+                fn current(&self) -> T {
+                    ::core::clone::Clone::clone(&self.current)
+                }
+
+                fn simplify(&mut self) -> bool {
+                    if self.shrunk {
+                        return false;
+                    }
+                    let mut candidates: #krate::std_facade::Vec<T> =
+                        ::core::iter::Iterator::collect(
+                            ::core::iter::IntoIterator::into_iter((self.shrinker)(&self.current)),
+                        );
+                    if candidates.is_empty() {
+                        return false;
+                    }
+                    let candidate = candidates.remove(0);
+                    let failing = ::core::mem::replace(&mut self.current, candidate);
+                    self.failing = ::core::option::Option::Some(failing);
+                    self.candidates = ::core::iter::IntoIterator::into_iter(candidates);
+                    true
+                }
+
+                fn complicate(&mut self) -> bool {
+                    match ::core::iter::Iterator::next(&mut self.candidates) {
+                        ::core::option::Option::Some(candidate) => {
+                            self.current = candidate;
+                            true
+                        }
+                        // None of the simpler values fail, so the last failing one is tested again
+                        // and reported. Going back to it is a step like any other, the filters on
+                        // top of this strategy expect to get to a value they accept this way.
+                        ::core::option::Option::None => match self.failing.take() {
+                            ::core::option::Option::Some(failing) => {
+                                self.current = failing;
+                                self.shrunk = true;
+                                true
+                            }
+                            ::core::option::Option::None => false,
+                        },
+                    }
+                }
+            }
+
+            fn shrink_with<S, F, I>(
+                strategy: S,
+                shrinker: F,
+            ) -> impl #krate::strategy::Strategy<Value = S::Value>
+            where
+                S: #krate::strategy::Strategy,
+                S::Value: ::core::clone::Clone,
+                F: ::core::ops::Fn(&S::Value) -> I + ::core::clone::Clone,
+                I: ::core::iter::IntoIterator<Item = S::Value>,
+            {
+                ShrinkWith(strategy, shrinker)
+            }
+
+            #wrapped
+        }
+    }
+}
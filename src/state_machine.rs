@@ -0,0 +1,472 @@
+//! The `#[proptest_state_machine]` attribute turning an `impl` block of a reference model into a
+//! `proptest-state-machine` test.
+
+use crate::args::{self, Arg, ProptestArgs, SpannedExpr};
+use crate::inputs::Input;
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{
+    Attribute, Error, FnArg, Ident, ImplItem, ImplItemMethod, ItemImpl, Path, ReturnType, Type,
+};
+
+/// Arguments of `#[proptest]` configuring the test runner and the reporting of failures, which also
+/// apply to state machine tests.
+const SUPPORTED_ARGS: &[&str] = &[
+    "crate",
+    "cases",
+    "timeout",
+    "max_shrink_iters",
+    "max_shrink_time",
+    "max_local_rejects",
+    "max_global_rejects",
+    "max_flat_map_regens",
+    "result_cache",
+    "env_overrides",
+    "verbose",
+    "fork",
+    "config",
+    "persistence",
+    "seed",
+    "rng",
+    "rng_bytes",
+    "replay",
+    "report",
+];
+
+/// A method of the model marked with `#[transition]`.
+struct Transition {
+    method: Ident,
+    variant: Ident,
+    inputs: Vec<Input>,
+    /// Whether the results of the model and the system under test are compared.
+    compare_output: bool,
+    /// The `cfg` attributes of the method, which also apply to its variant and strategy.
+    cfgs: Vec<Attribute>,
+}
+
+pub(crate) fn expand(args: Vec<Arg>, mut input: ItemImpl) -> syn::Result<TokenStream> {
+    let mut size = None;
+    let mut test_name = None;
+    let mut runner_args = Vec::new();
+    for arg in args {
+        if arg.name == "transitions" || arg.name == "test" {
+            let value = match &arg.value {
+                Some((_, value)) => value,
+                None => return Err(Error::new_spanned(arg, "unknown argument")),
+            };
+            if arg.name == "transitions" {
+                if size.is_some() {
+                    return Err(Error::new_spanned(
+                        &arg,
+                        "multiple `transitions` arguments are not allowed",
+                    ));
+                }
+                size = Some(SpannedExpr::from_expr("transitions", value)?);
+            } else {
+                if test_name.is_some() {
+                    return Err(Error::new_spanned(
+                        &arg,
+                        "multiple `test` arguments are not allowed",
+                    ));
+                }
+                let path = args::parse_path("test", value)?;
+                match path.get_ident() {
+                    Some(ident) => test_name = Some(ident.clone()),
+                    None => {
+                        return Err(Error::new_spanned(
+                            path,
+                            "invalid test: must be an identifier",
+                        ))
+                    }
+                }
+            }
+        } else if SUPPORTED_ARGS.iter().any(|name| arg.name == name) {
+            runner_args.push(arg);
+        } else {
+            return Err(Error::new_spanned(
+                arg,
+                "unknown argument for `proptest_state_machine`",
+            ));
+        }
+    }
+    let args = ProptestArgs::parse(runner_args)?;
+    let krate = args.krate();
+
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &input.generics,
+            "generic state machine models are not supported",
+        ));
+    }
+    if let Some((_, trait_, _)) = &input.trait_ {
+        return Err(Error::new_spanned(
+            trait_,
+            "`proptest_state_machine` must be applied to an inherent `impl` block",
+        ));
+    }
+    let model = match &*input.self_ty {
+        Type::Path(path) if path.qself.is_none() => match path.path.segments.last() {
+            Some(segment) => segment.ident.clone(),
+            None => unreachable!("paths have at least one segment"),
+        },
+        ty => {
+            return Err(Error::new_spanned(
+                ty,
+                "the state machine model must be a named type",
+            ))
+        }
+    };
+    let model_ty = &input.self_ty;
+
+    let mut init_state = None;
+    let mut sut_ty = None;
+    let mut check_invariants = false;
+    let mut transitions = Vec::new();
+    for item in &mut input.items {
+        let method = match item {
+            ImplItem::Method(method) => method,
+            _ => continue,
+        };
+
+        let is_transition = method
+            .attrs
+            .iter()
+            .any(|attr| attr.path.is_ident("transition"));
+        if is_transition {
+            method
+                .attrs
+                .retain(|attr| !attr.path.is_ident("transition"));
+            transitions.push(transition(method, &krate)?);
+            strip_arg_attrs(method);
+        } else if method.sig.ident == "init_state" {
+            let inputs = method
+                .sig
+                .inputs
+                .iter()
+                .cloned()
+                .map(|arg| Input::from_fn_arg(arg, &krate))
+                .collect::<syn::Result<Vec<_>>>()?;
+            init_state = Some(inputs);
+            strip_arg_attrs(method);
+        } else if method.sig.ident == "init_test" {
+            sut_ty = match &method.sig.output {
+                ReturnType::Type(_, ty) => Some(ty.clone()),
+                ReturnType::Default => {
+                    return Err(Error::new_spanned(
+                        &method.sig,
+                        "`init_test` must return the system under test",
+                    ))
+                }
+            };
+        } else if method.sig.ident == "check_invariants" {
+            check_invariants = true;
+        }
+    }
+
+    let init_state = init_state.ok_or_else(|| {
+        Error::new_spanned(
+            &input.self_ty,
+            "the state machine model requires an `init_state` function",
+        )
+    })?;
+    let sut_ty = sut_ty.ok_or_else(|| {
+        Error::new_spanned(
+            &input.self_ty,
+            "the state machine model requires an `init_test` method",
+        )
+    })?;
+    if transitions.is_empty() {
+        return Err(Error::new_spanned(
+            &input.self_ty,
+            "the state machine model requires at least one `#[transition]` method",
+        ));
+    }
+
+    let transition_ty = format_ident!("{}Transition", model);
+    let machine = format_ident!("{}StateMachine", model);
+    let test_name = test_name
+        .unwrap_or_else(|| format_ident!("{}_state_machine", snake_case(&model.to_string())));
+    let size = match &size {
+        Some(size) => quote! { #size },
+        None => quote! { 1..20 },
+    };
+
+    let init_state_strategy = crate::strategy::strategy(&args, &init_state)?;
+    let (init_state_values, init_state_args) = closure_values(&init_state);
+
+    let variants = transitions.iter().map(|transition| {
+        let cfgs = &transition.cfgs;
+        let variant = &transition.variant;
+        let tys = transition.inputs.iter().map(|input| &input.ty);
+        quote! { #(#cfgs)* #variant(#(#tys),*) }
+    });
+
+    let mut transition_strategies = Vec::with_capacity(transitions.len());
+    let mut reference_arms = Vec::with_capacity(transitions.len());
+    let mut test_arms = Vec::with_capacity(transitions.len());
+    for transition in &transitions {
+        let Transition {
+            method,
+            variant,
+            inputs,
+            compare_output,
+            cfgs,
+        } = transition;
+
+        let strategy = crate::strategy::strategy(&args, inputs)?;
+        let (values, idents) = closure_values(inputs);
+        transition_strategies.push(quote! {
+            #(#cfgs)*
+            strategies.push(#krate::strategy::Strategy::boxed(
+                #krate::strategy::Strategy::prop_map(
+                    #strategy,
+                    |#values| #transition_ty::#variant(#(#idents),*),
+                ),
+            ));
+        });
+
+        reference_arms.push(quote! {
+            #(#cfgs)*
+            #transition_ty::#variant(#(#idents),*) => {
+                let _ = state.#method(#(::core::clone::Clone::clone(#idents)),*);
+            }
+        });
+
+        let check = if *compare_output {
+            let method_name = method.to_string();
+            quote! {
+                let expected = model.#method(#(::core::clone::Clone::clone(&#idents)),*);
+                let actual = sut.#method(#(#idents),*);
+                ::core::assert_eq!(
+                    actual,
+                    expected,
+                    "`{}` returned different results for the model and the system under test",
+                    #method_name,
+                );
+            }
+        } else {
+            quote! {
+                model.#method(#(::core::clone::Clone::clone(&#idents)),*);
+                sut.#method(#(#idents),*);
+            }
+        };
+        test_arms.push(quote! {
+            #(#cfgs)*
+            #transition_ty::#variant(#(#idents),*) => {
+                #check
+            }
+        });
+    }
+
+    let check_invariants = if check_invariants {
+        quote! { #model_ty::check_invariants(ref_state, &state.0); }
+    } else {
+        quote! { let _ = (state, ref_state); }
+    };
+
+    let runner_settings = crate::runner_settings(&args, &test_name);
+    let runner = crate::runner(&args);
+    let cases_setup = crate::failure::setup();
+    let case = crate::failure::count(
+        quote! {
+            <#machine as ::proptest_state_machine::StateMachineTest>::test_sequential(
+                ::core::clone::Clone::clone(&config),
+                initial_state,
+                transitions,
+                seen_counter,
+            );
+            ::core::result::Result::Ok(())
+        },
+        Span::call_site(),
+    );
+    let report = crate::failure::report(
+        &krate,
+        &test_name,
+        quote! { result },
+        quote! { runner.config().fork() },
+        quote! { &seed },
+        args.report(),
+        args.format_failure.as_ref(),
+        false,
+    );
+
+    let attrs = crate::cfg_and_lint_attrs(&input.attrs);
+    let test_attr = args.report().test_attr();
+    let output = args.report().output();
+    let pass = args.report().pass();
+    Ok(quote! {
+        #input
+
+        #(#attrs)*
+        #[derive(Clone, Debug)]
+        enum #transition_ty {
+            #(#variants,)*
+        }
+
+        #(#attrs)*
+        struct #machine;
+
+        #(#attrs)*
+        impl ::proptest_state_machine::ReferenceStateMachine for #machine {
+            type State = #model_ty;
+            type Transition = #transition_ty;
+
+            fn init_state() -> #krate::strategy::BoxedStrategy<Self::State> {
+                #krate::strategy::Strategy::boxed(#krate::strategy::Strategy::prop_map(
+                    #init_state_strategy,
+                    |#init_state_values| #model_ty::init_state(#(#init_state_args),*),
+                ))
+            }
+
+            fn transitions(
+                _state: &Self::State,
+            ) -> #krate::strategy::BoxedStrategy<Self::Transition> {
+                let mut strategies = ::std::vec::Vec::new();
+                #(#transition_strategies)*
+                #krate::strategy::Strategy::boxed(#krate::strategy::Union::new(strategies))
+            }
+
+            fn apply(mut state: Self::State, transition: &Self::Transition) -> Self::State {
+                match transition {
+                    #(#reference_arms)*
+                }
+                state
+            }
+        }
+
+        #(#attrs)*
+        impl ::proptest_state_machine::StateMachineTest for #machine {
+            // The model is applied to its own copy of the state to compare the results of the
+            // transitions with the system under test
+            type SystemUnderTest = (#sut_ty, #model_ty);
+            type Reference = Self;
+
+            fn init_test(ref_state: &#model_ty) -> Self::SystemUnderTest {
+                (
+                    #model_ty::init_test(ref_state),
+                    ::core::clone::Clone::clone(ref_state),
+                )
+            }
+
+            fn apply(
+                (mut sut, mut model): Self::SystemUnderTest,
+                _ref_state: &#model_ty,
+                transition: #transition_ty,
+            ) -> Self::SystemUnderTest {
+                match transition {
+                    #(#test_arms)*
+                }
+                (sut, model)
+            }
+
+            fn check_invariants(state: &Self::SystemUnderTest, ref_state: &#model_ty) {
+                #check_invariants
+            }
+        }
+
+        #(#attrs)*
+        #test_attr
+        fn #test_name() #output {
+            let strategy =
+                <#machine as ::proptest_state_machine::ReferenceStateMachine>::sequential_strategy(
+                    #size,
+                );
+            let runner_settings = #runner_settings;
+            let config = ::core::clone::Clone::clone(&runner_settings);
+            let (mut runner, seed) = #runner;
+            #cases_setup
+            let result = runner.run(&strategy, |(initial_state, transitions, seen_counter)| #case);
+            #report
+            #pass
+        }
+    })
+}
+
+/// Check the signature of a transition method and collect its arguments.
+fn transition(method: &ImplItemMethod, krate: &Path) -> syn::Result<Transition> {
+    let mut args = method.sig.inputs.iter();
+    match args.next() {
+        Some(FnArg::Receiver(receiver))
+            if receiver.reference.is_some() && receiver.mutability.is_some() => {}
+        _ => {
+            return Err(Error::new_spanned(
+                &method.sig,
+                "transition methods must take `&mut self`",
+            ))
+        }
+    }
+    let inputs = args
+        .cloned()
+        .map(|arg| Input::from_fn_arg(arg, krate))
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let compare_output = match &method.sig.output {
+        ReturnType::Default => false,
+        ReturnType::Type(_, ty) => !matches!(&**ty, Type::Tuple(tuple) if tuple.elems.is_empty()),
+    };
+
+    Ok(Transition {
+        method: method.sig.ident.clone(),
+        variant: format_ident!("{}", camel_case(&method.sig.ident.to_string())),
+        inputs,
+        compare_output,
+        cfgs: method
+            .attrs
+            .iter()
+            .filter(|attr| attr.path.is_ident("cfg"))
+            .cloned()
+            .collect(),
+    })
+}
+
+/// Remove the `#[strategy]` and `#[filter]` attributes, which are only meaningful to this macro.
+pub(crate) fn strip_arg_attrs(method: &mut ImplItemMethod) {
+    for arg in &mut method.sig.inputs {
+        if let FnArg::Typed(arg) = arg {
+            arg.attrs.retain(|attr| !crate::inputs::is_arg_attr(attr));
+        }
+    }
+}
+
+/// A closure argument binding the generated values to fresh identifiers, and the identifiers.
+fn closure_values(inputs: &[Input]) -> (TokenStream, Vec<Ident>) {
+    let idents: Vec<_> = (0..inputs.len())
+        .map(|i| format_ident!("arg{}", i))
+        .collect();
+    let tys = inputs.iter().map(|input| &input.ty);
+    let values = match inputs.len() {
+        0 => quote! { (): () },
+        1 => quote! { #(#idents)*: #(#tys)* },
+        _ => quote! { ( #(#idents),* ): ( #(#tys),* ) },
+    };
+    (values, idents)
+}
+
+/// `push_back` to `PushBack`.
+fn camel_case(name: &str) -> String {
+    name.split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+/// `StackModel` to `stack_model`.
+pub(crate) fn snake_case(name: &str) -> String {
+    let mut result = String::new();
+    for c in name.chars() {
+        if c.is_uppercase() {
+            if !result.is_empty() {
+                result.push('_');
+            }
+            result.extend(c.to_lowercase());
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
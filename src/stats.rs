@@ -0,0 +1,129 @@
+//! Statistics of the generated values written as JSON with the `stats_out` argument, and the
+//! records of the runs appended with the `json_report` argument.
+
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use syn::{LitStr, Path};
+
+/// The variable holding the statistics of the run.
+pub(crate) fn setup() -> TokenStream {
+    quote! {
+        let stats = ::proptest_attr_runtime::Stats::new();
+    }
+}
+
+/// Record the size of the `values` of a test case and the outcome of `block`. The cases run after
+/// the first failure are the shrinking iterations.
+pub(crate) fn record(krate: &Path, values: &[Ident], block: TokenStream) -> TokenStream {
+    let values = match values {
+        [value] => quote! { &#value },
+        values => quote! { ( #(&#values,)* ) },
+    };
+    quote! {
+        {
+            let size = ::std::format!("{:?}", #values).len();
+            let outcome = ::std::panic::catch_unwind(::std::panic::AssertUnwindSafe(|| #block));
+            let case_outcome = match &outcome {
+                ::core::result::Result::Ok(::core::result::Result::Ok(())) => {
+                    ::proptest_attr_runtime::Outcome::Passed
+                }
+                ::core::result::Result::Ok(::core::result::Result::Err(
+                    #krate::test_runner::TestCaseError::Reject(_),
+                )) => ::proptest_attr_runtime::Outcome::Rejected,
+                _ => ::proptest_attr_runtime::Outcome::Failed,
+            };
+            stats.record(size, case_outcome);
+            match outcome {
+                ::core::result::Result::Ok(result) => result,
+                ::core::result::Result::Err(payload) => ::std::panic::resume_unwind(payload),
+            }
+        }
+    }
+}
+
+/// Write the statistics to `path` (relative to the crate root, with `<test>` replaced by the name
+/// of the test) once the run finishes with `result`, a `TestRunResult`. The counts of the
+/// `classify` labels are included when `classification` is set.
+pub(crate) fn write(test_name: &Ident, path: &LitStr, classification: bool) -> TokenStream {
+    let path = path.value().replace("<test>", &test_name.to_string());
+    let classification = if classification {
+        quote! { ::core::option::Option::Some(&classification) }
+    } else {
+        quote! { ::core::option::Option::None }
+    };
+    let test = full_name(test_name);
+    quote! {
+        stats.write(
+            &::std::path::Path::new(::core::env!("CARGO_MANIFEST_DIR")).join(#path),
+            #test,
+            result.is_ok(),
+            #classification,
+        );
+    }
+}
+
+/// Append a record of the run finished with `result` to `path` (relative to the crate root, with
+/// `<test>` replaced by the name of the test) as a line of JSON: the seed of the run, the numbers
+/// of cases, rejected cases and shrinking iterations, and the error and the minimal failing input
+/// if it fails. `aggregated` is set when `result` has the failures combined into a message instead
+/// of being a `TestRunResult`, in which case the failing input is not known.
+pub(crate) fn append_report(
+    krate: &Path,
+    test_name: &Ident,
+    path: &LitStr,
+    aggregated: bool,
+) -> TokenStream {
+    let path = path.value().replace("<test>", &test_name.to_string());
+    let failure = if aggregated {
+        quote! {
+            match &result {
+                ::core::result::Result::Err(message) => (
+                    ::core::option::Option::Some(::std::string::ToString::to_string(message)),
+                    ::core::option::Option::None,
+                ),
+                ::core::result::Result::Ok(()) => {
+                    (::core::option::Option::None, ::core::option::Option::None)
+                }
+            }
+        }
+    } else {
+        quote! {
+            match &result {
+                ::core::result::Result::Err(#krate::test_runner::TestError::Fail(reason, value)) => (
+                    ::core::option::Option::Some(::std::string::ToString::to_string(reason)),
+                    ::core::option::Option::Some(::std::format!("{:?}", value)),
+                ),
+                ::core::result::Result::Err(error) => (
+                    ::core::option::Option::Some(::std::string::ToString::to_string(error)),
+                    ::core::option::Option::None,
+                ),
+                ::core::result::Result::Ok(()) => {
+                    (::core::option::Option::None, ::core::option::Option::None)
+                }
+            }
+        }
+    };
+    let test = full_name(test_name);
+    quote! {
+        {
+            let (error, input): (
+                ::core::option::Option<::std::string::String>,
+                ::core::option::Option<::std::string::String>,
+            ) = #failure;
+            stats.append_report(
+                &::std::path::Path::new(::core::env!("CARGO_MANIFEST_DIR")).join(#path),
+                #test,
+                result.is_ok(),
+                seed.as_deref(),
+                error.as_deref(),
+                input.as_deref(),
+            );
+        }
+    }
+}
+
+/// The name of the test with the path of its module.
+fn full_name(test_name: &Ident) -> TokenStream {
+    let test_name = test_name.to_string();
+    quote! { ::core::concat!(::core::module_path!(), "::", #test_name) }
+}
@@ -0,0 +1,73 @@
+//! The `proptest_strategies!` macro defining named strategies that tests refer to with
+//! `strategy = "@name"`.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    parse::{Parse, ParseStream},
+    Attribute, Expr, Ident, Token, Type, Visibility,
+};
+
+/// A single `name: Type = expr;` definition.
+struct Definition {
+    attrs: Vec<Attribute>,
+    vis: Visibility,
+    name: Ident,
+    value_type: Type,
+    strategy: Expr,
+}
+
+impl Parse for Definition {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+        let vis = input.parse()?;
+        let name = input.parse()?;
+        input.parse::<Token![:]>()?;
+        let value_type = input.parse()?;
+        input.parse::<Token![=]>()?;
+        let strategy = input.parse()?;
+        input.parse::<Token![;]>()?;
+        Ok(Definition {
+            attrs,
+            vis,
+            name,
+            value_type,
+            strategy,
+        })
+    }
+}
+
+/// The definitions given to the macro.
+pub(crate) struct Strategies(Vec<Definition>);
+
+impl Parse for Strategies {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut definitions = Vec::new();
+        while !input.is_empty() {
+            definitions.push(input.parse()?);
+        }
+        Ok(Strategies(definitions))
+    }
+}
+
+/// Turn each definition into a function returning the strategy. The type of the values has to
+/// be spelled out, because it cannot be inferred for the return type of a function.
+pub(crate) fn expand(strategies: Strategies) -> TokenStream {
+    let functions = strategies.0.into_iter().map(
+        |Definition {
+             attrs,
+             vis,
+             name,
+             value_type,
+             strategy,
+         }| {
+            quote! {
+                #(#attrs)*
+                #vis fn #name() -> impl ::proptest::strategy::Strategy<Value = #value_type> {
+                    #strategy
+                }
+            }
+        },
+    );
+    quote! { #(#functions)* }
+}
@@ -0,0 +1,495 @@
+//! Construction of the strategy expression for a test.
+
+use crate::args::{ProptestArgs, SizeHint, SpannedExpr};
+use crate::inputs::Input;
+use crate::shrink_with::shrink_with;
+use proc_macro2::{Span, TokenStream, TokenTree};
+use quote::{quote, quote_spanned, ToTokens};
+use syn::{
+    parse_quote_spanned, spanned::Spanned, Error, Expr, GenericArgument, Pat, Path, PathArguments,
+    Type,
+};
+
+/// Build the strategy expression either from the `strategy` argument or from the per-argument
+/// `#[strategy(...)]` attributes. Arguments without a strategy use their `Arbitrary`
+/// implementation. The functions given with `#[map(...)]` and then the filters are applied on top
+/// of the respective strategies.
+pub(crate) fn strategy(args: &ProptestArgs, inputs: &[Input]) -> syn::Result<TokenStream> {
+    let krate = &args.krate();
+    let named = match &args.strategy {
+        Some(strategy) => named_strategies(strategy, inputs)?,
+        None => None,
+    };
+    let strategy = if let (Some(strategy), None) = (&args.strategy, &named) {
+        if let Some(input_strategy) = inputs.iter().find_map(|input| input.strategy.as_ref()) {
+            return Err(Error::new(
+                input_strategy.span,
+                "per-argument strategies cannot be combined with the `strategy` argument",
+            ));
+        }
+
+        if let Some(map) = inputs.iter().find_map(|input| input.map.as_ref()) {
+            return Err(Error::new(
+                map.span,
+                "`#[map]` cannot be combined with the `strategy` argument",
+            ));
+        }
+
+        if let Some(no_shrink) = inputs.iter().find_map(|input| input.no_shrink.as_ref()) {
+            return Err(Error::new_spanned(
+                no_shrink,
+                "`#[no_shrink]` cannot be combined with the `strategy` argument, use the \
+                 `no_shrink` argument to disable shrinking of the whole strategy",
+            ));
+        }
+
+        if let Some(shrink_with) = inputs.iter().find_map(|input| input.shrink_with.as_ref()) {
+            return Err(Error::new(
+                shrink_with.span,
+                "`#[shrink_with]` cannot be combined with the `strategy` argument",
+            ));
+        }
+
+        check_arity(strategy, inputs)?;
+
+        let tys: Vec<_> = inputs.iter().map(|input| &input.ty).collect();
+        let value_type = if tys.len() == 1 {
+            quote! { #(#tys)* }
+        } else {
+            quote! { ( #(#tys),* ) }
+        };
+        let span = tys.first().map_or(strategy.span, |ty| ty.span());
+        let strategy = typed(krate, quote! { #strategy }, value_type, span);
+
+        // Per-argument filters can only be applied to the whole tuple here
+        let filters = inputs.iter().flat_map(|input| &input.filters);
+        filter(krate, strategy, &ref_pat(inputs), filters)
+    } else {
+        let named = named.unwrap_or_default();
+        let with_rng = args.with_rng.unwrap_or(false);
+        let mut strategies: Vec<_> = inputs
+            .iter()
+            .enumerate()
+            .map(|(i, input)| {
+                let ty = &input.ty;
+                let strategy = named.get(i).and_then(Option::as_ref);
+                let strategy = match (strategy.or(input.strategy.as_ref()), &input.map) {
+                    (Some(strategy), None) if with_rng && i == inputs.len() - 1 => {
+                        typed_closure(krate, quote! { #strategy }, quote! { #ty }, ty.span())
+                    }
+                    (Some(strategy), None) => {
+                        typed(krate, quote! { #strategy }, quote! { #ty }, ty.span())
+                    }
+                    (None, None) => match args.size.as_ref().and_then(|size| params(size, ty)) {
+                        Some(params) => quote_spanned! {ty.span()=>
+                            #krate::arbitrary::any_with::<#ty>(#params)
+                        },
+                        None => quote_spanned!(ty.span()=> #krate::arbitrary::any::<#ty>()),
+                    },
+                    // The type of the raw values is inferred from the function
+                    (strategy, Some(map)) => {
+                        let strategy = match strategy {
+                            Some(strategy) => quote_spanned! {map.span=>
+                                #krate::strategy::Strategy::prop_map(#strategy, #map)
+                            },
+                            None => map_any(krate, map),
+                        };
+                        typed_closure(krate, strategy, quote! { #ty }, ty.span())
+                    }
+                };
+                // The simpler values are checked against the filters like any other value
+                let strategy = match (&input.shrink_with, &input.no_shrink) {
+                    (Some(shrinker), None) => shrink_with(krate, strategy, shrinker),
+                    (Some(shrinker), Some(_)) => {
+                        return Err(Error::new(
+                            shrinker.span,
+                            "`#[shrink_with]` cannot be combined with `#[no_shrink]`",
+                        ))
+                    }
+                    (None, _) => strategy,
+                };
+                let pat = input.ref_pat();
+                let strategy = filter(krate, strategy, &quote! { #pat: &#ty }, &input.filters);
+                Ok(match &input.no_shrink {
+                    Some(_) => no_shrink(krate, strategy),
+                    None => strategy,
+                })
+            })
+            .collect::<syn::Result<_>>()?;
+
+        match strategies.len() {
+            0 => quote! { #krate::arbitrary::any::<()>() },
+            1 => strategies.remove(0),
+            _ => quote! { ( #(#strategies),* ) },
+        }
+    };
+
+    let strategy = filter(krate, strategy, &ref_pat(inputs), &args.filter);
+    Ok(if args.no_shrink.unwrap_or(false) {
+        no_shrink(krate, strategy)
+    } else {
+        strategy
+    })
+}
+
+/// The `Arbitrary` parameters of `ty` limiting its collections and strings to `size`, if it has
+/// any the parameters of which are known. The parameters of the other types are left as default.
+fn params(size: &SizeHint, ty: &Type) -> Option<TokenStream> {
+    let default = || quote! { ::core::default::Default::default() };
+    match ty {
+        Type::Paren(ty) => params(size, &ty.elem),
+        Type::Group(ty) => params(size, &ty.elem),
+        Type::Array(ty) => params(size, &ty.elem),
+        Type::Tuple(ty) => {
+            let elems: Vec<_> = ty.elems.iter().map(|elem| params(size, elem)).collect();
+            if elems.iter().all(Option::is_none) {
+                return None;
+            }
+            let elems = elems.into_iter().map(|elem| elem.unwrap_or_else(default));
+            Some(quote! { ( #(#elems,)* ) })
+        }
+        Type::Path(ty) if ty.qself.is_none() => {
+            let segment = ty.path.segments.last()?;
+            let tys: Vec<_> = match &segment.arguments {
+                PathArguments::AngleBracketed(args) => args
+                    .args
+                    .iter()
+                    .filter_map(|arg| match arg {
+                        GenericArgument::Type(ty) => Some(ty),
+                        _ => None,
+                    })
+                    .collect(),
+                PathArguments::None => Vec::new(),
+                PathArguments::Parenthesized(_) => return None,
+            };
+            let (min, max) = (size.min, size.max);
+            let size_range = quote! { (#min..=#max).into() };
+            let name = segment.ident.to_string();
+            match (name.as_str(), tys.as_slice()) {
+                ("String", []) => {
+                    let regex = format!("\\PC{{{},{}}}", min, max);
+                    Some(quote! { ::core::convert::From::from(#regex) })
+                }
+                (
+                    "Vec" | "VecDeque" | "LinkedList" | "BinaryHeap" | "HashSet" | "BTreeSet",
+                    [elem],
+                ) => {
+                    let elem = params(size, elem).unwrap_or_else(default);
+                    Some(quote! { (#size_range, #elem) })
+                }
+                ("HashMap" | "BTreeMap", [key, value]) => {
+                    let key = params(size, key).unwrap_or_else(default);
+                    let value = params(size, value).unwrap_or_else(default);
+                    Some(quote! { (#size_range, #key, #value) })
+                }
+                ("Option", [inner]) => {
+                    let inner = params(size, inner)?;
+                    let probability = default();
+                    Some(quote! { (#probability, #inner) })
+                }
+                ("Box" | "Rc" | "Arc", [inner]) => params(size, inner),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Construct `strategy` once per process and keep it in a static, so that strategies which are
+/// expensive to build (e.g. regular expressions) are not rebuilt every time the test runs. The
+/// strategy must be `Send + Sync + 'static`.
+pub(crate) fn cached(krate: &Path, strategy: TokenStream, inputs: &[Input]) -> TokenStream {
+    let tys = inputs.iter().map(|input| &input.ty);
+    let value_type = if inputs.len() == 1 {
+        quote! { #(#tys)* }
+    } else {
+        quote! { ( #(#tys),* ) }
+    };
+    quote! {
+        {
+            static STRATEGY: ::std::sync::OnceLock<
+                #krate::strategy::SBoxedStrategy<#value_type>,
+            > = ::std::sync::OnceLock::new();
+            STRATEGY.get_or_init(|| #krate::strategy::Strategy::sboxed(#strategy))
+        }
+    }
+}
+
+/// Apply `map` to the values of `any::<A>()`, where `A` is the type `map` takes. Calling
+/// `prop_map` on `any()` directly would leave `A` uninferred.
+fn map_any(krate: &Path, map: &SpannedExpr) -> TokenStream {
+    quote_spanned! {map.span=>
+        {
+            fn map_any<A, O, F>(map: F) -> #krate::strategy::Map<
+                <A as #krate::arbitrary::Arbitrary>::Strategy,
+                F,
+            >
+            where
+                A: #krate::arbitrary::Arbitrary,
+                O: ::core::fmt::Debug,
+                F: ::core::ops::Fn(A) -> O,
+            {
+                #krate::strategy::Strategy::prop_map(#krate::arbitrary::any::<A>(), map)
+            }
+            map_any(#map)
+        }
+    }
+}
+
+/// Keep the values of `strategy` as they are generated when a test case fails.
+fn no_shrink(krate: &Path, strategy: TokenStream) -> TokenStream {
+    quote! { #krate::strategy::Strategy::no_shrink(#strategy) }
+}
+
+/// Make the last of `inputs` take a `TestRng` for `with_rng`. The RNG is derived from the one
+/// generating the rest of the case, so that it is the same when the case is replayed from its seed.
+/// `span` is where a test without arguments is reported.
+pub(crate) fn rng_input(args: &ProptestArgs, inputs: &mut [Input], span: Span) -> syn::Result<()> {
+    if let Some(strategy) = &args.strategy {
+        if named_strategies(strategy, inputs)?.is_none() {
+            return Err(Error::new(
+                strategy.span,
+                "`with_rng` requires the strategies of the `strategy` argument to be named, e.g. \
+                 `strategy = (a = 0..10)`",
+            ));
+        }
+    }
+    let input = match inputs.last_mut() {
+        Some(input) => input,
+        None => {
+            return Err(Error::new(
+                span,
+                "`with_rng` requires the last argument to take the RNG, e.g. `rng: TestRng`",
+            ))
+        }
+    };
+    if let Some(strategy) = &input.strategy {
+        return Err(Error::new(
+            strategy.span,
+            "the argument taking the RNG of `with_rng` cannot have a strategy",
+        ));
+    }
+    if let Some(map) = &input.map {
+        return Err(Error::new(
+            map.span,
+            "the argument taking the RNG of `with_rng` cannot have `#[map]`",
+        ));
+    }
+    let krate = args.krate();
+    let span = input.ty.span();
+    input.strategy = Some(SpannedExpr {
+        expr: parse_quote_spanned! {span=>
+            #krate::strategy::Strategy::prop_perturb(
+                #krate::strategy::Just(()),
+                |(), rng| rng,
+            )
+        },
+        span,
+    });
+    Ok(())
+}
+
+/// The strategies of the `strategy` argument written as `(a = 0..10, b = "[a-z]+")`, placed at the
+/// positions of the arguments they are named after. This way the tuple is built in the order of the
+/// arguments, and the arguments left out use their own strategies or their `Arbitrary`
+/// implementation. `None` if the strategy is not written this way.
+pub(crate) fn named_strategies(
+    strategy: &SpannedExpr,
+    inputs: &[Input],
+) -> syn::Result<Option<Vec<Option<SpannedExpr>>>> {
+    let elems: Vec<_> = match &strategy.expr {
+        Expr::Tuple(tuple) => tuple.elems.iter().collect(),
+        Expr::Paren(paren) => vec![&*paren.expr],
+        _ => return Ok(None),
+    };
+    if !elems.iter().any(|elem| matches!(elem, Expr::Assign(_))) {
+        return Ok(None);
+    }
+
+    let mut named: Vec<Option<SpannedExpr>> = inputs.iter().map(|_| None).collect();
+    for elem in elems {
+        let (name, value) = match elem {
+            Expr::Assign(assign) => {
+                let name = match &*assign.left {
+                    Expr::Path(left) if left.qself.is_none() => left.path.get_ident(),
+                    _ => None,
+                };
+                match name {
+                    Some(name) => (name, &*assign.right),
+                    None => {
+                        return Err(Error::new_spanned(
+                            &assign.left,
+                            "expected the name of an argument of the test function",
+                        ))
+                    }
+                }
+            }
+            elem => {
+                return Err(Error::new_spanned(
+                    elem,
+                    "expected `argument = strategy`: either all or none of the strategies in \
+                     the tuple must be named",
+                ))
+            }
+        };
+
+        let i = inputs
+            .iter()
+            .position(|input| matches!(&*input.pat, Pat::Ident(pat) if pat.ident == *name))
+            .ok_or_else(|| {
+                Error::new_spanned(
+                    name,
+                    format!("the test function has no argument named `{}`", name),
+                )
+            })?;
+        if named[i].is_some() {
+            return Err(Error::new_spanned(
+                elem,
+                format!("multiple strategies for `{}`", name),
+            ));
+        }
+        if let Some(input_strategy) = &inputs[i].strategy {
+            return Err(Error::new(
+                input_strategy.span,
+                format!(
+                    "`{}` already has a strategy in the `strategy` argument",
+                    name
+                ),
+            ));
+        }
+        // Taken as is like the elements of a tuple, so that a string literal is a regex
+        named[i] = Some(SpannedExpr {
+            expr: value.clone(),
+            span: value.span(),
+        });
+    }
+
+    Ok(Some(named))
+}
+
+/// Report a tuple strategy producing a different number of values than there are arguments, which
+/// would otherwise be reported as an obscure type error inside the test runner. A single argument
+/// may take a tuple, so it is not checked.
+fn check_arity(strategy: &SpannedExpr, inputs: &[Input]) -> syn::Result<()> {
+    let elems = match &strategy.expr {
+        Expr::Tuple(tuple) if inputs.len() != 1 => &tuple.elems,
+        _ => return Ok(()),
+    };
+
+    if let Some(extra) = elems.iter().nth(inputs.len()) {
+        return Err(Error::new_spanned(
+            extra,
+            format!(
+                "the strategy produces {} values, but the test function takes {} arguments",
+                elems.len(),
+                inputs.len()
+            ),
+        ));
+    }
+    if let Some(missing) = inputs.get(elems.len()) {
+        let pat = &missing.pat;
+        let ty = &missing.ty;
+        return Err(Error::new_spanned(
+            quote! { #pat: #ty },
+            format!(
+                "no value for this argument: the strategy produces {} values, but the test \
+                 function takes {} arguments",
+                elems.len(),
+                inputs.len()
+            ),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Require `strategy` to produce values of `value_type`. Without this, a strategy producing values
+/// of a wrong type is reported as a mismatch in the arguments of the closure passed to the test
+/// runner, while this reports the strategy expression and the expected type at `span`. A strategy
+/// of the wrong type fails the method lookup, so the mismatch is reported once and not again where
+/// the strategy is used. Types containing references are left as is, because their lifetimes
+/// cannot be elided in a bound.
+fn typed(krate: &Path, strategy: TokenStream, value_type: TokenStream, span: Span) -> TokenStream {
+    if has_reference(value_type.clone()) {
+        return strategy;
+    }
+    // The errors are reported at the strategy, and the unsatisfied bound at the expected type
+    let strategy_span = strategy.span();
+    let typed = quote_spanned!(strategy_span=> struct Typed<S>(S););
+    let call = quote_spanned!(strategy_span=> Typed(#strategy).typed_strategy());
+    quote_spanned! {span=>
+        {
+            #typed
+            impl<S: #krate::strategy::Strategy<Value = #value_type>> Typed<S> {
+                fn typed_strategy(self) -> S {
+                    self.0
+                }
+            }
+            #call
+        }
+    }
+}
+
+/// Require the values returned by a closure in `strategy`, the function given with `#[map(...)]` or
+/// the one taking the RNG of `with_rng`, to be of `value_type`. A closure returning values of a
+/// wrong type is reported at its return value, and only there.
+fn typed_closure(
+    krate: &Path,
+    strategy: TokenStream,
+    value_type: TokenStream,
+    span: Span,
+) -> TokenStream {
+    if has_reference(value_type.clone()) {
+        return strategy;
+    }
+    quote_spanned! {span=>
+        {
+            fn typed_strategy<S>(strategy: S) -> S
+            where
+                S: #krate::strategy::Strategy<Value = #value_type>,
+            {
+                strategy
+            }
+            typed_strategy(#strategy)
+        }
+    }
+}
+
+fn has_reference(tokens: TokenStream) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Punct(punct) => punct.as_char() == '&',
+        TokenTree::Group(group) => has_reference(group.stream()),
+        _ => false,
+    })
+}
+
+/// A closure argument binding references to all test function arguments.
+fn ref_pat(inputs: &[Input]) -> TokenStream {
+    let pats = inputs.iter().map(Input::ref_pat);
+    let tys = inputs.iter().map(|input| &input.ty);
+    if inputs.len() == 1 {
+        quote! { #(#pats)*: &#(#tys)* }
+    } else {
+        quote! { ( #(#pats),* ): &( #(#tys),* ) }
+    }
+}
+
+/// Wrap `strategy` into `prop_filter` for each of the `filters`. `pat` binds the values produced
+/// by `strategy`, a filter usually does not use all of its bindings.
+fn filter<'a>(
+    krate: &Path,
+    strategy: TokenStream,
+    pat: &TokenStream,
+    filters: impl IntoIterator<Item = &'a SpannedExpr>,
+) -> TokenStream {
+    filters.into_iter().fold(strategy, |strategy, filter| {
+        let whence = filter.expr.to_token_stream().to_string();
+        quote_spanned! {filter.span=>
+            #krate::strategy::Strategy::prop_filter(#strategy, #whence, {
+                #[allow(unused_variables)]
+                let filter = |#pat| -> bool { #filter };
+                filter
+            })
+        }
+    })
+}
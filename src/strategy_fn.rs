@@ -0,0 +1,110 @@
+//! The `#[strategy_fn]` attribute turning a function into a strategy constructor, similarly to
+//! `prop_compose!`.
+
+use crate::args::{Arg, ProptestArgs};
+use crate::inputs::Input;
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{punctuated::Punctuated, Error, FnArg, ItemFn, ReturnType, Signature, Token};
+
+/// Arguments of `#[proptest]` that also make sense for strategy functions.
+const SUPPORTED_ARGS: &[&str] = &["crate", "filter"];
+
+pub(crate) fn expand(args: Vec<Arg>, input: ItemFn) -> syn::Result<TokenStream> {
+    if let Some(arg) = args
+        .iter()
+        .find(|arg| !SUPPORTED_ARGS.iter().any(|name| arg.name == name))
+    {
+        return Err(Error::new_spanned(
+            arg,
+            "unknown argument for `strategy_fn`",
+        ));
+    }
+    let args = ProptestArgs::parse(args)?;
+    let krate = args.krate();
+
+    if let Some(asyncness) = &input.sig.asyncness {
+        return Err(Error::new_spanned(
+            asyncness,
+            "strategy functions cannot be async",
+        ));
+    }
+    let value_type = match &input.sig.output {
+        ReturnType::Type(_, ty) => ty,
+        ReturnType::Default => {
+            return Err(Error::new_spanned(
+                &input.sig,
+                "strategy functions must return the type of the generated values",
+            ))
+        }
+    };
+
+    // Arguments marked with `#[param]` are kept as the arguments of the strategy function, the
+    // rest are generated
+    let mut params = Punctuated::<FnArg, Token![,]>::new();
+    let mut inputs = Vec::new();
+    for arg in input.sig.inputs.iter().cloned() {
+        match arg {
+            FnArg::Typed(mut arg) if arg.attrs.iter().any(|attr| attr.path.is_ident("param")) => {
+                arg.attrs.retain(|attr| !attr.path.is_ident("param"));
+                params.push(FnArg::Typed(arg));
+            }
+            arg => inputs.push(Input::from_fn_arg(arg, &krate)?),
+        }
+    }
+
+    let strategy = crate::strategy::strategy(&args, &inputs)?;
+
+    // Patterns of the closure arguments cannot have attributes, such arguments are rebound in the
+    // body instead
+    let mut rebindings = Vec::new();
+    let pats: Vec<_> = inputs
+        .iter()
+        .enumerate()
+        .map(|(i, input)| {
+            if input.attrs.is_empty() {
+                let pat = &input.pat;
+                return quote! { #pat };
+            }
+            let value = format_ident!("arg{}", i, span = Span::mixed_site());
+            rebindings.push(crate::inputs::rebinding(&input.attrs, &input.pat, &value));
+            quote! { #value }
+        })
+        .collect();
+    let tys: Vec<_> = inputs.iter().map(|input| &input.ty).collect();
+    let values = if inputs.is_empty() {
+        quote! { (): () }
+    } else if inputs.len() == 1 {
+        quote! { #(#pats)*: #(#tys)* }
+    } else {
+        quote! { ( #(#pats),* ): ( #(#tys),* ) }
+    };
+
+    let signature = Signature {
+        inputs: params,
+        output: syn::parse_quote! {
+            -> impl #krate::strategy::Strategy<Value = #value_type>
+        },
+        ..input.sig.clone()
+    };
+    let attrs = &input.attrs;
+    let vis = &input.vis;
+    let block = &input.block;
+    let body = if rebindings.is_empty() {
+        quote! { #block }
+    } else {
+        quote! {
+            {
+                #(#rebindings)*
+                #block
+            }
+        }
+    };
+
+    Ok(quote! {
+        #(#attrs)*
+        #vis #signature {
+            #krate::strategy::Strategy::prop_map(#strategy, move |#values| -> #value_type #body)
+        }
+    })
+}
@@ -0,0 +1,246 @@
+//! `#[proptest_suite]` traits whose associated functions are properties of the implementors, and
+//! `#[proptest_suite_impl]` instantiating their tests for an implementor.
+
+use crate::args::{self, Arg, ProptestArgs};
+use crate::module_config;
+use proc_macro2::{Group, Ident, Punct, Spacing, Span, TokenStream, TokenTree};
+use quote::{format_ident, quote, ToTokens};
+use syn::{parse_quote, Error, FnArg, ItemFn, ItemImpl, ItemTrait, TraitItem};
+
+/// The placeholder of the implementor in the generated tests, replaced with `$ty`.
+const SELF_PLACEHOLDER: &str = "__ProptestSuiteSelf";
+/// The placeholder of the trait in the generated tests, replaced with `$suite`.
+const TRAIT_PLACEHOLDER: &str = "__ProptestSuiteTrait";
+
+/// Keep the trait and generate a `macro_rules!` macro expanding to the tests of its provided
+/// associated functions for a given implementor. The macro is imported under the name of the
+/// trait, so that wherever the trait is in scope, the macro is too.
+pub(crate) fn expand_trait(defaults: Vec<Arg>, mut input: ItemTrait) -> syn::Result<TokenStream> {
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &input.generics,
+            "generic traits are not supported by `proptest_suite`",
+        ));
+    }
+
+    let callee_self: TokenStream = format!("<{} as {}>", SELF_PLACEHOLDER, TRAIT_PLACEHOLDER)
+        .parse()
+        .unwrap();
+    let mut tests = Vec::new();
+    for item in &mut input.items {
+        let method = match item {
+            TraitItem::Method(method) => method,
+            _ => continue,
+        };
+        // Functions without a body are left to the implementors, e.g. to provide strategies
+        let block = match &method.default {
+            Some(block) => block.clone(),
+            None => continue,
+        };
+        if let Some(receiver) = method.sig.receiver() {
+            return Err(Error::new_spanned(
+                receiver,
+                "the properties of a suite cannot take `self`, take `Self` as an argument instead",
+            ));
+        }
+        if !method.sig.generics.params.is_empty() {
+            return Err(Error::new_spanned(
+                &method.sig.generics,
+                "generic associated functions are not supported by `proptest_suite`",
+            ));
+        }
+
+        let mut attr = match method
+            .attrs
+            .iter()
+            .position(|attr| module_config::is_attr(attr, "proptest"))
+        {
+            Some(i) => method.attrs.remove(i),
+            None => parse_quote! { #[proptest] },
+        };
+        module_config::merge(&mut attr, &defaults)?;
+        attr.tokens = replace_self(attr.tokens);
+        let args = if attr.tokens.is_empty() {
+            Vec::new()
+        } else {
+            attr.parse_args_with(args::parse_args)?
+        };
+        let args = ProptestArgs::parse(args)?;
+        if args.property_fn.is_some()
+            || args.fuzz_target.is_some()
+            || args.kani.is_some()
+            || args.bench.is_some()
+            || args.iai.is_some()
+            || args.afl.is_some()
+        {
+            return Err(Error::new_spanned(
+                &attr,
+                "`property_fn`, `fuzz_target`, `kani`, `bench`, `iai` and `afl` cannot be used \
+                 in a suite",
+            ));
+        }
+
+        let sig = syn::parse2(replace_self(method.sig.to_token_stream()))?;
+        let test = ItemFn {
+            attrs: method.attrs.clone(),
+            vis: parse_quote! { pub(super) },
+            sig,
+            block: Box::new(block),
+        };
+        let name = &method.sig.ident;
+        let callee = quote! { #callee_self::#name };
+        tests.push(if args.strategies.is_some() {
+            crate::matrix::tests(args, test, callee)?
+        } else {
+            crate::expand_test(&args, test, Some(callee))?
+        });
+
+        // The attributes of the test (e.g. `#[should_panic]`) are moved to the generated tests
+        method.attrs.retain(crate::is_propagated);
+        for arg in &mut method.sig.inputs {
+            if let FnArg::Typed(arg) = arg {
+                arg.attrs.retain(|attr| !crate::inputs::is_arg_attr(attr));
+            }
+        }
+    }
+
+    let tests = macro_vars(quote! { #(#tests)* });
+    let trait_name = &input.ident;
+    let macro_name = format_ident!("__proptest_suite_{}", trait_name);
+    let attrs = crate::cfg_and_lint_attrs(&input.attrs);
+    Ok(quote! {
+        #input
+
+        #(#attrs)*
+        #[doc(hidden)]
+        macro_rules! #macro_name {
+            ($ty:ty, $suite:path, $module:ident) => {
+                #[cfg(any(test, trybuild))]
+                mod $module {
+                    #[allow(unused_imports)]
+                    use super::*;
+
+                    #tests
+                }
+            };
+        }
+        #(#attrs)*
+        #[allow(unused_imports)]
+        pub(crate) use #macro_name as #trait_name;
+    })
+}
+
+/// Keep the implementation and generate the tests of the suite for its type in a module named
+/// after the type and the trait (e.g. `wrapper_monoid` for `impl Monoid for Wrapper`).
+pub(crate) fn expand_impl(args: Vec<Arg>, input: ItemImpl) -> syn::Result<TokenStream> {
+    if let Some(arg) = args.first() {
+        return Err(Error::new_spanned(
+            arg,
+            "`proptest_suite_impl` takes no arguments, configure the properties in the trait",
+        ));
+    }
+    let trait_path =
+        match &input.trait_ {
+            Some((None, path, _)) => path,
+            Some((Some(bang), _, _)) => {
+                return Err(Error::new_spanned(
+                    bang,
+                    "`proptest_suite_impl` cannot be applied to a negative `impl`",
+                ))
+            }
+            None => return Err(Error::new_spanned(
+                input.impl_token,
+                "`proptest_suite_impl` must be applied to an `impl` of a `proptest_suite` trait",
+            )),
+        };
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &input.generics,
+            "generic `impl` blocks are not supported by `proptest_suite_impl`",
+        ));
+    }
+
+    let self_ty = &input.self_ty;
+    let trait_name = match trait_path.segments.last() {
+        Some(segment) => segment.ident.to_string(),
+        None => unreachable!("paths have at least one segment"),
+    };
+    let module = format_ident!(
+        "{}_{}",
+        type_name(self_ty),
+        crate::state_machine::snake_case(&trait_name)
+    );
+    let attrs = crate::cfg_and_lint_attrs(&input.attrs);
+    Ok(quote! {
+        #input
+
+        #(#attrs)*
+        #trait_path!(#self_ty, #trait_path, #module);
+    })
+}
+
+/// The words of a type in snake case, e.g. `vec_u8` for `Vec<u8>`.
+fn type_name(ty: &syn::Type) -> String {
+    ty.to_token_stream()
+        .to_string()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(crate::state_machine::snake_case)
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
+/// Replace `Self` in the tokens written in the trait with the placeholder of the implementor, and
+/// `Self::` with the placeholder of the implementor as the trait.
+fn replace_self(tokens: TokenStream) -> TokenStream {
+    let mut tokens = tokens.into_iter().peekable();
+    let mut result = Vec::new();
+    while let Some(token) = tokens.next() {
+        match token {
+            TokenTree::Ident(ident) if ident == "Self" => {
+                let is_path = matches!(
+                    tokens.peek(),
+                    Some(TokenTree::Punct(punct))
+                        if punct.as_char() == ':' && punct.spacing() == Spacing::Joint
+                );
+                let placeholder = Ident::new(SELF_PLACEHOLDER, ident.span());
+                if is_path {
+                    let trait_placeholder = Ident::new(TRAIT_PLACEHOLDER, ident.span());
+                    result.extend(quote! { <#placeholder as #trait_placeholder> });
+                } else {
+                    result.push(TokenTree::Ident(placeholder));
+                }
+            }
+            TokenTree::Group(group) => {
+                let mut replaced = Group::new(group.delimiter(), replace_self(group.stream()));
+                replaced.set_span(group.span());
+                result.push(TokenTree::Group(replaced));
+            }
+            token => result.push(token),
+        }
+    }
+    result.into_iter().collect()
+}
+
+/// Replace the placeholders with the metavariables of the generated macro.
+fn macro_vars(tokens: TokenStream) -> TokenStream {
+    tokens
+        .into_iter()
+        .flat_map(|token| match token {
+            TokenTree::Ident(ident) if ident == SELF_PLACEHOLDER => metavar("ty", ident.span()),
+            TokenTree::Ident(ident) if ident == TRAIT_PLACEHOLDER => metavar("suite", ident.span()),
+            TokenTree::Group(group) => {
+                let mut replaced = Group::new(group.delimiter(), macro_vars(group.stream()));
+                replaced.set_span(group.span());
+                vec![TokenTree::Group(replaced)]
+            }
+            token => vec![token],
+        })
+        .collect()
+}
+
+fn metavar(name: &str, span: Span) -> Vec<TokenTree> {
+    let mut dollar = Punct::new('$', Spacing::Alone);
+    dollar.set_span(span);
+    vec![dollar.into(), Ident::new(name, span).into()]
+}
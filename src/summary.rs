@@ -0,0 +1,77 @@
+//! The summary of a run passed to the callback given with `on_complete`.
+
+use crate::args::SpannedExpr;
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+use syn::Path;
+
+/// The counters of the cases passed and rejected by the test.
+pub(crate) fn setup() -> TokenStream {
+    quote! {
+        let cases_passed = ::core::sync::atomic::AtomicU32::new(0);
+        let cases_rejected = ::core::sync::atomic::AtomicU32::new(0);
+    }
+}
+
+/// Count the case as passed or rejected by the test according to the outcome of `block`.
+pub(crate) fn count(krate: &Path, block: TokenStream) -> TokenStream {
+    quote! {
+        {
+            let result: #krate::test_runner::TestCaseResult = #block;
+            match &result {
+                ::core::result::Result::Ok(()) => {
+                    cases_passed.fetch_add(1, ::core::sync::atomic::Ordering::Relaxed);
+                }
+                ::core::result::Result::Err(#krate::test_runner::TestCaseError::Reject(_)) => {
+                    cases_rejected.fetch_add(1, ::core::sync::atomic::Ordering::Relaxed);
+                }
+                ::core::result::Result::Err(_) => {}
+            }
+            result
+        }
+    }
+}
+
+/// Bind the summary of the run to `prop_summary` and pass it to `on_complete` if the run passed,
+/// so that the callback can fail the test when too few cases were explored.
+pub(crate) fn complete(on_complete: &SpannedExpr) -> TokenStream {
+    // The closure gets the type of its argument from the bound of `on_complete`
+    let call = quote_spanned!(on_complete.span=> on_complete(#on_complete, &prop_summary));
+    quote! {
+        /// What the run has explored.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        struct PropSummary {
+            /// The number of cases run, including the rejected ones and the shrinking iterations.
+            cases: u32,
+            /// The number of cases the test passed.
+            passed: u32,
+            /// The number of cases rejected by the test, such as with `prop_assume!`.
+            rejected: u32,
+            /// The number of values rejected by the filters of the strategies, which were never
+            /// run as cases.
+            filtered: u32,
+        }
+
+        fn on_complete<F: ::core::ops::FnOnce(&PropSummary)>(callback: F, summary: &PropSummary) {
+            callback(summary)
+        }
+
+        if ::core::result::Result::is_ok(&result) {
+            // The runner only shows the number of the values rejected by the filters when it is
+            // displayed
+            let runner_text = ::std::format!("{}", runner);
+            let filtered = runner_text
+                .lines()
+                .find_map(|line| line.trim().strip_prefix("local rejects: "))
+                .and_then(|count| count.parse().ok())
+                .unwrap_or(0);
+            let prop_summary = PropSummary {
+                cases: cases_run.load(::core::sync::atomic::Ordering::Relaxed),
+                passed: cases_passed.load(::core::sync::atomic::Ordering::Relaxed),
+                rejected: cases_rejected.load(::core::sync::atomic::Ordering::Relaxed),
+                filtered,
+            };
+            #call;
+        }
+    }
+}
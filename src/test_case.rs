@@ -0,0 +1,221 @@
+//! Support for `#[test_case(...)]` attributes, the fixed cases run as separate tests next to the
+//! property test.
+
+use crate::args::{ProptestArgs, Target};
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote, quote_spanned};
+use syn::{
+    parse::ParseStream, punctuated::Punctuated, spanned::Spanned, Attribute, Error, Expr, Ident,
+    ItemFn, LitStr, Token, Visibility,
+};
+
+/// A fixed case given with `#[test_case(values...)]` or `#[test_case(values... ; "name")]`.
+pub(crate) struct TestCase {
+    span: Span,
+    values: Punctuated<Expr, Token![,]>,
+    name: Option<LitStr>,
+}
+
+impl TestCase {
+    fn from_attr(attr: &Attribute) -> syn::Result<Self> {
+        let (values, name) = attr.parse_args_with(Self::parse_args)?;
+        Ok(TestCase {
+            span: attr.path.span(),
+            values,
+            name,
+        })
+    }
+
+    /// The values of the arguments, followed by the name of the case after a `;`.
+    fn parse_args(
+        input: ParseStream,
+    ) -> syn::Result<(Punctuated<Expr, Token![,]>, Option<LitStr>)> {
+        let mut values = Punctuated::new();
+        while !input.is_empty() && !input.peek(Token![;]) && !input.peek(Token![=>]) {
+            values.push_value(input.parse()?);
+            if !input.peek(Token![,]) {
+                break;
+            }
+            values.push_punct(input.parse()?);
+        }
+        if input.peek(Token![=>]) {
+            return Err(input.error(
+                "expected results are not supported, check the result in the body of the test",
+            ));
+        }
+        let name = match input.parse::<Option<Token![;]>>()? {
+            Some(_) => Some(input.parse()?),
+            None => None,
+        };
+        if !input.is_empty() {
+            return Err(input.error("expected `,` or `;` followed by the name of the case"));
+        }
+        Ok((values, name))
+    }
+}
+
+/// Whether `attr` is `#[test_case(...)]`, e.g. `#[test_case::test_case(...)]`.
+fn is_test_case(attr: &Attribute) -> bool {
+    attr.path
+        .segments
+        .last()
+        .is_some_and(|segment| segment.ident == "test_case")
+}
+
+/// Remove the `#[test_case(...)]` attributes from `input` and parse them.
+pub(crate) fn extract(input: &mut ItemFn) -> syn::Result<Vec<TestCase>> {
+    let (cases, attrs): (Vec<_>, Vec<_>) = input.attrs.drain(..).partition(is_test_case);
+    input.attrs = attrs;
+    cases.iter().map(TestCase::from_attr).collect()
+}
+
+/// A module named after the test with a test for each of the `cases`, calling the body of `input`
+/// with the values of the case, like the tests of the `test-case` crate.
+pub(crate) fn tests(
+    args: &ProptestArgs,
+    input: &ItemFn,
+    cases: Vec<TestCase>,
+) -> syn::Result<TokenStream> {
+    let first = match cases.first() {
+        Some(case) => case.span,
+        None => return Ok(quote! {}),
+    };
+    if !input.sig.generics.params.is_empty() {
+        return Err(Error::new(
+            first,
+            "`#[test_case]` cannot be used with generic test functions",
+        ));
+    }
+    if input.sig.asyncness.is_some() {
+        return Err(Error::new(
+            first,
+            "`#[test_case]` cannot be used with async test functions",
+        ));
+    }
+    if input.attrs.iter().any(crate::is_rstest) {
+        return Err(Error::new(
+            first,
+            "`#[test_case]` cannot be combined with `rstest`, use `#[case]` instead",
+        ));
+    }
+    let unsupported = [
+        ("with_rng", args.with_rng.unwrap_or(false)),
+        ("with_context", args.with_context.unwrap_or(false)),
+        ("postcondition", args.postcondition.is_some()),
+    ];
+    if let Some((name, _)) = unsupported.iter().find(|(_, used)| *used) {
+        return Err(Error::new(
+            first,
+            format!("`#[test_case]` cannot be combined with `{}`", name),
+        ));
+    }
+
+    let module = &input.sig.ident;
+    let property = format_ident!("property");
+    let property_fn = crate::property_fn_item(
+        &ItemFn {
+            vis: Visibility::Inherited,
+            ..input.clone()
+        },
+        &property,
+    )?;
+
+    // The harness of the property test runs the cases too
+    let user_harness_attrs: Vec<_> = input
+        .attrs
+        .iter()
+        .filter(|attr| crate::is_test_attr(attr))
+        .collect();
+    let test_attr = if let Some(Target::Wasm) = &args.target {
+        quote! { #[::wasm_bindgen_test::wasm_bindgen_test] }
+    } else if let Some(test_attr) = &args.test_attr {
+        quote! { #[#test_attr] }
+    } else if !user_harness_attrs.is_empty() {
+        quote! { #(#user_harness_attrs)* }
+    } else if args.no_test_attr.unwrap_or(false) {
+        quote! {}
+    } else {
+        args.report().test_attr()
+    };
+
+    let arity = input.sig.inputs.len();
+    let output = &input.sig.output;
+    let mut names = Vec::with_capacity(cases.len());
+    let mut tests = Vec::with_capacity(cases.len());
+    for (i, case) in cases.into_iter().enumerate() {
+        if case.values.len() != arity {
+            return Err(Error::new(
+                case.span,
+                format!(
+                    "expected {} value{} for the arguments of the test, found {}",
+                    arity,
+                    if arity == 1 { "" } else { "s" },
+                    case.values.len(),
+                ),
+            ));
+        }
+        let name = match &case.name {
+            Some(name) => match case_name(&name.value()) {
+                Some(ident) => Ident::new(&ident, name.span()),
+                None => {
+                    return Err(Error::new_spanned(
+                        name,
+                        "the name of a test case must contain letters or digits",
+                    ))
+                }
+            },
+            None => format_ident!("case_{}", i + 1, span = case.span),
+        };
+        if names.contains(&name) {
+            let span = case.name.as_ref().map_or(case.span, LitStr::span);
+            return Err(Error::new(
+                span,
+                format!("duplicate test case name `{}`", name),
+            ));
+        }
+        let values = &case.values;
+        tests.push(quote_spanned! {case.span=>
+            #test_attr
+            pub(super) fn #name() #output {
+                #property(#values)
+            }
+        });
+        names.push(name);
+    }
+
+    let attrs = crate::cfg_and_lint_attrs(&input.attrs);
+    Ok(quote! {
+        #(#attrs)*
+        mod #module {
+            #[allow(unused_imports)]
+            use super::*;
+
+            #property_fn
+            #(#tests)*
+        }
+    })
+}
+
+/// The name of the test of a case named `description`, with the characters that cannot be a part
+/// of an identifier replaced with underscores, e.g. `"empty input"` becomes `empty_input`.
+fn case_name(description: &str) -> Option<String> {
+    if !description.chars().any(|c| c.is_ascii_alphanumeric()) {
+        return None;
+    }
+    let name: String = description
+        .trim()
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        Some(format!("_{}", name))
+    } else {
+        Some(name)
+    }
+}
@@ -0,0 +1,80 @@
+//! Limiting the duration of the whole test with `total_timeout`.
+
+use crate::args::{Report, TotalTimeout};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Ident, Path};
+
+/// The flag set by the watchdog when the total timeout is exceeded, and the number of the cases
+/// which failed, or are still running or panicked.
+pub(crate) fn setup() -> TokenStream {
+    quote! {
+        let timed_out = ::core::sync::atomic::AtomicBool::new(false);
+        let unfinished = ::core::sync::atomic::AtomicU32::new(0);
+    }
+}
+
+/// Evaluate `run` while a watchdog thread waits for it to finish. If it takes longer than
+/// `timeout`, the watchdog sets the `timed_out` flag, so that the remaining cases are skipped. If
+/// the run is still stuck after a grace period, it reports the number of the cases executed so far
+/// (the `cases_run` counter of `failure::setup`) and exits the process.
+pub(crate) fn guard(test_name: &Ident, timeout: &TotalTimeout, run: TokenStream) -> TokenStream {
+    let test_name = test_name.to_string();
+    let TotalTimeout { millis, text } = timeout;
+    quote! {
+        ::proptest_attr_runtime::guard(
+            #test_name,
+            ::std::time::Duration::from_millis(#millis),
+            #text,
+            &cases_run,
+            &timed_out,
+            || { #run },
+        )
+    }
+}
+
+/// Reject the test case without running `block` once the total timeout is exceeded, so that the
+/// runner soon aborts for too many rejects, or stops shrinking with the failure found so far.
+pub(crate) fn skip_timed_out(krate: &Path, block: TokenStream) -> TokenStream {
+    quote! {
+        {
+            if timed_out.load(::core::sync::atomic::Ordering::Relaxed) {
+                ::core::result::Result::Err(#krate::test_runner::TestCaseError::reject(
+                    "the total timeout is exceeded",
+                ))
+            } else {
+                unfinished.fetch_add(1, ::core::sync::atomic::Ordering::Relaxed);
+                let result = #block;
+                if !::core::matches!(
+                    result,
+                    ::core::result::Result::Err(#krate::test_runner::TestCaseError::Fail(_)),
+                ) {
+                    unfinished.fetch_sub(1, ::core::sync::atomic::Ordering::Relaxed);
+                }
+                result
+            }
+        }
+    }
+}
+
+/// Fail the test with the number of the cases executed if the total timeout was exceeded before
+/// any case failed, instead of reporting that the runner aborted. A failure found before the
+/// timeout is reported as usual.
+pub(crate) fn fail(test_name: &Ident, timeout: &TotalTimeout, report: Report) -> TokenStream {
+    let test_name = test_name.to_string();
+    let text = &timeout.text;
+    let fail = report.fail(quote! { message });
+    quote! {
+        if timed_out.load(::core::sync::atomic::Ordering::Relaxed)
+            && unfinished.load(::core::sync::atomic::Ordering::Relaxed) == 0
+        {
+            let message = ::std::format!(
+                "{}: exceeded the total timeout of {}\ncases executed: {}\n",
+                #test_name,
+                #text,
+                cases_run.load(::core::sync::atomic::Ordering::Relaxed),
+            );
+            #fail
+        }
+    }
+}
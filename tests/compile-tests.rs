@@ -1,9 +1,12 @@
 #[test]
 fn compile_tests() {
+    // Many cases fail on purpose, their regressions must not be written next to the tests and
+    // replayed by the next runs
+    std::env::set_var("PROPTEST_DISABLE_FAILURE_PERSISTENCE", "1");
     let t = trybuild::TestCases::new();
     t.pass("tests/ui/01-valid-example.rs");
     t.pass("tests/ui/02-valid-example-mult-args.rs");
-    t.compile_fail("tests/ui/03-no-strategy.rs");
+    t.pass("tests/ui/03-no-strategy.rs");
     t.compile_fail("tests/ui/04-multiple-strategy.rs");
     t.compile_fail("tests/ui/05-unknown-argument.rs");
     t.compile_fail("tests/ui/06-unknown-argument-2.rs");
@@ -12,4 +15,166 @@ fn compile_tests() {
     t.compile_fail("tests/ui/09-strategy-invalid-expression.rs");
     t.pass("tests/ui/10-valid-example-mut-pattern.rs");
     t.compile_fail("tests/ui/11-argument-incorrect-type.rs");
+    t.pass("tests/ui/12-per-argument-strategy.rs");
+    t.compile_fail("tests/ui/13-per-argument-strategy-conflict.rs");
+    t.pass("tests/ui/14-per-argument-strategy-missing.rs");
+    t.pass("tests/ui/15-cases.rs");
+    t.compile_fail("tests/ui/16-cases-wrong-literal.rs");
+    t.pass("tests/ui/17-config.rs");
+    t.compile_fail("tests/ui/18-config-invalid-expression.rs");
+    t.compile_fail("tests/ui/19-no-arbitrary.rs");
+    t.pass("tests/ui/20-async.rs");
+    t.compile_fail("tests/ui/21-async-missing-runtime.rs");
+    t.pass("tests/ui/22-unit-return.rs");
+    t.pass("tests/ui/23-unit-return-failure.rs");
+    t.pass("tests/ui/24-seed.rs");
+    t.compile_fail("tests/ui/25-seed-invalid.rs");
+    t.pass("tests/ui/26-filter.rs");
+    t.compile_fail("tests/ui/27-filter-wrong-literal.rs");
+    t.compile_fail("tests/ui/28-flag-wrong-literal.rs");
+    t.compile_fail("tests/ui/29-test-attr-conflict.rs");
+    t.compile_fail("tests/ui/30-test-attr-invalid.rs");
+    t.pass("tests/ui/31-property-fn.rs");
+    t.pass("tests/ui/32-module-config.rs");
+    t.compile_fail("tests/ui/33-module-config-strategy.rs");
+    t.pass("tests/ui/34-unquoted-expressions.rs");
+    t.pass("tests/ui/35-shrink-limits.rs");
+    t.pass("tests/ui/36-persistence.rs");
+    t.compile_fail("tests/ui/37-persistence-invalid.rs");
+    t.pass("tests/ui/38-crate-path.rs");
+    t.compile_fail("tests/ui/39-crate-path-invalid.rs");
+    t.pass("tests/ui/40-generic.rs");
+    t.compile_fail("tests/ui/41-generic-missing-types.rs");
+    t.pass("tests/ui/42-expect-panic.rs");
+    t.pass("tests/ui/43-backtrace.rs");
+    t.pass("tests/ui/44-fuzz-target.rs");
+    t.pass("tests/ui/45-strategy-fn.rs");
+    t.compile_fail("tests/ui/46-strategy-fn-invalid.rs");
+    t.pass("tests/ui/47-state-machine.rs");
+    t.compile_fail("tests/ui/48-state-machine-invalid.rs");
+    t.pass("tests/ui/49-before-after-each.rs");
+    t.compile_fail("tests/ui/50-strategy-arity.rs");
+    t.pass("tests/ui/51-verbose.rs");
+    t.pass("tests/ui/52-custom-error.rs");
+    t.pass("tests/ui/53-arbitrary.rs");
+    t.compile_fail("tests/ui/54-arbitrary-invalid.rs");
+    t.compile_fail("tests/ui/55-typed-strategy.rs");
+    t.pass("tests/ui/56-replay.rs");
+    t.compile_fail("tests/ui/57-replay-invalid.rs");
+    t.pass("tests/ui/58-parallel.rs");
+    t.compile_fail("tests/ui/59-parallel-invalid.rs");
+    t.pass("tests/ui/60-rejects.rs");
+    t.pass("tests/ui/61-classify.rs");
+    t.pass("tests/ui/62-impl-block.rs");
+    t.compile_fail("tests/ui/63-impl-block-invalid.rs");
+    t.pass("tests/ui/64-wasm.rs");
+    t.compile_fail("tests/ui/65-wasm-invalid.rs");
+    t.pass("tests/ui/66-bench.rs");
+    t.pass("tests/ui/67-rng.rs");
+    t.compile_fail("tests/ui/68-rng-invalid.rs");
+    t.pass("tests/ui/69-patterns.rs");
+    t.pass("tests/ui/70-flat-map-regens-result-cache.rs");
+    t.compile_fail("tests/ui/71-invalid-return-type.rs");
+    t.pass("tests/ui/72-ignore.rs");
+    t.compile_fail("tests/ui/73-ignore-invalid.rs");
+    t.pass("tests/ui/74-tokio.rs");
+    t.compile_fail("tests/ui/75-tokio-invalid.rs");
+    t.pass("tests/ui/76-named-strategies.rs");
+    t.compile_fail("tests/ui/77-named-strategies-invalid.rs");
+    t.pass("tests/ui/78-no-shrink.rs");
+    t.compile_fail("tests/ui/79-no-shrink-invalid.rs");
+    t.pass("tests/ui/80-strategies.rs");
+    t.compile_fail("tests/ui/81-strategies-invalid.rs");
+    t.compile_fail("tests/ui/82-strategy-type-spans.rs");
+    t.pass("tests/ui/83-before-all.rs");
+    t.pass("tests/ui/84-env-overrides.rs");
+    t.pass("tests/ui/85-bolero.rs");
+    t.compile_fail("tests/ui/86-bolero-invalid.rs");
+    t.pass("tests/ui/87-binding-modes.rs");
+    t.pass("tests/ui/88-failure-report.rs");
+    t.pass("tests/ui/89-cache-strategy.rs");
+    t.pass("tests/ui/90-trace.rs");
+    t.pass("tests/ui/91-runner.rs");
+    t.compile_fail("tests/ui/92-runner-invalid.rs");
+    t.pass("tests/ui/93-corpus.rs");
+    t.compile_fail("tests/ui/94-corpus-invalid.rs");
+    t.pass("tests/ui/95-no-std.rs");
+    t.compile_fail("tests/ui/96-no-std-invalid.rs");
+    t.pass("tests/ui/97-exhaustive-small.rs");
+    t.compile_fail("tests/ui/98-exhaustive-small-invalid.rs");
+    t.pass("tests/ui/99-const-generics.rs");
+    t.compile_fail("tests/ui/100-const-generics-invalid.rs");
+    t.pass("tests/ui/101-map.rs");
+    t.compile_fail("tests/ui/102-map-invalid.rs");
+    t.pass("tests/ui/103-stats-out.rs");
+    t.compile_fail("tests/ui/104-stats-out-invalid.rs");
+    t.pass("tests/ui/105-falsify.rs");
+    t.compile_fail("tests/ui/106-falsify-invalid.rs");
+    t.pass("tests/ui/107-result-aliases.rs");
+    t.pass("tests/ui/108-serial.rs");
+    t.compile_fail("tests/ui/109-serial-invalid.rs");
+    t.pass("tests/ui/110-total-timeout.rs");
+    t.compile_fail("tests/ui/111-total-timeout-invalid.rs");
+    t.pass("tests/ui/112-proptest-case.rs");
+    t.compile_fail("tests/ui/113-proptest-case-invalid.rs");
+    t.pass("tests/ui/114-debug-expand.rs");
+    t.pass("tests/ui/115-suite.rs");
+    t.compile_fail("tests/ui/116-suite-invalid.rs");
+    t.pass("tests/ui/117-attribute-propagation.rs");
+    t.pass("tests/ui/118-size.rs");
+    t.compile_fail("tests/ui/119-size-invalid.rs");
+    t.pass("tests/ui/120-continue-on-failure.rs");
+    t.compile_fail("tests/ui/121-continue-on-failure-invalid.rs");
+    t.pass("tests/ui/122-googletest.rs");
+    t.compile_fail("tests/ui/123-googletest-invalid.rs");
+    t.pass("tests/ui/124-setup.rs");
+    t.compile_fail("tests/ui/125-setup-invalid.rs");
+    t.pass("tests/ui/126-iai.rs");
+    t.compile_fail("tests/ui/127-iai-invalid.rs");
+    t.pass("tests/ui/128-named-tuple-strategy.rs");
+    t.compile_fail("tests/ui/129-named-tuple-strategy-invalid.rs");
+    t.pass("tests/ui/130-fail-fast.rs");
+    t.compile_fail("tests/ui/131-fail-fast-invalid.rs");
+    t.pass("tests/ui/132-source-file.rs");
+    t.pass("tests/ui/133-postcondition.rs");
+    t.compile_fail("tests/ui/134-postcondition-invalid.rs");
+    t.pass("tests/ui/135-kani.rs");
+    t.compile_fail("tests/ui/136-kani-invalid.rs");
+    t.pass("tests/ui/137-oneof.rs");
+    t.compile_fail("tests/ui/138-oneof-invalid.rs");
+    t.pass("tests/ui/139-regressions.rs");
+    t.compile_fail("tests/ui/140-regressions-invalid.rs");
+    t.pass("tests/ui/141-example.rs");
+    t.compile_fail("tests/ui/142-example-invalid.rs");
+    t.pass("tests/ui/143-with-rng.rs");
+    t.compile_fail("tests/ui/144-with-rng-invalid.rs");
+    t.pass("tests/ui/145-json-report.rs");
+    t.compile_fail("tests/ui/146-json-report-invalid.rs");
+    t.pass("tests/ui/147-attribute-order.rs");
+    t.compile_fail("tests/ui/148-attribute-order-invalid.rs");
+    t.pass("tests/ui/149-borrowed-args.rs");
+    t.pass("tests/ui/150-dedup.rs");
+    t.compile_fail("tests/ui/151-dedup-invalid.rs");
+    t.pass("tests/ui/152-shrink-with.rs");
+    t.compile_fail("tests/ui/153-shrink-with-invalid.rs");
+    t.pass("tests/ui/154-document.rs");
+    t.compile_fail("tests/ui/155-document-invalid.rs");
+    t.pass("tests/ui/156-loom.rs");
+    t.compile_fail("tests/ui/157-loom-invalid.rs");
+    t.pass("tests/ui/158-on-complete.rs");
+    t.compile_fail("tests/ui/159-on-complete-invalid.rs");
+    t.pass("tests/ui/160-strategy-paths.rs");
+    t.pass("tests/ui/161-format-failure.rs");
+    t.compile_fail("tests/ui/162-format-failure-invalid.rs");
+    t.pass("tests/ui/163-assertion-location.rs");
+    t.pass("tests/ui/164-with-context.rs");
+    t.compile_fail("tests/ui/165-with-context-invalid.rs");
+    t.pass("tests/ui/166-afl.rs");
+    t.compile_fail("tests/ui/167-afl-invalid.rs");
+    t.pass("tests/ui/168-range-len.rs");
+    t.compile_fail("tests/ui/169-range-len-invalid.rs");
+    t.pass("tests/ui/170-test-case.rs");
+    t.compile_fail("tests/ui/171-test-case-invalid.rs");
+    t.pass("tests/ui/172-parallel-shrinking.rs");
+    t.pass("tests/ui/173-seed-dwords.rs");
 }
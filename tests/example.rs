@@ -5,3 +5,84 @@ use proptest_attr::proptest;
 fn basic_test(_value: u8) -> Result<(), TestCaseError> {
     Ok(())
 }
+
+#[proptest(strategy = "0..10u8")]
+#[test]
+fn explicit_test_attr(value: u8) {
+    assert!(value < 10);
+}
+
+#[proptest(strategy = "0..10u8", no_test_attr)]
+fn not_a_test(value: u8) {
+    assert!(value < 10);
+}
+
+#[test]
+fn calls_not_a_test() {
+    not_a_test();
+}
+
+#[proptest(strategy = "0..10u8", test_attr = "test", persistence = "off")]
+#[should_panic]
+fn custom_test_attr(value: u8) {
+    assert!(value > 10);
+}
+
+#[proptest(strategy = "0..10u8", cases = 4, timeout = 5000)]
+fn timeout_test(value: u8) {
+    assert!(value < 10);
+}
+
+#[proptest(strategy = "0..10u8", cases = 1, timeout = 100, persistence = "off")]
+#[should_panic]
+fn timeout_exceeded_test(_value: u8) {
+    std::thread::sleep(std::time::Duration::from_secs(2));
+}
+
+#[proptest(strategy = "0..10u8", cases = 4, fork)]
+fn fork_test(value: u8) {
+    assert!(value < 10);
+}
+
+#[proptest(strategy = "0..10u8", fork = true, persistence = "off")]
+#[should_panic]
+fn fork_abort_test(value: u8) {
+    if value > 5 {
+        std::process::abort();
+    }
+}
+
+#[proptest(strategy = "0..10u8", persistence = "file")]
+fn persistence_test(value: u8) {
+    assert!(value < 10);
+}
+
+#[proptest(types = (u8, u16), cases = 10)]
+fn generic_test<T: Copy + PartialEq + std::fmt::Debug + Arbitrary>(value: T) {
+    let copy = value;
+    assert_eq!(copy, value);
+}
+
+#[rstest::fixture]
+fn limit() -> u8 {
+    10
+}
+
+#[proptest(cases = 10)]
+#[rstest::rstest]
+#[case(1)]
+#[case(2)]
+fn rstest_test(limit: u8, #[case] step: u8, #[strategy(0..limit)] value: u8) {
+    assert!(value < limit);
+    assert!(step <= 2);
+}
+
+#[proptest(cases = 10)]
+#[test_case(0, 0)]
+#[test_case(u8::MAX, 1 ; "overflow")]
+fn test_case_test(a: u8, b: u8) {
+    assert_eq!(
+        a.checked_add(b).is_none(),
+        a as u16 + b as u16 > u8::MAX as u16
+    );
+}
@@ -1,10 +1,17 @@
+use proptest::prelude::*;
 use proptest_attr::proptest;
 
 fn main() {
     basic_test();
+    no_arguments_test();
 }
 
 #[proptest]
 fn basic_test(_value: u8) -> Result<(), TestCaseError> {
     Ok(())
 }
+
+#[proptest]
+fn no_arguments_test() -> Result<(), TestCaseError> {
+    Ok(())
+}
@@ -0,0 +1,18 @@
+use proptest_attr::proptest;
+
+#[proptest]
+fn missing_consts<const N: usize>(_values: [u8; N]) {}
+
+#[proptest(consts = [1, 2])]
+fn not_generic(_value: u8) {}
+
+#[proptest(consts = [1, 2])]
+fn type_param<T: std::fmt::Debug>(_value: T) {}
+
+#[proptest(consts = 8)]
+fn not_a_list<const N: usize>(_values: [u8; N]) {}
+
+#[proptest(consts = [1, 1])]
+fn duplicate<const N: usize>(_values: [u8; N]) {}
+
+fn main() {}
@@ -0,0 +1,48 @@
+use proptest::prelude::*;
+use proptest_attr::{proptest, proptest_arbitrary};
+
+fn double(value: u8) -> u16 {
+    u16::from(value) * 2
+}
+
+#[proptest]
+fn closure(#[map("|s: String| s.to_lowercase()")] s: String) {
+    assert_eq!(s, s.to_lowercase());
+}
+
+#[proptest]
+fn function_path(#[map(double)] value: u16) {
+    assert_eq!(value % 2, 0);
+}
+
+#[proptest]
+fn with_strategy_and_filter(
+    #[strategy(0..100u32)]
+    #[map(|value| value * 3)]
+    #[filter(*value > 0)]
+    value: u32,
+    other: bool,
+) -> Result<(), TestCaseError> {
+    prop_assert!(value > 0 && value < 300 && value % 3 == 0);
+    let _ = other;
+    Ok(())
+}
+
+#[proptest_arbitrary]
+#[derive(Debug)]
+struct Even {
+    #[map(double)]
+    value: u16,
+}
+
+#[proptest]
+fn arbitrary_field(even: Even) {
+    assert_eq!(even.value % 2, 0);
+}
+
+fn main() {
+    closure();
+    function_path();
+    with_strategy_and_filter();
+    arbitrary_field();
+}
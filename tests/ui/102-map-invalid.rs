@@ -0,0 +1,18 @@
+use proptest_attr::proptest;
+
+#[proptest(strategy = 0..10u8)]
+fn with_strategy_argument(#[map(|value: u8| value + 1)] value: u8) {
+    let _ = value;
+}
+
+#[proptest]
+fn multiple(#[map(|value: u8| value)] #[map(|value: u8| value)] value: u8) {
+    let _ = value;
+}
+
+#[proptest]
+fn wrong_type(#[map(|value: u8| u32::from(value))] value: u8) {
+    let _ = value;
+}
+
+fn main() {}
@@ -0,0 +1,44 @@
+use proptest::prelude::*;
+use proptest_attr::proptest;
+
+#[proptest(
+    cases = 20,
+    stats_out = "target/proptest-stats/<test>.json",
+    classify = if *a < 128 { "low \"half\"" } else { "high" },
+)]
+fn passing(a: u8, b: String) -> Result<(), TestCaseError> {
+    prop_assume!(a != 0);
+    let _ = b;
+    Ok(())
+}
+
+#[proptest(stats_out = "target/proptest-stats/failing.json")]
+fn failing(value: u32) {
+    assert!(value < 1000);
+}
+
+fn read(name: &str) -> String {
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("target/proptest-stats")
+        .join(name);
+    std::fs::read_to_string(path).unwrap()
+}
+
+fn main() {
+    passing();
+    let stats = read("passing.json");
+    assert!(stats.contains("\"test\": \"trybuild"), "{}", stats);
+    assert!(stats.contains("::passing\""), "{}", stats);
+    assert!(stats.contains("\"result\": \"passed\""), "{}", stats);
+    assert!(stats.contains("\"passed\": 20,"), "{}", stats);
+    assert!(stats.contains("\"failed\": 0,"), "{}", stats);
+    assert!(stats.contains("\"shrink_iterations\": 0,"), "{}", stats);
+    assert!(stats.contains("\"sizes\": {\""), "{}", stats);
+    assert!(stats.contains("\"low \\\"half\\\"\": "), "{}", stats);
+
+    assert!(std::panic::catch_unwind(failing).is_err());
+    let stats = read("failing.json");
+    assert!(stats.contains("\"result\": \"failed\""), "{}", stats);
+    assert!(!stats.contains("\"shrink_iterations\": 0,"), "{}", stats);
+    assert!(!stats.contains("classification"), "{}", stats);
+}
@@ -0,0 +1,13 @@
+use proptest_attr::proptest;
+
+#[proptest(stats_out = "target/stats.json", parallel)]
+fn with_parallel(value: u8) {
+    let _ = value;
+}
+
+#[proptest(stats_out = target)]
+fn not_a_string(value: u8) {
+    let _ = value;
+}
+
+fn main() {}
@@ -0,0 +1,42 @@
+use proptest::prelude::*;
+use proptest_attr::proptest;
+
+#[proptest(falsify)]
+fn not_all_even(value: u8) {
+    assert_eq!(value % 2, 0);
+}
+
+#[proptest(falsify = true, strategy = (0..10u8, 0..10u8))]
+fn not_commutative(a: u8, b: u8) -> Result<(), TestCaseError> {
+    prop_assert_eq!(a + b, b + a);
+    Ok(())
+}
+
+#[proptest(falsify, cases = 0)]
+fn no_cases(value: u8) {
+    let _ = value;
+}
+
+fn panic_message(test: fn()) -> String {
+    let payload = std::panic::catch_unwind(test).unwrap_err();
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => payload.downcast_ref::<&str>().unwrap().to_string(),
+    }
+}
+
+fn main() {
+    not_all_even();
+
+    let message = panic_message(not_commutative);
+    assert!(
+        message.contains("`not_commutative` was expected to fail"),
+        "{}",
+        message
+    );
+    assert!(message.contains("held for all 256 cases"), "{}", message);
+    assert!(message.contains("sample satisfying input: (\n"), "{}", message);
+
+    let message = panic_message(no_cases);
+    assert!(message.contains("no cases were run"), "{}", message);
+}
@@ -0,0 +1,18 @@
+use proptest_attr::proptest;
+
+#[proptest(falsify, parallel)]
+fn with_parallel(value: u8) {
+    let _ = value;
+}
+
+#[proptest(falsify, expect_panic)]
+fn with_expect_panic(value: u8) {
+    let _ = value;
+}
+
+#[proptest(falsify, no_std)]
+fn with_no_std(value: u8) {
+    let _ = value;
+}
+
+fn main() {}
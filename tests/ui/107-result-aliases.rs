@@ -0,0 +1,92 @@
+use proptest::prelude::*;
+use proptest::test_runner::TestCaseResult;
+use proptest_attr::proptest;
+
+type Outcome = Result<(), TestCaseError>;
+type Generic<T = ()> = core::result::Result<T, TestCaseError>;
+type Unit = ();
+
+trait Same {
+    type This;
+}
+
+impl<T> Same for T {
+    type This = T;
+}
+
+#[proptest]
+fn prelude_alias(value: u8) -> prop::test_runner::TestCaseResult {
+    prop_assert!(value <= u8::MAX);
+    Ok(())
+}
+
+#[proptest]
+fn imported_alias(value: u8) -> TestCaseResult {
+    prop_assume!(value != 0);
+    Ok(())
+}
+
+#[proptest]
+fn user_alias(value: u8) -> Outcome {
+    prop_assert_eq!(value, value);
+    Ok(())
+}
+
+#[proptest]
+fn generic_alias(value: u8) -> Generic {
+    prop_assert_eq!(value, value);
+    Ok(())
+}
+
+#[proptest]
+fn core_path(value: u8) -> core::result::Result<(), TestCaseError> {
+    prop_assert_eq!(value, value);
+    Ok(())
+}
+
+#[proptest]
+fn absolute_std_path(value: u8) -> ::std::result::Result<(), TestCaseError> {
+    prop_assert_eq!(value, value);
+    Ok(())
+}
+
+#[proptest]
+fn qualified_path(value: u8) -> <TestCaseResult as Same>::This {
+    prop_assert_eq!(value, value);
+    Ok(())
+}
+
+#[proptest]
+fn unit_alias(value: u8) -> Unit {
+    assert_eq!(value, value);
+}
+
+#[proptest]
+fn io_result(value: u8) -> std::io::Result<()> {
+    let _ = value;
+    Ok(())
+}
+
+#[proptest]
+fn parenthesized_unit(value: u8) -> (()) {
+    let _ = value;
+}
+
+#[proptest(fuzz_target)]
+fn fuzz_alias(value: u8) -> Outcome {
+    prop_assert_eq!(value, value);
+    Ok(())
+}
+
+fn main() {
+    prelude_alias();
+    imported_alias();
+    user_alias();
+    generic_alias();
+    core_path();
+    absolute_std_path();
+    qualified_path();
+    unit_alias();
+    io_result();
+    parenthesized_unit();
+}
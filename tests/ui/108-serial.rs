@@ -0,0 +1,59 @@
+use proptest_attr::proptest;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static RUNNING: AtomicBool = AtomicBool::new(false);
+
+fn enter() {
+    assert!(
+        !RUNNING.swap(true, Ordering::SeqCst),
+        "serial tests overlap"
+    );
+}
+
+fn exit() {
+    RUNNING.store(false, Ordering::SeqCst);
+}
+
+#[proptest(serial, cases = 20)]
+fn first(value: u8) {
+    enter();
+    std::thread::yield_now();
+    let _ = value;
+    exit();
+}
+
+#[proptest(serial = true, cases = 20)]
+fn second(value: u8) {
+    enter();
+    std::thread::yield_now();
+    let _ = value;
+    exit();
+}
+
+#[proptest(serial = "env", cases = 5)]
+fn grouped(value: u8) {
+    std::env::set_var("PROPTEST_ATTR_SERIAL", value.to_string());
+    assert_eq!(
+        std::env::var("PROPTEST_ATTR_SERIAL").unwrap(),
+        value.to_string()
+    );
+}
+
+#[proptest(serial = false)]
+fn not_serial(value: u8) {
+    let _ = value;
+}
+
+fn main() {
+    let threads = vec![
+        std::thread::spawn(first),
+        std::thread::spawn(second),
+        std::thread::spawn(first),
+        std::thread::spawn(second),
+    ];
+    for thread in threads {
+        thread.join().unwrap();
+    }
+    grouped();
+    not_serial();
+}
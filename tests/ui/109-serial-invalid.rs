@@ -0,0 +1,23 @@
+use proptest_attr::proptest;
+
+#[proptest(serial = "not a group")]
+fn invalid_group(value: u8) {
+    let _ = value;
+}
+
+#[proptest(serial = 1)]
+fn invalid_value(value: u8) {
+    let _ = value;
+}
+
+#[proptest(target = "wasm", serial)]
+fn with_wasm(value: u8) {
+    let _ = value;
+}
+
+#[proptest(no_std, serial)]
+fn with_no_std(value: u8) {
+    let _ = value;
+}
+
+fn main() {}
@@ -0,0 +1,93 @@
+use proptest_attr::proptest;
+use std::process::Command;
+
+#[proptest(total_timeout = "60s", cases = 50)]
+fn within_timeout(value: u8) {
+    let _ = value;
+}
+
+#[proptest(total_timeout = "1m", parallel, threads = 2, cases = 50)]
+fn parallel_within_timeout(value: u8) {
+    let _ = value;
+}
+
+#[proptest(total_timeout = "200ms", cases = 1_000_000)]
+fn exceeds_timeout(value: u8) {
+    std::thread::sleep(std::time::Duration::from_millis(20));
+    let _ = value;
+}
+
+#[proptest(total_timeout = "200ms", parallel, threads = 2, cases = 1_000_000)]
+fn parallel_exceeds_timeout(value: u8) {
+    std::thread::sleep(std::time::Duration::from_millis(20));
+    let _ = value;
+}
+
+// Shrinking takes longer than the timeout, and stops with the failure found so far
+#[proptest(total_timeout = "300ms", cases = 1_000_000, strategy = 1000..1_000_000u32)]
+fn fails_before_timeout(value: u32) {
+    std::thread::sleep(std::time::Duration::from_millis(20));
+    assert!(value < 1000);
+}
+
+#[proptest(total_timeout = "100ms", cases = 10)]
+fn stuck(value: u8) {
+    let _ = value;
+    loop {
+        std::thread::sleep(std::time::Duration::from_secs(1));
+    }
+}
+
+const CHILD: &str = "PROPTEST_ATTR_TOTAL_TIMEOUT_CHILD";
+
+fn failure(test: fn()) -> String {
+    *std::panic::catch_unwind(test)
+        .unwrap_err()
+        .downcast::<String>()
+        .unwrap()
+}
+
+fn main() {
+    if std::env::var_os(CHILD).is_some() {
+        stuck();
+        return;
+    }
+
+    within_timeout();
+    parallel_within_timeout();
+
+    // The remaining cases are skipped and the test fails like any other
+    let message = failure(exceeds_timeout);
+    assert!(
+        message
+            .starts_with("exceeds_timeout: exceeded the total timeout of 200ms\ncases executed: "),
+        "{}",
+        message
+    );
+    let message = failure(parallel_exceeds_timeout);
+    assert!(
+        message.starts_with(
+            "parallel_exceeds_timeout: exceeded the total timeout of 200ms\ncases executed: "
+        ),
+        "{}",
+        message
+    );
+
+    let message = failure(fails_before_timeout);
+    assert!(message.contains("minimal failing input: "), "{}", message);
+
+    // A stuck case cannot be interrupted, so the watchdog exits the process after a grace period
+    let output = Command::new(std::env::current_exe().unwrap())
+        .env(CHILD, "1")
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(101));
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(
+        stderr.contains(
+            "stuck: exceeded the total timeout of 100ms and did not stop\ncases executed: 1"
+        ),
+        "{}",
+        stderr
+    );
+}
@@ -0,0 +1,28 @@
+use proptest_attr::proptest;
+
+#[proptest(total_timeout = "60")]
+fn without_unit(value: u8) {
+    let _ = value;
+}
+
+#[proptest(total_timeout = "1d")]
+fn unknown_unit(value: u8) {
+    let _ = value;
+}
+
+#[proptest(total_timeout = "0s")]
+fn zero(value: u8) {
+    let _ = value;
+}
+
+#[proptest(total_timeout = 60)]
+fn not_a_string(value: u8) {
+    let _ = value;
+}
+
+#[proptest(no_std, total_timeout = "60s")]
+fn with_no_std(value: u8) {
+    let _ = value;
+}
+
+fn main() {}
@@ -0,0 +1,42 @@
+use proptest::prelude::*;
+use proptest_attr::proptest_case;
+
+fn panic_message(test: fn()) -> String {
+    let payload = std::panic::catch_unwind(test).unwrap_err();
+    *payload.downcast::<String>().unwrap()
+}
+
+fn properties() {
+    proptest_case!(0..10u8, |value: u8| {
+        assert!(value < 10);
+    });
+
+    proptest_case!(cases = 10, |a: u16, b: u16| {
+        assert_eq!(u32::from(a) + u32::from(b), u32::from(b) + u32::from(a));
+    });
+
+    proptest_case!(
+        prop::collection::vec(any::<u8>(), 1..10),
+        max_shrink_iters = 100,
+        |values: Vec<u8>| -> Result<(), TestCaseError> {
+            prop_assume!(values.len() > 1);
+            prop_assert!(!values.is_empty());
+            Ok(())
+        },
+    );
+
+    proptest_case!(|value: u32| assert_eq!(value.wrapping_add(0), value));
+}
+
+fn failing() {
+    proptest_case!(0..100u8, |value: u8| {
+        assert!(value < 10);
+    });
+}
+
+fn main() {
+    properties();
+
+    let message = panic_message(failing);
+    assert!(message.contains("minimal failing input: 10"), "{}", message);
+}
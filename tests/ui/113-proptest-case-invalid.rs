@@ -0,0 +1,24 @@
+use proptest_attr::proptest_case;
+
+fn main() {
+    proptest_case!(0..10u8, |value| {
+        let _ = value;
+    });
+
+    proptest_case!(0..10u8, move |value: u8| {
+        let _ = value;
+    });
+
+    proptest_case!(cases = 10, 0..10u8, |value: u8| {
+        let _ = value;
+    });
+
+    proptest_case!(test_attr = "tokio::test", |value: u8| {
+        let _ = value;
+    });
+
+    let limit = 10;
+    proptest_case!(0..10u8, |value: u8| {
+        assert!(value < limit);
+    });
+}
@@ -0,0 +1,25 @@
+use proptest::prelude::*;
+use proptest_attr::proptest;
+
+#[proptest(debug_expand, cases = 10)]
+fn simple(value: u8) {
+    let _ = value;
+}
+
+#[proptest(debug_expand = true, strategy = (0..10u8, "[a-z]*"))]
+fn with_strategy(a: u8, b: String) -> Result<(), TestCaseError> {
+    prop_assert!(a < 10 && b.chars().all(|c| c.is_ascii_lowercase()));
+    Ok(())
+}
+
+#[proptest(debug_expand, types = (u8, u16))]
+fn generic<T: Arbitrary + Copy + PartialEq + std::fmt::Debug>(value: T) {
+    assert_eq!(value, value);
+}
+
+fn main() {
+    simple();
+    with_strategy();
+    generic::u8();
+    generic::u16();
+}
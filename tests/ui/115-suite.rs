@@ -0,0 +1,122 @@
+use proptest::prelude::*;
+use proptest_attr::{proptest_suite, proptest_suite_impl};
+use std::fmt::Debug;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+static COMBINED: AtomicU32 = AtomicU32::new(0);
+
+mod laws {
+    use super::*;
+
+    #[proptest_suite(cases = 16)]
+    pub trait Monoid: Arbitrary + Clone + Debug + PartialEq {
+        fn empty() -> Self;
+        fn combine(a: Self, b: Self) -> Self;
+
+        fn associativity(a: Self, b: Self, c: Self) {
+            COMBINED.fetch_add(1, Ordering::Relaxed);
+            assert_eq!(
+                Self::combine(Self::combine(a.clone(), b.clone()), c.clone()),
+                Self::combine(a, Self::combine(b, c)),
+            );
+        }
+
+        /// The empty value does not change others.
+        #[proptest(cases = 8)]
+        fn identity(value: Self) -> Result<(), TestCaseError> {
+            prop_assert_eq!(Self::combine(Self::empty(), value.clone()), value.clone());
+            prop_assert_eq!(Self::combine(value.clone(), Self::empty()), value);
+            Ok(())
+        }
+
+        fn repeat(value: Self, #[strategy(0..4usize)] times: usize) {
+            let mut result = Self::empty();
+            for _ in 0..times {
+                result = Self::combine(result, value.clone());
+            }
+            if times == 0 {
+                assert_eq!(result, Self::empty());
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Sum(u32);
+
+impl Arbitrary for Sum {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((): ()) -> Self::Strategy {
+        (0..1000u32).prop_map(Sum).boxed()
+    }
+}
+
+#[proptest_suite_impl]
+impl laws::Monoid for Sum {
+    fn empty() -> Self {
+        Sum(0)
+    }
+
+    fn combine(a: Self, b: Self) -> Self {
+        Sum(a.0 + b.0)
+    }
+}
+
+use laws::Monoid;
+
+#[proptest_suite_impl]
+impl Monoid for Vec<u8> {
+    fn empty() -> Self {
+        Vec::new()
+    }
+
+    fn combine(mut a: Self, b: Self) -> Self {
+        a.extend(b);
+        a
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Difference(i8);
+
+impl Arbitrary for Difference {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((): ()) -> Self::Strategy {
+        (-10..10i8).prop_map(Difference).boxed()
+    }
+}
+
+#[proptest_suite_impl]
+impl Monoid for Difference {
+    fn empty() -> Self {
+        Difference(0)
+    }
+
+    fn combine(a: Self, b: Self) -> Self {
+        Difference(a.0 - b.0)
+    }
+}
+
+fn panic_message(test: fn()) -> String {
+    let payload = std::panic::catch_unwind(test).unwrap_err();
+    *payload.downcast::<String>().unwrap()
+}
+
+fn main() {
+    sum_monoid::associativity();
+    assert_eq!(COMBINED.load(Ordering::Relaxed), 16);
+    sum_monoid::identity();
+    sum_monoid::repeat();
+    vec_u8_monoid::associativity();
+    vec_u8_monoid::identity();
+    vec_u8_monoid::repeat();
+
+    let message = panic_message(difference_monoid::associativity);
+    assert!(message.contains("minimal failing input"), "{}", message);
+    let message = panic_message(difference_monoid::identity);
+    assert!(message.contains("minimal failing input"), "{}", message);
+}
@@ -0,0 +1,43 @@
+use proptest_attr::{proptest_suite, proptest_suite_impl};
+
+#[proptest_suite]
+trait WithSelf: Sized {
+    fn property(&self) {}
+}
+
+#[proptest_suite]
+trait Generic<T> {
+    fn property(value: T) {
+        let _ = value;
+    }
+}
+
+#[proptest_suite]
+trait WithFuzzTarget: Sized {
+    #[proptest(fuzz_target)]
+    fn property(value: u8) {
+        let _ = value;
+    }
+}
+
+#[proptest_suite]
+trait WithAfl: Sized {
+    #[proptest(afl)]
+    fn property(value: u8) {
+        let _ = value;
+    }
+}
+
+struct Type;
+
+#[proptest_suite_impl]
+impl Type {}
+
+#[proptest_suite_impl(cases = 10)]
+impl Clone for Type {
+    fn clone(&self) -> Self {
+        Type
+    }
+}
+
+fn main() {}
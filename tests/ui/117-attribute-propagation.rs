@@ -0,0 +1,177 @@
+#![deny(warnings)]
+// The `slow` feature does not exist, so the tests below are never compiled
+#![allow(unexpected_cfgs)]
+
+use proptest::prelude::*;
+use proptest_attr::{
+    proptest, proptest_config, proptest_state_machine, proptest_suite, proptest_suite_impl,
+};
+use std::fmt::Debug;
+
+// None of the generated items may be compiled without the feature, since they would refer to the
+// missing function
+#[cfg(feature = "slow")]
+fn only_with_slow() {}
+
+#[cfg(feature = "slow")]
+#[proptest]
+fn plain(value: u8) {
+    only_with_slow();
+    let _ = value;
+}
+
+#[cfg(feature = "slow")]
+#[proptest(property_fn)]
+fn with_property_fn(value: u8) {
+    only_with_slow();
+    let _ = value;
+}
+
+#[cfg(feature = "slow")]
+#[proptest(fuzz_target)]
+fn with_fuzz_target(value: u8) {
+    only_with_slow();
+    let _ = value;
+}
+
+#[cfg(feature = "slow")]
+#[proptest(bench)]
+fn with_bench(value: u8) {
+    only_with_slow();
+    let _ = value;
+}
+
+#[cfg(feature = "slow")]
+#[proptest(types = (u8, u16))]
+fn generic<T: Arbitrary + Debug>(value: T) {
+    only_with_slow();
+    let _ = value;
+}
+
+#[cfg(feature = "slow")]
+#[proptest(consts = [1, 2])]
+fn with_consts<const N: usize>(values: [u8; N]) {
+    only_with_slow();
+    let _ = values;
+}
+
+#[cfg(feature = "slow")]
+#[proptest(strategies = [0..10u8, 10..20u8])]
+fn matrix(value: u8) {
+    only_with_slow();
+    let _ = value;
+}
+
+#[cfg(feature = "slow")]
+#[proptest(cache_strategy, before_all = only_with_slow, classify = value % 2 == 0)]
+fn with_helpers(value: u8) {
+    only_with_slow();
+    let _ = value;
+}
+
+struct Type;
+
+#[proptest_config]
+impl Type {
+    #[cfg(feature = "slow")]
+    #[proptest]
+    fn method(value: u8) {
+        only_with_slow();
+        let _ = value;
+    }
+}
+
+// The `cfg` of a transition applies to its variant and strategy
+#[derive(Clone, Debug)]
+struct Model;
+
+#[proptest_state_machine(cases = 8)]
+impl Model {
+    fn init_state() -> Self {
+        Model
+    }
+
+    fn init_test(&self) -> Model {
+        Model
+    }
+
+    #[transition]
+    fn step(&mut self) {}
+
+    #[cfg(feature = "slow")]
+    #[transition]
+    fn slow_step(&mut self, value: u8) {
+        only_with_slow();
+        let _ = value;
+    }
+}
+
+#[proptest_suite(cases = 8)]
+trait Laws: Arbitrary + Debug {
+    fn holds(value: Self) {
+        let _ = value;
+    }
+
+    #[cfg(feature = "slow")]
+    fn slow(value: Self) {
+        only_with_slow();
+        let _ = value;
+    }
+}
+
+#[proptest_suite_impl]
+impl Laws for u8 {}
+
+// Lint attributes apply to all the generated items containing the body
+#[allow(unused_variables)]
+#[proptest(property_fn, fuzz_target, bench)]
+fn allowed(value: u8) {
+    let unused = value;
+}
+
+#[allow(unused_variables)]
+#[proptest(strategies = [0..10u8, 10..20u8])]
+fn allowed_matrix(value: u8) {
+    let unused = value;
+}
+
+#[allow(unused_variables)]
+#[proptest(types = (u8, u16))]
+fn allowed_generic<T: Arbitrary + Debug>(value: T) {
+    let unused = value;
+}
+
+// The expected lints are only raised by the function with the body
+#[expect(unused_variables)]
+#[proptest(property_fn)]
+fn expected(value: u8) {
+    let unused = value;
+}
+
+struct Other;
+
+#[proptest_config]
+impl Other {
+    #[expect(unused_variables)]
+    #[proptest]
+    fn expected_method(value: u8) {
+        let unused = value;
+    }
+}
+
+/// Documented.
+#[proptest(property_fn, fuzz_target, bench)]
+fn documented(value: u8) {
+    let _ = value;
+}
+
+fn main() {
+    allowed();
+    allowed_matrix_0();
+    allowed_generic::u8();
+    expected();
+    other_proptest::expected_method();
+    documented();
+    model_state_machine();
+    u8_laws::holds();
+}
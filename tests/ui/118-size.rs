@@ -0,0 +1,54 @@
+use proptest::prelude::*;
+use proptest_attr::proptest;
+use std::collections::{BTreeSet, HashMap};
+use std::rc::Rc;
+
+#[proptest(size = "1..4")]
+fn collections(
+    values: Vec<u8>,
+    text: String,
+    map: HashMap<u8, Vec<u16>>,
+    nested: Option<Vec<Vec<bool>>>,
+    pair: (BTreeSet<u32>, u8),
+    boxed: Box<[Vec<u8>; 2]>,
+    shared: Rc<String>,
+) {
+    assert!((1..4).contains(&values.len()));
+    assert!((1..4).contains(&text.chars().count()));
+    assert!((1..4).contains(&map.len()));
+    assert!(map.values().all(|values| (1..4).contains(&values.len())));
+    if let Some(nested) = nested {
+        assert!((1..4).contains(&nested.len()));
+        assert!(nested.iter().all(|values| (1..4).contains(&values.len())));
+    }
+    // The set may have less elements than generated because of duplicates
+    assert!(!pair.0.is_empty() && pair.0.len() < 4);
+    let _ = pair.1;
+    assert!(boxed.iter().all(|values| (1..4).contains(&values.len())));
+    assert!((1..4).contains(&shared.chars().count()));
+}
+
+#[proptest(size = "2..=2")]
+fn inclusive(values: Vec<u8>) {
+    assert_eq!(values.len(), 2);
+}
+
+#[proptest(size = "3")]
+fn exact(values: Vec<u8>, text: String) {
+    assert_eq!(values.len(), 3);
+    assert_eq!(text.chars().count(), 3);
+}
+
+// Explicit strategies are not affected
+#[proptest(size = "0..2")]
+fn explicit(#[strategy(prop::collection::vec(any::<u8>(), 5))] values: Vec<u8>, other: u8) {
+    assert_eq!(values.len(), 5);
+    let _ = other;
+}
+
+fn main() {
+    collections();
+    inclusive();
+    exact();
+    explicit();
+}
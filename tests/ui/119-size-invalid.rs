@@ -0,0 +1,18 @@
+use proptest_attr::proptest;
+
+#[proptest(size = "0..0")]
+fn empty(values: Vec<u8>) {
+    let _ = values;
+}
+
+#[proptest(size = "a..b")]
+fn invalid(values: Vec<u8>) {
+    let _ = values;
+}
+
+#[proptest(size = 0..8)]
+fn unquoted(values: Vec<u8>) {
+    let _ = values;
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+use proptest::prelude::*;
+use proptest_attr::proptest;
+
+fn main() {
+    basic_test();
+}
+
+#[proptest]
+fn basic_test(
+    #[strategy("0..10u8")] a: u8,
+    #[strategy("any::<String>()")] s: String,
+    #[strategy("10..100u32")] mut b: u32,
+) -> Result<(), TestCaseError> {
+    b += a as u32;
+    prop_assert!(b >= 10);
+    let _len = s.len();
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use proptest::prelude::*;
+use proptest_attr::proptest;
+use std::collections::HashSet;
+
+#[proptest(continue_on_failure = 3, cases = 1000)]
+fn small(value: u8) {
+    assert!(value < 100);
+}
+
+#[proptest(continue_on_failure = 5, strategy = 0..4u8)]
+fn one_failure(value: u8) -> Result<(), TestCaseError> {
+    prop_assert_ne!(value, 2);
+    Ok(())
+}
+
+#[proptest(continue_on_failure = 2)]
+fn holds(a: u8, b: u8) {
+    assert_eq!(a.wrapping_add(b), b.wrapping_add(a));
+}
+
+fn panic_message(test: fn()) -> String {
+    let payload = std::panic::catch_unwind(test).unwrap_err();
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => payload.downcast_ref::<&str>().unwrap().to_string(),
+    }
+}
+
+fn main() {
+    let message = panic_message(small);
+    assert!(
+        message.contains(
+            "`small` failed for 3 distinct inputs (the limit set by `continue_on_failure`)"
+        ),
+        "{}",
+        message
+    );
+    assert!(message.contains("minimal failing input: 100\n"), "{}", message);
+    assert!(message.contains("cases executed: "), "{}", message);
+    let inputs: HashSet<_> = message
+        .lines()
+        .filter_map(|line| line.strip_prefix("minimal failing input: "))
+        .collect();
+    assert_eq!(inputs.len(), 3, "{}", message);
+    assert!(message.contains("failure 3: "), "{}", message);
+
+    // The cases run out before the limit is reached
+    let message = panic_message(one_failure);
+    assert!(
+        message.contains("`one_failure` failed for 1 distinct input\n"),
+        "{}",
+        message
+    );
+    assert!(message.contains("minimal failing input: 2\n"), "{}", message);
+    assert!(!message.contains("failure 2: "), "{}", message);
+
+    holds();
+}
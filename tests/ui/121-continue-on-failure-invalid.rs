@@ -0,0 +1,23 @@
+use proptest_attr::proptest;
+
+#[proptest(continue_on_failure = 0)]
+fn zero(value: u8) {
+    let _ = value;
+}
+
+#[proptest(continue_on_failure = 2, parallel)]
+fn parallel(value: u8) {
+    let _ = value;
+}
+
+#[proptest(continue_on_failure = 2, falsify)]
+fn falsify(value: u8) {
+    let _ = value;
+}
+
+#[proptest(continue_on_failure = 2, no_std)]
+fn no_std(value: u8) {
+    let _ = value;
+}
+
+fn main() {}
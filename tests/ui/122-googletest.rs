@@ -0,0 +1,76 @@
+use googletest::prelude::*;
+use proptest_attr::{proptest, proptest_state_machine};
+
+#[proptest(report = "googletest")]
+fn holds(value: u8) -> googletest::Result<()> {
+    verify_that!(value.checked_mul(1), some(eq(value)))
+}
+
+#[proptest(report = "googletest")]
+fn fails(value: u8) -> googletest::Result<()> {
+    verify_that!(value, lt(100))
+}
+
+#[proptest(report = "googletest")]
+fn panics(value: u8) {
+    assert!(value < 100, "too large");
+}
+
+#[proptest(report = "googletest", ignore_if = true)]
+fn skipped(value: u8) {
+    let _ = value;
+    unreachable!();
+}
+
+#[proptest(report = "googletest", falsify)]
+fn not_all_even(value: u8) {
+    assert_eq!(value % 2, 0);
+}
+
+// The harness attribute of the user is kept and not duplicated
+#[proptest(report = "googletest")]
+#[googletest::test]
+fn with_harness(value: u8) -> googletest::Result<()> {
+    verify_that!(value, ge(0))
+}
+
+#[derive(Clone, Debug)]
+struct Counter(u8);
+
+#[proptest_state_machine(report = "googletest", cases = 8)]
+impl Counter {
+    fn init_state() -> Self {
+        Counter(0)
+    }
+
+    fn init_test(&self) -> Counter {
+        self.clone()
+    }
+
+    fn check_invariants(&self, sut: &Counter) {
+        assert_eq!(self.0, sut.0);
+    }
+
+    #[transition]
+    fn increment(&mut self) {
+        self.0 = self.0.saturating_add(1);
+    }
+}
+
+fn main() {
+    holds().unwrap();
+
+    let failure = fails().unwrap_err().to_string();
+    assert!(failure.contains("Expected: is less than 100"), "{}", failure);
+    assert!(failure.contains("minimal failing input: 100"), "{}", failure);
+    assert!(failure.contains("cases executed: "), "{}", failure);
+    assert!(failure.contains("to reproduce, add `seed = "), "{}", failure);
+
+    let failure = panics().unwrap_err().to_string();
+    assert!(failure.contains("too large"), "{}", failure);
+    assert!(failure.contains("minimal failing input: 100"), "{}", failure);
+
+    skipped().unwrap();
+    not_all_even().unwrap();
+    counter_state_machine().unwrap();
+}
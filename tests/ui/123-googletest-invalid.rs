@@ -0,0 +1,22 @@
+use proptest_attr::{proptest, proptest_case};
+
+#[proptest(report = "json")]
+fn unknown(value: u8) {
+    let _ = value;
+}
+
+#[proptest(report = "googletest", engine = "bolero")]
+fn bolero(value: u8) {
+    let _ = value;
+}
+
+#[proptest(report = "googletest", no_std)]
+fn no_std(value: u8) {
+    let _ = value;
+}
+
+fn main() {
+    proptest_case!(report = "googletest", |value: u8| {
+        let _ = value;
+    });
+}
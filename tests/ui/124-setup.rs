@@ -0,0 +1,54 @@
+use proptest::prelude::*;
+use proptest_attr::proptest;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+mod config {
+    pub const MAX: u8 = 10;
+}
+
+static TABLES_BUILT: AtomicU32 = AtomicU32::new(0);
+
+fn build_table() -> Vec<u8> {
+    TABLES_BUILT.fetch_add(1, Ordering::Relaxed);
+    vec![2, 3, 5, 7]
+}
+
+#[proptest(
+    setup = "let max = config::MAX; let table = build_table();",
+    cases = 32
+)]
+fn quoted(
+    #[strategy(0..max)] value: u8,
+    #[strategy(prop::sample::select(table.clone()))]
+    #[filter(*prime != 2)]
+    prime: u8,
+) {
+    assert!(value < config::MAX);
+    assert!([3, 5, 7].contains(&prime));
+}
+
+#[proptest(setup = { let max = config::MAX * 2; }, strategy = 0..max, filter = value % 2 == 0)]
+fn braces(value: u8) {
+    assert!(value < 20);
+    assert_eq!(value % 2, 0);
+}
+
+#[proptest(setup = "let max = config::MAX;", strategies = [0..max, max..max * 2])]
+fn matrix(value: u8) {
+    assert!(value < 20);
+}
+
+#[proptest(setup = "let table = build_table();", cache_strategy)]
+fn cached(#[strategy(prop::sample::select(table))] prime: u8) {
+    assert!(prime < 10);
+}
+
+fn main() {
+    quoted();
+    // The setup runs once per test, not once per case
+    assert_eq!(TABLES_BUILT.load(Ordering::Relaxed), 1);
+    braces();
+    matrix_0();
+    matrix_1();
+    cached();
+}
@@ -0,0 +1,13 @@
+use proptest_attr::proptest;
+
+#[proptest(setup = 5)]
+fn not_statements(value: u8) {
+    let _ = value;
+}
+
+#[proptest(setup = "let max = ;")]
+fn invalid_statements(value: u8) {
+    let _ = value;
+}
+
+fn main() {}
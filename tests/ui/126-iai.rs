@@ -0,0 +1,41 @@
+use iai_callgrind::library_benchmark_group;
+use proptest::prelude::*;
+use proptest_attr::proptest;
+
+#[proptest(cases = 10, iai)]
+fn benchmarked(#[strategy(0..1000u32)] value: u32) {
+    assert!(value < 1000);
+}
+
+#[proptest(cases = 10, iai = true, iai_inputs = 5, property_fn)]
+fn tuple(a: u8, #[filter("*b > 0")] b: u8) -> Result<(), TestCaseError> {
+    prop_assert!(u16::from(a) / u16::from(b) <= u16::from(a));
+    Ok(())
+}
+
+#[proptest(cases = 10, iai = "named", setup = "let max = 4;")]
+fn named_test(#[strategy(prop::collection::vec(any::<u8>(), 0..max))] value: Vec<u8>) {
+    assert!(value.len() < 4);
+}
+
+library_benchmark_group!(
+    name = properties;
+    benchmarks = benchmarked_iai, tuple_iai, named
+);
+
+fn main() {
+    benchmarked();
+    tuple();
+    named_test();
+
+    let inputs = benchmarked_iai_inputs();
+    assert_eq!(inputs.len(), 32);
+    // The inputs come from a fixed seed
+    assert_eq!(inputs, benchmarked_iai_inputs());
+
+    let inputs = tuple_iai_inputs();
+    assert_eq!(inputs.len(), 5);
+    assert!(inputs.iter().all(|(_, b)| *b > 0));
+
+    assert!(named_inputs().iter().all(|value| value.len() < 4));
+}
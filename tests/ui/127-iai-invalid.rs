@@ -0,0 +1,23 @@
+use proptest_attr::proptest;
+
+#[proptest(iai = 1)]
+fn invalid_name(value: u8) {
+    let _ = value;
+}
+
+#[proptest(iai, iai_inputs = 0)]
+fn no_inputs(value: u8) {
+    let _ = value;
+}
+
+#[proptest(iai_inputs = 10)]
+fn inputs_without_iai(value: u8) {
+    let _ = value;
+}
+
+#[proptest(iai, strategies = [0..10u8, 10..20u8])]
+fn matrix(value: u8) {
+    let _ = value;
+}
+
+fn main() {}
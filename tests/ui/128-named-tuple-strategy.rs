@@ -0,0 +1,38 @@
+use proptest::prelude::*;
+use proptest_attr::proptest;
+
+// The strategies are listed in a different order than the arguments
+#[proptest(strategy = (count = 1..4usize, text = "[a-z]{1,8}"), cases = 32)]
+fn named(text: String, count: usize) {
+    assert!(!text.is_empty() && text.len() <= 8);
+    assert!((1..4).contains(&count));
+}
+
+// The arguments left out use their own strategies or `Arbitrary`
+#[proptest(strategy = (b = 10..20u8))]
+fn mixed(#[strategy(0..10u8)] a: u8, b: u8, #[map(|c: u8| c % 5)] c: u8, flag: bool) {
+    assert!(a < 10);
+    assert!((10..20).contains(&b));
+    assert!(c < 5);
+    let _ = flag;
+}
+
+#[proptest(strategy = "(value = 0..100u32)")]
+fn single_quoted(#[filter(*value % 2 == 0)] value: u32) {
+    assert!(value < 100);
+    assert_eq!(value % 2, 0);
+}
+
+#[proptest(strategies = [(a = 0..10u8), (a = 10..20u8, b = Just(0u8))])]
+fn matrix(a: u8, b: u8) {
+    assert!(a < 20);
+    let _ = b;
+}
+
+fn main() {
+    named();
+    mixed();
+    single_quoted();
+    matrix_0();
+    matrix_1();
+}
@@ -0,0 +1,28 @@
+use proptest_attr::proptest;
+
+#[proptest(strategy = (a = 0..10u8, 0..10u8))]
+fn partially_named(a: u8, b: u8) {
+    let _ = (a, b);
+}
+
+#[proptest(strategy = (c = 0..10u8))]
+fn unknown_argument(a: u8, b: u8) {
+    let _ = (a, b);
+}
+
+#[proptest(strategy = (a = 0..10u8, a = 10..20u8))]
+fn duplicate(a: u8, b: u8) {
+    let _ = (a, b);
+}
+
+#[proptest(strategy = (a = 0..10u8))]
+fn with_attribute(#[strategy(10..20u8)] a: u8, b: u8) {
+    let _ = (a, b);
+}
+
+#[proptest(strategy = (a.0 = 0..10u8))]
+fn not_a_name(a: (u8,), b: u8) {
+    let _ = (a, b);
+}
+
+fn main() {}
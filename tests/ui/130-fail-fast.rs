@@ -0,0 +1,65 @@
+use proptest::prelude::*;
+use proptest_attr::proptest;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+static THREES: AtomicU32 = AtomicU32::new(0);
+
+#[proptest(fail_fast = false, cases = 200, strategy = 0..4u8)]
+fn panics(value: u8) {
+    if value == 3 {
+        THREES.fetch_add(1, Ordering::Relaxed);
+    }
+    assert_ne!(value, 3);
+}
+
+// Rejected cases are neither failures nor counted
+#[proptest(fail_fast = false, cases = 100, strategy = 0..10u8)]
+fn errors(value: u8) -> Result<(), TestCaseError> {
+    prop_assume!(value != 0);
+    prop_assert!(value < 5, "{} is too large", value);
+    Ok(())
+}
+
+#[proptest(fail_fast = false)]
+fn holds(a: u8, b: u8) {
+    assert_eq!(a.wrapping_add(b), b.wrapping_add(a));
+}
+
+#[proptest(fail_fast = true, strategy = 0..4u8)]
+fn fails_fast(value: u8) {
+    assert_ne!(value, 3);
+}
+
+fn panic_message(test: fn()) -> String {
+    let payload = std::panic::catch_unwind(test).unwrap_err();
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => payload.downcast_ref::<&str>().unwrap().to_string(),
+    }
+}
+
+fn main() {
+    let message = panic_message(panics);
+    let threes = THREES.load(Ordering::Relaxed);
+    assert!(threes > 0);
+    assert!(
+        message.contains(&format!("`panics` failed for {} of 200 cases (", threes)),
+        "{}",
+        message
+    );
+    assert!(message.contains("first failure: assertion"), "{}", message);
+    assert!(
+        message.contains("failing input (not shrunk): 3\n"),
+        "{}",
+        message
+    );
+
+    let message = panic_message(errors);
+    assert!(message.contains(" of 100 cases ("), "{}", message);
+    assert!(message.contains(" is too large"), "{}", message);
+
+    holds();
+
+    let message = panic_message(fails_fast);
+    assert!(!message.contains("not shrunk"), "{}", message);
+}
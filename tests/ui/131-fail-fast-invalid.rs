@@ -0,0 +1,23 @@
+use proptest_attr::proptest;
+
+#[proptest(fail_fast = 0)]
+fn not_a_bool(value: u8) {
+    let _ = value;
+}
+
+#[proptest(fail_fast = false, parallel)]
+fn parallel(value: u8) {
+    let _ = value;
+}
+
+#[proptest(fail_fast = false, continue_on_failure = 2)]
+fn continue_on_failure(value: u8) {
+    let _ = value;
+}
+
+#[proptest(fail_fast = false, no_std)]
+fn no_std(value: u8) {
+    let _ = value;
+}
+
+fn main() {}
@@ -0,0 +1,66 @@
+use proptest::prelude::*;
+use proptest::test_runner::{Config, FailurePersistence, PersistedSeed};
+use proptest_attr::proptest;
+use std::any::Any;
+use std::fmt;
+use std::sync::Mutex;
+
+static SOURCE_FILES: Mutex<Vec<Option<&'static str>>> = Mutex::new(Vec::new());
+
+/// Records the source files the runner looks up the persisted failures for.
+#[derive(Clone, Debug, PartialEq)]
+struct Recorder;
+
+impl FailurePersistence for Recorder {
+    fn load_persisted_failures2(&self, source_file: Option<&'static str>) -> Vec<PersistedSeed> {
+        SOURCE_FILES.lock().unwrap().push(source_file);
+        Vec::new()
+    }
+
+    fn save_persisted_failure2(
+        &mut self,
+        _source_file: Option<&'static str>,
+        _seed: PersistedSeed,
+        _shrunken_value: &dyn fmt::Debug,
+    ) {
+    }
+
+    fn box_clone(&self) -> Box<dyn FailurePersistence> {
+        Box::new(self.clone())
+    }
+
+    fn eq(&self, other: &dyn FailurePersistence) -> bool {
+        other.as_any().downcast_ref::<Self>().is_some()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+fn config() -> Config {
+    Config {
+        failure_persistence: Some(Box::new(Recorder)),
+        ..Config::default()
+    }
+}
+
+#[proptest(config = config(), cases = 1)]
+fn recorded(value: u8) {
+    let _ = value;
+}
+
+#[proptest(config = config(), cases = 1, strategy = 0..10u8)]
+fn recorded_with_strategy(value: u8) -> Result<(), TestCaseError> {
+    prop_assert!(value < 10);
+    Ok(())
+}
+
+fn main() {
+    recorded();
+    recorded_with_strategy();
+    assert_eq!(
+        *SOURCE_FILES.lock().unwrap(),
+        [Some(file!()), Some(file!())]
+    );
+}
@@ -0,0 +1,67 @@
+use proptest::prelude::*;
+use proptest_attr::proptest;
+
+mod checks {
+    use proptest::prelude::*;
+
+    pub fn sorted_permutation(input: &Vec<u8>, output: Vec<u8>) -> Result<(), TestCaseError> {
+        prop_assert!(output.windows(2).all(|pair| pair[0] <= pair[1]));
+        let mut expected = input.clone();
+        expected.sort_unstable();
+        prop_assert_eq!(output, expected);
+        Ok(())
+    }
+}
+
+fn is_sum(inputs: &(u8, u8), output: u16) {
+    assert_eq!(output, u16::from(inputs.0) + u16::from(inputs.1));
+}
+
+fn never_holds(_input: &u8, _output: u8) -> Result<(), TestCaseError> {
+    Err(TestCaseError::fail("postcondition violated"))
+}
+
+#[derive(Debug)]
+struct Overflow;
+
+#[proptest(postcondition = checks::sorted_permutation)]
+fn sort(mut values: Vec<u8>) -> Vec<u8> {
+    values.sort();
+    values
+}
+
+#[proptest(postcondition = "is_sum")]
+fn add(a: u8, b: u8) -> Result<u16, TestCaseError> {
+    prop_assume!(a != 0);
+    Ok(u16::from(a) + u16::from(b))
+}
+
+// The error of the property fails the case before the postcondition is called
+#[proptest(postcondition = is_sum, strategy = (200..=255u8, 200..=255u8))]
+fn overflowing(a: u8, b: u8) -> Result<u16, Overflow> {
+    a.checked_add(b).map(u16::from).ok_or(Overflow)
+}
+
+#[proptest(postcondition = never_holds)]
+fn identity(value: u8) -> u8 {
+    value
+}
+
+fn panic_message(test: fn()) -> String {
+    let payload = std::panic::catch_unwind(test).unwrap_err();
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => payload.downcast_ref::<&str>().unwrap().to_string(),
+    }
+}
+
+fn main() {
+    sort();
+    add();
+
+    let message = panic_message(overflowing);
+    assert!(message.contains("Overflow"), "{}", message);
+
+    let message = panic_message(identity);
+    assert!(message.contains("postcondition violated"), "{}", message);
+}
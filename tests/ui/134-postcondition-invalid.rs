@@ -0,0 +1,15 @@
+use proptest_attr::proptest;
+
+fn check(_input: &u8, _output: u8) {}
+
+#[proptest(postcondition = 1)]
+fn not_a_path(value: u8) -> u8 {
+    value
+}
+
+#[proptest(postcondition = check, fuzz_target)]
+fn with_fuzz_target(value: u8) -> u8 {
+    value
+}
+
+fn main() {}
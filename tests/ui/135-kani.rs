@@ -0,0 +1,25 @@
+use proptest::prelude::*;
+use proptest_attr::proptest;
+
+// The harnesses are only compiled by Kani
+#[proptest(kani)]
+fn plain(#[strategy(0..10u8)] a: u8, #[filter(*b != 0)] b: u16) {
+    assert!(a < 10 && b != 0);
+}
+
+#[proptest(kani = "named", strategy = (0..10u8, 5..=6u32), filter = *a < 5)]
+fn tuple(a: u8, b: u32) -> Result<(), TestCaseError> {
+    prop_assert!(a < 5 && b >= 5);
+    Ok(())
+}
+
+#[proptest(kani = false, strategy = ..100i32)]
+fn disabled(value: i32) {
+    assert!(value < 100);
+}
+
+fn main() {
+    plain();
+    tuple();
+    disabled();
+}
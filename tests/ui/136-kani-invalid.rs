@@ -0,0 +1,20 @@
+use proptest_attr::proptest;
+
+fn check(_input: &u8, _output: u8) {}
+
+#[proptest(kani = 1)]
+fn invalid_name(value: u8) {
+    let _ = value;
+}
+
+#[proptest(kani, postcondition = check)]
+fn with_postcondition(value: u8) -> u8 {
+    value
+}
+
+#[proptest(kani, strategies = [0..10u8, 10..20u8])]
+fn matrix(value: u8) {
+    let _ = value;
+}
+
+fn main() {}
@@ -0,0 +1,39 @@
+use proptest::prelude::*;
+use proptest_attr::proptest;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+static COMMON: AtomicU32 = AtomicU32::new(0);
+static RARE: AtomicU32 = AtomicU32::new(0);
+
+#[proptest(oneof = ["1000 => Just(0u8)", "1 => Just(1u8)"], cases = 100)]
+fn weighted(value: u8) {
+    match value {
+        0 => COMMON.fetch_add(1, Ordering::SeqCst),
+        _ => RARE.fetch_add(1, Ordering::SeqCst),
+    };
+}
+
+// The strategies without a weight are picked with the weight of 1
+#[proptest(oneof = ["3 => 0..10u32", 100..110u32, "u32::MAX - 10.."])]
+fn mixed(value: u32) {
+    assert!(value < 10 || (100..110).contains(&value) || value >= u32::MAX - 10);
+}
+
+// `=>` inside of a strategy does not make it weighted
+#[proptest(oneof = [Just(1u8), "any::<u8>().prop_map(|x| match x { 0 => 2, x => x })"])]
+fn unweighted(value: u8) {
+    assert_ne!(value, 0);
+}
+
+#[proptest(oneof = ["2 => (0..10u8, Just(false))", "(10..20u8, Just(true))"])]
+fn tuples(value: u8, large: bool) {
+    assert_eq!(value >= 10, large);
+}
+
+fn main() {
+    weighted();
+    assert!(COMMON.load(Ordering::SeqCst) > RARE.load(Ordering::SeqCst));
+    mixed();
+    unweighted();
+    tuples();
+}
@@ -0,0 +1,38 @@
+use proptest_attr::proptest;
+
+#[proptest(oneof = "3 => 0..10u8")]
+fn not_a_list(value: u8) {
+    let _ = value;
+}
+
+#[proptest(oneof = [])]
+fn empty(value: u8) {
+    let _ = value;
+}
+
+#[proptest(oneof = ["3 => 0..10u8", "1 => "])]
+fn missing_strategy(value: u8) {
+    let _ = value;
+}
+
+#[proptest(oneof = ["x => 0..10u8", "1 => 10..20u8"])]
+fn weight_not_an_integer(value: u8) {
+    let _ = value;
+}
+
+#[proptest(oneof = ["1.5 => 0..10u8"])]
+fn fractional_weight(value: u8) {
+    let _ = value;
+}
+
+#[proptest(strategy = 0..10u8, oneof = [Just(0u8), Just(1u8)])]
+fn with_strategy(value: u8) {
+    let _ = value;
+}
+
+#[proptest(strategies = [0..10u8], oneof = [Just(0u8), Just(1u8)])]
+fn with_strategies(value: u8) {
+    let _ = value;
+}
+
+fn main() {}
@@ -0,0 +1,56 @@
+use proptest::prelude::*;
+use proptest_attr::proptest;
+use std::sync::Mutex;
+
+static SEEN: Mutex<Vec<u32>> = Mutex::new(Vec::new());
+
+#[proptest(regressions = "regressions/139", cases = 0)]
+fn replayed(value: u32) {
+    SEEN.lock().unwrap().push(value);
+}
+
+// The shared corpus is replayed whatever the configuration says
+#[proptest(
+    regressions = "regressions/139/shared.txt",
+    config = ProptestConfig { failure_persistence: None, ..ProptestConfig::with_cases(0) },
+    strategy = 0..10u32,
+)]
+fn failing(value: u32) {
+    assert!(value > 100);
+}
+
+#[proptest(regressions = "regressions/139-missing", cases = 5, strategy = 0..10u32)]
+fn missing_regressions(value: u32) -> Result<(), TestCaseError> {
+    prop_assert!(value < 10);
+    Ok(())
+}
+
+fn main() {
+    let dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("regressions/139");
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(
+        dir.join("shared.txt"),
+        "# Seeds for failure cases proptest has generated in the past.\n\
+         \n\
+         xs 1 2 3 4 # shrinks to value = 0\n",
+    )
+    .unwrap();
+    std::fs::write(
+        dir.join("other.txt"),
+        "cc 0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef\n",
+    )
+    .unwrap();
+
+    replayed();
+    let seen = SEEN.lock().unwrap().clone();
+    assert_eq!(seen.len(), 2);
+    // The seeds give the same values each time
+    replayed();
+    assert_eq!(SEEN.lock().unwrap()[2..], seen[..]);
+
+    let payload = std::panic::catch_unwind(failing).unwrap_err();
+    let message = payload.downcast::<String>().unwrap();
+    assert!(message.contains("minimal failing input: 0"), "{}", message);
+
+    missing_regressions();
+}
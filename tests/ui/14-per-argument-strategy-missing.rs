@@ -0,0 +1,13 @@
+use proptest::prelude::*;
+use proptest_attr::proptest;
+
+fn main() {
+    basic_test();
+}
+
+#[proptest]
+fn basic_test(#[strategy("0..10u8")] a: u8, b: u32) -> Result<(), TestCaseError> {
+    prop_assert!(a < 10);
+    let _c = a as u32 as u64 + b as u64;
+    Ok(())
+}
@@ -0,0 +1,28 @@
+use proptest_attr::proptest;
+
+#[proptest(regressions = regressions_dir)]
+fn not_a_string(value: u8) {
+    let _ = value;
+}
+
+#[proptest(regressions = "regressions", parallel)]
+fn parallel(value: u8) {
+    let _ = value;
+}
+
+#[proptest(regressions = "regressions", replay = "xs 1 2 3 4")]
+fn replay(value: u8) {
+    let _ = value;
+}
+
+#[proptest(regressions = "regressions", continue_on_failure = 3)]
+fn continue_on_failure(value: u8) {
+    let _ = value;
+}
+
+#[proptest(regressions = "regressions", no_std)]
+fn no_std(value: u8) {
+    let _ = value;
+}
+
+fn main() {}
@@ -0,0 +1,37 @@
+use proptest_attr::proptest;
+use std::sync::Mutex;
+
+static SEEN: Mutex<Vec<(u32, u32)>> = Mutex::new(Vec::new());
+
+// The examples run first, in order, and are not filtered
+#[proptest(example = (3, 7), example = "(0, 0)", cases = 2, filter = a < b)]
+fn pairs(a: u32, b: u32) {
+    SEEN.lock().unwrap().push((a, b));
+}
+
+#[proptest(example = 1000, strategy = 0..10u32)]
+fn failing(value: u32) {
+    assert!(value < 10);
+}
+
+#[proptest(example = String::from("edge"), cases = 1)]
+fn single(text: String) {
+    let _ = text;
+}
+
+fn main() {
+    pairs();
+    let seen = SEEN.lock().unwrap();
+    assert_eq!(seen.len(), 4);
+    assert_eq!(seen[..2], [(3, 7), (0, 0)]);
+
+    let payload = std::panic::catch_unwind(failing).unwrap_err();
+    let message = payload.downcast::<String>().unwrap();
+    assert!(
+        message.contains("minimal failing input: 1000"),
+        "{}",
+        message
+    );
+
+    single();
+}
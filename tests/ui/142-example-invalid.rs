@@ -0,0 +1,18 @@
+use proptest_attr::proptest;
+
+#[proptest(example = (1, 2), parallel)]
+fn parallel(a: u8, b: u8) {
+    let _ = (a, b);
+}
+
+#[proptest(example = 1, continue_on_failure = 3)]
+fn continue_on_failure(value: u8) {
+    let _ = value;
+}
+
+#[proptest(example = (1, "two"))]
+fn wrong_type(a: u8, b: u8) {
+    let _ = (a, b);
+}
+
+fn main() {}
@@ -0,0 +1,29 @@
+use proptest::prelude::*;
+use proptest::test_runner::TestRng;
+use proptest_attr::proptest;
+use std::sync::Mutex;
+
+static DRAWN: Mutex<Vec<(u8, u64)>> = Mutex::new(Vec::new());
+
+// The same seed gives the same values and the same auxiliary randomness
+#[proptest(with_rng, seed = "xs 0123456789abcdef0123456789abcdef", cases = 8)]
+fn seeded(value: u8, mut rng: TestRng) {
+    DRAWN.lock().unwrap().push((value, rng.next_u64()));
+}
+
+#[proptest(with_rng, strategy = (value = 0..10u32))]
+fn named(value: u32, mut rng: TestRng) {
+    assert!(value < 10);
+    let _ = rng.next_u32();
+}
+
+fn main() {
+    seeded();
+    let first = std::mem::take(&mut *DRAWN.lock().unwrap());
+    seeded();
+    assert_eq!(*DRAWN.lock().unwrap(), first);
+    // The RNG is not the same for every case
+    assert!(first.windows(2).any(|pair| pair[0].1 != pair[1].1));
+
+    named();
+}
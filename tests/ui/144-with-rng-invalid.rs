@@ -0,0 +1,31 @@
+use proptest::test_runner::TestRng;
+use proptest_attr::proptest;
+
+#[proptest(with_rng)]
+fn no_arguments() {}
+
+#[proptest(with_rng, strategy = (0..10u32, 0..10u32))]
+fn positional(value: u32, rng: TestRng) {
+    let _ = (value, rng);
+}
+
+#[proptest(with_rng)]
+fn with_strategy(
+    value: u32,
+    #[strategy(proptest::strategy::Just(TestRng::deterministic_rng(Default::default())))]
+    rng: TestRng,
+) {
+    let _ = (value, rng);
+}
+
+#[proptest(with_rng, strategies = [0..10u32])]
+fn matrix(value: u32, rng: TestRng) {
+    let _ = (value, rng);
+}
+
+#[proptest(with_rng)]
+fn wrong_type(value: u32, rng: u64) {
+    let _ = (value, rng);
+}
+
+fn main() {}
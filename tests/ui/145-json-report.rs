@@ -0,0 +1,97 @@
+use proptest::prelude::*;
+use proptest_attr::proptest;
+
+const REPORTS: &str = "reports/145";
+
+#[proptest(json_report = "reports/145/<test>.jsonl", cases = 10)]
+fn passing(value: u8) {
+    let _ = value;
+}
+
+#[proptest(json_report = "reports/145/shared.jsonl", cases = 10, strategy = 0..100u32)]
+fn failing(value: u32) -> Result<(), TestCaseError> {
+    prop_assert!(value < 10, "too large");
+    Ok(())
+}
+
+#[proptest(json_report = "reports/145/shared.jsonl", continue_on_failure = 2, strategy = 0..100u32)]
+fn continued(value: u32) {
+    assert!(value < 10);
+}
+
+fn lines(file: &str) -> Vec<String> {
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join(REPORTS)
+        .join(file);
+    std::fs::read_to_string(path)
+        .unwrap()
+        .lines()
+        .map(String::from)
+        .collect()
+}
+
+fn main() {
+    let dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join(REPORTS);
+    let _ = std::fs::remove_dir_all(&dir);
+
+    // Each run appends a record
+    passing();
+    passing();
+    let passing = lines("passing.jsonl");
+    assert_eq!(passing.len(), 2);
+    for line in &passing {
+        assert!(line.starts_with("{\"test\": \""), "{}", line);
+        assert!(
+            line.contains("::passing\", \"result\": \"passed\", \"seed\": "),
+            "{}",
+            line
+        );
+        assert!(
+            line.contains("\"cases\": 10, \"rejects\": 0, \"shrink_steps\": 0"),
+            "{}",
+            line
+        );
+        assert!(
+            line.ends_with("\"error\": null, \"failing_input\": null}"),
+            "{}",
+            line
+        );
+    }
+
+    assert!(std::panic::catch_unwind(failing).is_err());
+    assert!(std::panic::catch_unwind(continued).is_err());
+    let shared = lines("shared.jsonl");
+    assert_eq!(shared.len(), 2);
+    assert!(
+        shared[0].contains("::failing\", \"result\": \"failed\""),
+        "{}",
+        shared[0]
+    );
+    assert!(
+        shared[0].contains("\"error\": \"too large"),
+        "{}",
+        shared[0]
+    );
+    // The failure is shrunk to the smallest failing value
+    assert!(
+        shared[0].ends_with("\"failing_input\": \"10\"}"),
+        "{}",
+        shared[0]
+    );
+    assert!(
+        shared[1].contains("::continued\", \"result\": \"failed\""),
+        "{}",
+        shared[1]
+    );
+    assert!(
+        shared[1].contains("failed for 2 distinct inputs"),
+        "{}",
+        shared[1]
+    );
+    // The failures are combined into the error
+    assert!(
+        shared[1].ends_with("\"failing_input\": null}"),
+        "{}",
+        shared[1]
+    );
+}
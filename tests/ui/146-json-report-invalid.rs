@@ -0,0 +1,28 @@
+use proptest_attr::proptest;
+
+#[proptest(json_report = report_path)]
+fn not_a_string(value: u8) {
+    let _ = value;
+}
+
+#[proptest(json_report = "report.jsonl", fork)]
+fn fork(value: u8) {
+    let _ = value;
+}
+
+#[proptest(json_report = "report.jsonl", parallel)]
+fn parallel(value: u8) {
+    let _ = value;
+}
+
+#[proptest(json_report = "report.jsonl", fail_fast = false)]
+fn fail_fast(value: u8) {
+    let _ = value;
+}
+
+#[proptest(json_report = "report.jsonl", no_std)]
+fn no_std(value: u8) {
+    let _ = value;
+}
+
+fn main() {}
@@ -0,0 +1,46 @@
+use proptest_attr::proptest;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+static RUNS: AtomicU32 = AtomicU32::new(0);
+
+#[proptest(cases = 5)]
+#[tokio::test]
+async fn tokio_test(value: u8) {
+    let flavor = tokio::runtime::Handle::current().runtime_flavor();
+    assert_eq!(flavor, tokio::runtime::RuntimeFlavor::CurrentThread);
+    tokio::task::yield_now().await;
+    let _ = value;
+    RUNS.fetch_add(1, Ordering::Relaxed);
+}
+
+#[proptest(cases = 5)]
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn tokio_test_options(value: u8) {
+    let flavor = tokio::runtime::Handle::current().runtime_flavor();
+    assert_eq!(flavor, tokio::runtime::RuntimeFlavor::MultiThread);
+    let _ = value;
+    RUNS.fetch_add(1, Ordering::Relaxed);
+}
+
+// The same runtime as the argument
+#[proptest(async = "tokio", cases = 5)]
+#[tokio::test]
+async fn tokio_test_with_argument(value: u8) {
+    let _ = value;
+    RUNS.fetch_add(1, Ordering::Relaxed);
+}
+
+#[proptest(cases = 5)]
+#[allow(unused_variables)]
+#[wasm_bindgen_test::wasm_bindgen_test]
+fn wasm_bindgen_test_last(value: u8) {
+    RUNS.fetch_add(1, Ordering::Relaxed);
+}
+
+fn main() {
+    tokio_test();
+    tokio_test_options();
+    tokio_test_with_argument();
+    wasm_bindgen_test_last();
+    assert_eq!(RUNS.load(Ordering::Relaxed), 20);
+}
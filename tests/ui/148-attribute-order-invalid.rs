@@ -0,0 +1,27 @@
+use proptest_attr::proptest;
+
+#[proptest(async = "async-std")]
+#[tokio::test]
+async fn conflicting_runtime(value: u8) {
+    let _ = value;
+}
+
+#[proptest]
+#[tokio::test(unhandled_panic = "shutdown_runtime")]
+async fn unsupported_option(value: u8) {
+    let _ = value;
+}
+
+#[proptest]
+#[tokio::test(flavor = "current_thread", worker_threads = 2)]
+async fn invalid_options(value: u8) {
+    let _ = value;
+}
+
+#[proptest]
+#[async_std::test]
+fn not_async(value: u8) {
+    let _ = value;
+}
+
+fn main() {}
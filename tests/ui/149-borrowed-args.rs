@@ -0,0 +1,46 @@
+use proptest::prelude::*;
+use proptest_attr::proptest;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+static RUNS: AtomicU32 = AtomicU32::new(0);
+
+fn count(s: &str, bytes: &[u8]) -> usize {
+    s.chars().count() + bytes.len()
+}
+
+#[proptest(cases = 10)]
+fn inferred(s: &str, bytes: &[u8]) {
+    assert!(count(s, bytes) >= bytes.len());
+    RUNS.fetch_add(1, Ordering::Relaxed);
+}
+
+#[proptest(cases = 10)]
+fn per_argument(
+    #[strategy(prop::string::string_regex("[a-z]{1,8}").unwrap())]
+    #[filter(!s.starts_with('z'))]
+    s: &str,
+    #[strategy(prop::collection::vec(0..10u32, 3))] values: &[u32],
+) -> Result<(), TestCaseError> {
+    prop_assert!(!s.is_empty() && !s.starts_with('z'));
+    prop_assert_eq!(values.len(), 3);
+    RUNS.fetch_add(1, Ordering::Relaxed);
+    Ok(())
+}
+
+#[proptest(
+    strategy = ("[0-9]+", Just(vec![1u8, 2])),
+    example = (String::from("42"), vec![3u8]),
+    cases = 10
+)]
+fn whole_strategy(digits: &str, bytes: &[u8]) {
+    assert!(digits.parse::<u128>().is_ok() || digits.len() > 30);
+    assert!(!bytes.is_empty());
+    RUNS.fetch_add(1, Ordering::Relaxed);
+}
+
+fn main() {
+    inferred();
+    per_argument();
+    whole_strategy();
+    assert_eq!(RUNS.load(Ordering::Relaxed), 31);
+}
@@ -0,0 +1,16 @@
+use proptest::prelude::*;
+use proptest_attr::proptest;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+static CASES: AtomicU32 = AtomicU32::new(0);
+
+fn main() {
+    basic_test();
+    assert_eq!(CASES.load(Ordering::SeqCst), 10);
+}
+
+#[proptest(strategy = "0..10u8", cases = 10)]
+fn basic_test(_value: u8) -> Result<(), TestCaseError> {
+    CASES.fetch_add(1, Ordering::SeqCst);
+    Ok(())
+}
@@ -0,0 +1,69 @@
+use proptest::prelude::*;
+use proptest_attr::proptest;
+use std::cell::RefCell;
+use std::collections::HashSet;
+
+thread_local! {
+    static SEEN: RefCell<Vec<(bool, u8)>> = RefCell::new(Vec::new());
+    static HOOKS: RefCell<u32> = RefCell::new(0);
+}
+
+fn count_hook() {
+    HOOKS.with(|hooks| *hooks.borrow_mut() += 1);
+}
+
+// Far fewer distinct inputs than cases: each is tested once and the test still finishes
+#[proptest(dedup, cases = 100, strategy = (any::<bool>(), 0..4u8), before_each = count_hook)]
+fn small_space(flag: bool, value: u8) {
+    SEEN.with(|seen| seen.borrow_mut().push((flag, value)));
+}
+
+// The cases rejected by the test keep their own limit
+#[proptest(dedup = true, cases = 20, max_global_rejects = 10, strategy = 0..1000u32)]
+fn own_rejects(value: u32) -> Result<(), TestCaseError> {
+    prop_assume!(value % 100 != 0);
+    Ok(())
+}
+
+#[proptest(dedup, cases = 10, strategy = 0..3u8)]
+fn failing(value: u8) {
+    assert!(value < 2);
+}
+
+// The candidates of shrinking run even if they were tested before the failure
+#[proptest(
+    dedup,
+    cases = 50,
+    strategy = (0..4u32, 0..1000u32),
+    seed = "xs 000102030405060708090a0b0c0d0e0f"
+)]
+fn shrinking(a: u32, b: u32) {
+    assert!(a < 2 || b < 100);
+}
+
+fn main() {
+    small_space();
+    let seen = SEEN.with(|seen| seen.borrow().clone());
+    let distinct: HashSet<_> = seen.iter().collect();
+    assert_eq!(seen.len(), distinct.len());
+    assert_eq!(distinct.len(), 8);
+    assert_eq!(HOOKS.with(|hooks| *hooks.borrow()), 8);
+
+    own_rejects();
+
+    let message = *std::panic::catch_unwind(failing)
+        .unwrap_err()
+        .downcast::<String>()
+        .unwrap();
+    assert!(message.contains("minimal failing input: 2"), "{}", message);
+
+    let message = *std::panic::catch_unwind(shrinking)
+        .unwrap_err()
+        .downcast::<String>()
+        .unwrap();
+    assert!(
+        message.contains("minimal failing input: (\n    2,\n    100,\n)"),
+        "{}",
+        message
+    );
+}
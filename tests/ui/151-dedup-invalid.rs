@@ -0,0 +1,23 @@
+use proptest_attr::proptest;
+
+#[proptest(dedup = 1)]
+fn not_a_bool(value: u8) {
+    let _ = value;
+}
+
+#[proptest(dedup, fork)]
+fn fork(value: u8) {
+    let _ = value;
+}
+
+#[proptest(dedup, parallel)]
+fn parallel(value: u8) {
+    let _ = value;
+}
+
+#[proptest(dedup, no_std)]
+fn no_std(value: u8) {
+    let _ = value;
+}
+
+fn main() {}
@@ -0,0 +1,69 @@
+use proptest::prelude::*;
+use proptest_attr::proptest;
+
+fn remove_one(items: &Vec<u32>) -> Vec<Vec<u32>> {
+    (0..items.len())
+        .map(|i| [&items[..i], &items[i + 1..]].concat())
+        .collect()
+}
+
+fn panic_message(test: fn()) -> String {
+    let payload = std::panic::catch_unwind(test).unwrap_err();
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => payload.downcast_ref::<&str>().unwrap().to_string(),
+    }
+}
+
+// The items are only removed, so the large one is kept as it was generated
+#[proptest(cases = 100)]
+fn removed_items(
+    #[strategy(prop::collection::vec(0..1000u32, 1..10))]
+    #[map(|mut items: Vec<u32>| { items.sort(); items })]
+    #[shrink_with(remove_one)]
+    items: Vec<u32>,
+) {
+    assert!(items.windows(2).all(|pair| pair[0] <= pair[1]));
+    assert!(items.iter().all(|item| *item < 500));
+}
+
+#[proptest]
+fn closure(
+    #[strategy(100..1000u32)]
+    #[shrink_with(|value: &u32| if *value > 0 { vec![value / 2, value - 1] } else { vec![] })]
+    value: u32,
+) {
+    assert!(value < 10);
+}
+
+// The simpler values not satisfying the filter are skipped
+#[proptest]
+fn filtered(
+    #[strategy(100..1000u32)]
+    #[shrink_with(|value: &u32| vec![value - 1, value - 2])]
+    #[filter(value % 2 == 1)]
+    value: u32,
+) {
+    assert!(value < 10);
+}
+
+fn main() {
+    let message = panic_message(removed_items);
+    let input = message.split("minimal failing input: ").nth(1).unwrap();
+    let input = &input[..input.find("\n]").unwrap()];
+    assert_eq!(input.matches(',').count(), 1, "{}", message);
+
+    let message = panic_message(closure);
+    assert!(
+        message.contains("minimal failing input: 10\n"),
+        "{}",
+        message
+    );
+
+    let message = panic_message(filtered);
+    assert!(
+        message.contains("minimal failing input: 11\n"),
+        "{}",
+        message
+    );
+}
@@ -0,0 +1,35 @@
+use proptest_attr::proptest;
+
+fn shrink(value: &u8) -> Vec<u8> {
+    vec![value / 2]
+}
+
+#[proptest]
+fn multiple(
+    #[shrink_with(shrink)]
+    #[shrink_with(shrink)]
+    value: u8,
+) {
+    let _ = value;
+}
+
+#[proptest]
+fn no_shrink(
+    #[shrink_with(shrink)]
+    #[no_shrink]
+    value: u8,
+) {
+    let _ = value;
+}
+
+#[proptest(strategy = 0..10u8)]
+fn whole_strategy(#[shrink_with(shrink)] value: u8) {
+    let _ = value;
+}
+
+#[proptest]
+fn wrong_type(#[shrink_with(shrink)] value: u32) {
+    let _ = value;
+}
+
+fn main() {}
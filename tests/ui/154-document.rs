@@ -0,0 +1,31 @@
+use proptest::prelude::*;
+use proptest::test_runner::TestRng;
+use proptest_attr::proptest;
+
+/// Doubling does not overflow.
+#[proptest(document, cases = 10)]
+fn simple(#[strategy(0..100u8)] value: u8) {
+    assert!(value < 128);
+}
+
+#[proptest(document = true, strategy = (a = 0..10u8, b = "[a-z]*"), example = (1, "a".to_string()))]
+fn named(a: u8, #[filter(!b.is_empty())] b: String) {
+    assert!(a < 10 && !b.is_empty());
+}
+
+#[proptest(document, strategy = prop::collection::vec(any::<u8>(), 0..10), filter = "!value.is_empty()")]
+fn whole(value: Vec<u8>) {
+    assert!(!value.is_empty());
+}
+
+#[proptest(document, with_rng, cases = 10)]
+fn with_rng(#[no_shrink] value: u8, mut rng: TestRng) {
+    let _ = (value, rng.next_u32());
+}
+
+fn main() {
+    simple();
+    named();
+    whole();
+    with_rng();
+}
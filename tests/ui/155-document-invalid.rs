@@ -0,0 +1,8 @@
+use proptest_attr::proptest;
+
+#[proptest(document = 1)]
+fn not_a_bool(value: u8) {
+    let _ = value;
+}
+
+fn main() {}
@@ -0,0 +1,42 @@
+use proptest::prelude::*;
+use proptest_attr::proptest;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+use std::thread;
+
+// Without `cfg(loom)`, the cases run directly
+#[proptest(loom)]
+fn counter(#[strategy(1..10u32)] threads: u32) {
+    let counter = Arc::new(AtomicU32::new(0));
+    let handles: Vec<_> = (0..threads)
+        .map(|_| {
+            let counter = Arc::clone(&counter);
+            thread::spawn(move || {
+                counter.fetch_add(1, Ordering::SeqCst);
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    assert_eq!(counter.load(Ordering::SeqCst), threads);
+}
+
+#[proptest(loom = true, cases = 10)]
+fn result(a: u8, b: Vec<u8>) -> Result<(), TestCaseError> {
+    prop_assert!(b.len() < 1024 && a <= u8::MAX);
+    Ok(())
+}
+
+#[proptest(loom, expect_panic = "overflow")]
+fn panicking(#[strategy(200..=255u8)] value: u8) {
+    if value.checked_add(100).is_none() {
+        panic!("overflow");
+    }
+}
+
+fn main() {
+    counter();
+    result();
+    panicking();
+}
@@ -0,0 +1,23 @@
+use proptest_attr::proptest;
+
+#[proptest(loom = 1)]
+fn not_a_bool(value: u8) {
+    let _ = value;
+}
+
+#[proptest(loom, parallel)]
+fn parallel(value: u8) {
+    let _ = value;
+}
+
+#[proptest(loom, async = "tokio")]
+async fn async_test(value: u8) {
+    let _ = value;
+}
+
+#[proptest(loom, no_std)]
+fn no_std(value: u8) {
+    let _ = value;
+}
+
+fn main() {}
@@ -0,0 +1,42 @@
+use proptest::prelude::*;
+use proptest_attr::proptest;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+static FILTERED: AtomicU32 = AtomicU32::new(0);
+
+fn enough_cases(cases: u32, rejected: u32) {
+    assert!(rejected * 10 <= cases * 9, "too many rejected cases");
+}
+
+#[proptest(cases = 20, on_complete = |summary| assert_eq!((summary.cases, summary.passed), (20, 20)))]
+fn counted(value: u8) {
+    let _ = value;
+}
+
+#[proptest(
+    cases = 20,
+    on_complete = "|summary| enough_cases(summary.cases, summary.rejected)"
+)]
+fn assumed(value: u8) -> Result<(), TestCaseError> {
+    prop_assume!(value % 2 == 0);
+    Ok(())
+}
+
+#[proptest(cases = 20, on_complete = |summary| FILTERED.store(summary.filtered, Ordering::SeqCst))]
+fn filtered(#[filter(*value % 2 == 0)] value: u8) {
+    assert_eq!(value % 2, 0);
+}
+
+#[proptest(cases = 20, on_complete = |summary| assert!(summary.rejected > 10, "{:?}", summary))]
+fn over_rejected(value: u8) -> Result<(), TestCaseError> {
+    prop_assume!(value % 8 == 0);
+    Ok(())
+}
+
+fn main() {
+    counted();
+    assumed();
+    filtered();
+    assert!(FILTERED.load(Ordering::SeqCst) > 0);
+    over_rejected();
+}
@@ -0,0 +1,18 @@
+use proptest_attr::proptest;
+
+#[proptest(on_complete = |summary| summary.missing)]
+fn missing_field(value: u8) {
+    let _ = value;
+}
+
+#[proptest(on_complete = |_| (), parallel)]
+fn parallel(value: u8) {
+    let _ = value;
+}
+
+#[proptest(on_complete = |_| (), no_std)]
+fn no_std(value: u8) {
+    let _ = value;
+}
+
+fn main() {}
@@ -0,0 +1,84 @@
+mod helpers {
+    use proptest::prelude::*;
+
+    pub(crate) fn digit() -> impl Strategy<Value = u8> {
+        0..10u8
+    }
+}
+
+mod properties {
+    use proptest::prelude::*;
+    use proptest_attr::{proptest, proptest_config};
+
+    fn small() -> impl Strategy<Value = u8> {
+        0..5u8
+    }
+
+    #[proptest(strategy = super::helpers::digit(), cases = 10)]
+    pub(crate) fn whole(value: u8) {
+        assert!(value < 10);
+    }
+
+    #[proptest(types = (u8, u16), cases = 10)]
+    pub(crate) fn generic<T: From<u8> + PartialOrd + std::fmt::Debug>(
+        #[strategy(super::helpers::digit().prop_map(T::from))] value: T,
+    ) {
+        assert!(value < T::from(10));
+    }
+
+    #[proptest(types = (u8, u32), strategy = "self::small()", cases = 10)]
+    pub(crate) fn generic_string<T: From<u8> + PartialOrd>(value: u8) {
+        assert!(T::from(value) < T::from(5));
+    }
+
+    #[proptest(consts = [5, 10], cases = 10)]
+    pub(crate) fn constant<const N: u8>(
+        #[strategy(super::helpers::digit())]
+        #[filter(*value < N)]
+        value: u8,
+    ) {
+        assert!(value < N);
+    }
+
+    pub(crate) fn run_parser() {
+        parser_proptest::roundtrip();
+        parser_proptest::per_argument();
+    }
+
+    pub(crate) struct Parser;
+
+    #[proptest_config(cases = 10)]
+    impl Parser {
+        fn inputs() -> impl Strategy<Value = u8> {
+            super::helpers::digit()
+        }
+
+        fn parse(input: &str) -> u8 {
+            input.parse().unwrap()
+        }
+
+        #[proptest(strategy = Self::inputs())]
+        fn roundtrip(value: u8) {
+            assert_eq!(Self::parse(&value.to_string()), value);
+        }
+
+        #[proptest]
+        fn per_argument(
+            #[strategy(Self::inputs())] value: u8,
+            #[strategy(self::small())] small: u8,
+        ) {
+            assert!(value < 10 && small < 5);
+        }
+    }
+}
+
+fn main() {
+    properties::whole();
+    properties::generic::u8();
+    properties::generic::u16();
+    properties::generic_string::u8();
+    properties::generic_string::u32();
+    properties::constant::n_5();
+    properties::constant::n_10();
+    properties::run_parser();
+}
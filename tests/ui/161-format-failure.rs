@@ -0,0 +1,53 @@
+use proptest::prelude::*;
+use proptest::test_runner::TestCaseError;
+use proptest_attr::proptest;
+
+fn hex_dump(bytes: &Vec<u8>, error: &TestCaseError) -> String {
+    let hex: Vec<_> = bytes.iter().map(|byte| format!("{:02x}", byte)).collect();
+    format!("{}\ninput: [{}]", error, hex.join(" "))
+}
+
+fn pair(input: &(u8, String), error: &TestCaseError) -> String {
+    format!("{} for {} and {:?}", error, input.0, input.1)
+}
+
+#[proptest(format_failure = hex_dump, seed = "xs 0123456789abcdef0123456789abcdef")]
+fn hex(#[strategy(prop::collection::vec(any::<u8>(), 1..100))] bytes: Vec<u8>) {
+    assert!(bytes.iter().all(|byte| *byte < 0xf0));
+}
+
+#[proptest(format_failure = "pair", cases = 10, strategy = (Just(200u8), Just("a".to_string())))]
+fn tuple(a: u8, b: String) -> Result<(), TestCaseError> {
+    prop_assert!(a < 100, "too large");
+    let _ = b;
+    Ok(())
+}
+
+#[proptest(format_failure = hex_dump, cases = 10)]
+fn passing(bytes: Vec<u8>) {
+    let _ = bytes;
+}
+
+fn main() {
+    passing();
+    let panics = [
+        std::panic::catch_unwind(hex).unwrap_err(),
+        std::panic::catch_unwind(tuple).unwrap_err(),
+    ];
+    let messages: Vec<_> = panics
+        .iter()
+        .map(|panic| panic.downcast_ref::<String>().unwrap().clone())
+        .collect();
+    assert!(messages[0].contains("input: [f0]"), "{}", messages[0]);
+    assert!(messages[0].contains("seed: xs"), "{}", messages[0]);
+    assert!(
+        messages[1].starts_with("Case failed: too large"),
+        "{}",
+        messages[1]
+    );
+    assert!(
+        messages[1].contains(" for 200 and \"a\"\n"),
+        "{}",
+        messages[1]
+    );
+}
@@ -0,0 +1,23 @@
+use proptest::test_runner::TestCaseError;
+use proptest_attr::proptest;
+
+fn wrong_type(input: &u32, error: &TestCaseError) -> String {
+    format!("{} {}", input, error)
+}
+
+#[proptest(format_failure = wrong_type)]
+fn mismatched(value: u8) {
+    let _ = value;
+}
+
+#[proptest(format_failure = 1)]
+fn not_a_path(value: u8) {
+    let _ = value;
+}
+
+#[proptest(format_failure = wrong_type, falsify)]
+fn falsify(value: u8) {
+    let _ = value;
+}
+
+fn main() {}
@@ -0,0 +1,67 @@
+use proptest::prelude::*;
+use proptest::test_runner::TestCaseError;
+use proptest_attr::proptest;
+
+const ASSERT_LINE: u32 = line!() + 4;
+
+#[proptest(seed = "xs 0123456789abcdef0123456789abcdef")]
+fn asserting(a: u8) {
+    assert_eq!(a / 128, 0);
+}
+
+const ATTRIBUTE_LINE: u32 = line!() + 1;
+#[proptest(cases = 10, seed = "xs 0123456789abcdef0123456789abcdef")]
+fn prop_asserting(a: u8) -> Result<(), TestCaseError> {
+    prop_assert!(a > 255);
+    Ok(())
+}
+
+fn failure(test: &str) -> (String, String) {
+    let output = std::process::Command::new(std::env::current_exe().unwrap())
+        .env("LOCATION_TEST", test)
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    // The last panic is the failure of the test
+    let last = stderr.rfind("panicked at ").unwrap();
+    let (location, message) = stderr[last..].split_once(":\n").unwrap();
+    (location.to_string(), message.to_string())
+}
+
+fn main() {
+    match std::env::var("LOCATION_TEST").as_deref() {
+        Ok("asserting") => return asserting(),
+        Ok("prop_asserting") => return prop_asserting(),
+        _ => {}
+    }
+
+    let (location, message) = failure("asserting");
+    let expected = format!("panicked at {}:{}:", file!(), ASSERT_LINE);
+    assert!(location.starts_with(&expected), "{}", location);
+    assert!(message.starts_with("Test failed: "), "{}", message);
+    assert!(
+        message.contains("minimal failing input: 128"),
+        "{}",
+        message
+    );
+    assert!(message.contains("seed: xs"), "{}", message);
+
+    // The message of `prop_assert!` has the location already
+    let (location, message) = failure("prop_asserting");
+    let expected = format!("panicked at {}:{}:", file!(), ATTRIBUTE_LINE);
+    assert!(location.starts_with(&expected), "{}", location);
+    assert!(
+        message.starts_with("Test failed: assertion failed"),
+        "{}",
+        message
+    );
+
+    let panic = std::panic::catch_unwind(asserting).unwrap_err();
+    let message = panic.downcast_ref::<String>().unwrap();
+    assert!(
+        message.contains("assertion `left == right` failed"),
+        "{}",
+        message
+    );
+}
@@ -0,0 +1,60 @@
+use proptest::prelude::*;
+use proptest::test_runner::TestRng;
+use proptest_attr::proptest;
+use proptest_attr_runtime::Context;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Mutex;
+
+static CASES: Mutex<Vec<u32>> = Mutex::new(Vec::new());
+
+#[proptest(with_context, cases = 20, seed = "xs 0123456789abcdef0123456789abcdef")]
+fn indexed(a: u8, ctx: &Context) {
+    let _ = a;
+    assert_eq!(ctx.seed(), Some("xs 0123456789abcdef0123456789abcdef"));
+    CASES.lock().unwrap().push(ctx.case());
+}
+
+static REJECTED: AtomicU32 = AtomicU32::new(0);
+
+#[proptest(with_context, cases = 20)]
+fn rejecting(a: u8, ctx: &Context) {
+    if a % 2 == 1 {
+        REJECTED.fetch_add(1, Ordering::Relaxed);
+        ctx.reject("odd");
+    }
+    assert_eq!(a % 2, 0);
+}
+
+#[proptest(with_context, max_global_rejects = 5, cases = 10)]
+fn rejecting_all(ctx: Context) -> Result<(), TestCaseError> {
+    ctx.reject(format!("case {}", ctx.case()))
+}
+
+#[proptest(with_context, with_rng, runner = proptest::test_runner::TestRunner::deterministic())]
+fn after_rng(a: u8, mut rng: TestRng, ctx: &Context) {
+    let _ = (a, rng.next_u32());
+    assert_eq!(ctx.seed(), None);
+}
+
+#[proptest(with_context, expect_panic = "too large", cases = 10)]
+fn panicking(#[strategy(100u8..)] a: u8, ctx: &Context) {
+    if a == 100 {
+        ctx.reject("the boundary");
+    }
+    panic!("too large: {}", a);
+}
+
+fn main() {
+    indexed();
+    assert_eq!(*CASES.lock().unwrap(), (0..20).collect::<Vec<_>>());
+
+    rejecting();
+    assert!(REJECTED.load(Ordering::Relaxed) > 0);
+
+    let panic = std::panic::catch_unwind(rejecting_all).unwrap_err();
+    let message = panic.downcast_ref::<String>().unwrap();
+    assert!(message.contains("Too many global rejects"), "{}", message);
+
+    after_rng();
+    panicking();
+}
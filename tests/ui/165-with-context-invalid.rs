@@ -0,0 +1,31 @@
+use proptest_attr::proptest;
+
+#[proptest(with_context)]
+fn no_arguments() {}
+
+#[proptest(with_context)]
+fn generated_context(#[strategy(proptest::strategy::Just(0))] ctx: u8) {
+    let _ = ctx;
+}
+
+#[proptest(with_context, parallel)]
+fn parallel(a: u8, ctx: &proptest_attr_runtime::Context) {
+    let _ = (a, ctx);
+}
+
+#[proptest(with_context, loom)]
+fn loom(a: u8, ctx: &proptest_attr_runtime::Context) {
+    let _ = (a, ctx);
+}
+
+#[proptest(with_context, no_std)]
+fn no_std(a: u8, ctx: &proptest_attr_runtime::Context) {
+    let _ = (a, ctx);
+}
+
+#[proptest(with_context)]
+fn wrong_type(a: u8, ctx: u32) {
+    let _ = (a, ctx);
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+use proptest_attr::proptest;
+
+#[proptest(afl = 1)]
+fn invalid_name(value: u8) {
+    let _ = value;
+}
+
+#[proptest(afl, no_std)]
+fn no_std(value: u8) {
+    let _ = value;
+}
+
+#[proptest(afl, with_context)]
+fn with_context(value: u8, ctx: &proptest_attr_runtime::Context) {
+    let _ = (value, ctx);
+}
+
+fn main() {}
@@ -0,0 +1,85 @@
+use proptest_attr::{proptest, proptest_arbitrary};
+use std::collections::{BTreeMap, HashSet, VecDeque};
+
+#[proptest]
+fn ranges(#[range(0..=10)] a: u8, #[range(-5..5)] b: i64, #[range(0.0..1.0)] c: f64) {
+    assert!(a <= 10);
+    assert!((-5..5).contains(&b));
+    assert!((0.0..1.0).contains(&c));
+}
+
+#[proptest]
+fn lengths(
+    #[len(0..32)] bytes: Vec<u8>,
+    #[len(1..8)] s: String,
+    #[len(3)] deque: VecDeque<bool>,
+    #[len(0..4)] set: HashSet<u32>,
+    #[len(2..=3)] map: BTreeMap<u8, String>,
+) {
+    assert!(bytes.len() < 32);
+    assert!((1..8).contains(&s.chars().count()));
+    assert_eq!(deque.len(), 3);
+    assert!(set.len() < 4);
+    assert!((2..=3).contains(&map.len()));
+}
+
+#[proptest]
+fn element_ranges(
+    #[len(1..16)]
+    #[range(b'a'..=b'z')]
+    bytes: Vec<u8>,
+    #[range('0'..='9')]
+    #[len(4)]
+    digits: String,
+) {
+    assert!(!bytes.is_empty() && bytes.iter().all(u8::is_ascii_lowercase));
+    assert_eq!(digits.len(), 4);
+    assert!(digits.chars().all(|c| c.is_ascii_digit()));
+}
+
+#[proptest]
+fn borrowed(
+    #[len(0..4)] s: &str,
+    #[len(1..4)]
+    #[range(0..10)]
+    items: &[u32],
+) {
+    assert!(s.chars().count() < 4);
+    assert!((1..4).contains(&items.len()) && items.iter().all(|item| *item < 10));
+}
+
+#[proptest]
+fn with_map_and_filter(
+    #[range(0..100u32)]
+    #[map(|n| n * 2)]
+    #[filter(even % 3 != 0)]
+    even: u32,
+) {
+    assert_eq!(even % 2, 0);
+    assert!(even < 200 && even % 3 != 0);
+}
+
+#[proptest_arbitrary]
+#[derive(Debug)]
+struct Word {
+    #[len(1..10)]
+    #[range('a'..='z')]
+    text: String,
+    #[range(1..=3)]
+    count: u8,
+}
+
+#[proptest]
+fn arbitrary(word: Word) {
+    assert!(!word.text.is_empty() && word.text.chars().all(|c| c.is_ascii_lowercase()));
+    assert!((1..=3).contains(&word.count));
+}
+
+fn main() {
+    ranges();
+    lengths();
+    element_ranges();
+    borrowed();
+    with_map_and_filter();
+    arbitrary();
+}
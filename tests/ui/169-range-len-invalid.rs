@@ -0,0 +1,68 @@
+use proptest_attr::proptest;
+
+#[proptest]
+fn not_a_range(#[range(10)] a: u8) {
+    let _ = a;
+}
+
+#[proptest]
+fn duplicate_range(
+    #[range(0..10)]
+    #[range(0..20)]
+    a: u8,
+) {
+    let _ = a;
+}
+
+#[proptest]
+fn duplicate_len(
+    #[len(0..10)]
+    #[len(0..20)]
+    a: Vec<u8>,
+) {
+    let _ = a;
+}
+
+#[proptest]
+fn with_strategy(
+    #[strategy(0..10u8)]
+    #[range(0..20)]
+    a: u8,
+) {
+    let _ = a;
+}
+
+#[proptest]
+fn not_a_collection(#[len(0..10)] a: u8) {
+    let _ = a;
+}
+
+#[proptest]
+fn len_with_map(
+    #[len(0..10)]
+    #[map(|v: Vec<u8>| v.len())]
+    a: usize,
+) {
+    let _ = a;
+}
+
+#[proptest]
+fn range_on_map(
+    #[len(0..10)]
+    #[range(0..10)]
+    a: std::collections::HashMap<u8, u8>,
+) {
+    let _ = a;
+}
+
+#[proptest]
+fn full_range(#[range(..)] a: u8) {
+    let _ = a;
+}
+
+#[proptest]
+fn half_open_chars(#[range('a'..'z')] c: char) {
+    let _ = c;
+}
+
+fn main() {}
@@ -0,0 +1,28 @@
+use proptest::prelude::*;
+use proptest_attr::proptest;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+static CONFIG_CASES: AtomicU32 = AtomicU32::new(0);
+static OVERRIDDEN_CASES: AtomicU32 = AtomicU32::new(0);
+
+fn main() {
+    config_test();
+    assert_eq!(CONFIG_CASES.load(Ordering::SeqCst), 7);
+    overridden_test();
+    assert_eq!(OVERRIDDEN_CASES.load(Ordering::SeqCst), 3);
+}
+
+#[proptest(
+    strategy = "0..10u8",
+    config = "ProptestConfig { cases: 7, ..ProptestConfig::default() }"
+)]
+fn config_test(_value: u8) -> Result<(), TestCaseError> {
+    CONFIG_CASES.fetch_add(1, Ordering::SeqCst);
+    Ok(())
+}
+
+#[proptest(strategy = "0..10u8", config = "ProptestConfig::with_cases(7)", cases = 3)]
+fn overridden_test(_value: u8) -> Result<(), TestCaseError> {
+    OVERRIDDEN_CASES.fetch_add(1, Ordering::SeqCst);
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use proptest::prelude::*;
+use proptest_attr::proptest;
+use std::sync::Mutex;
+
+static SEEN: Mutex<Vec<(u8, u8)>> = Mutex::new(Vec::new());
+
+#[proptest(cases = 10)]
+#[test_case(0, 0)]
+#[test_case(255, 1 ; "max value")]
+#[test_case(7, 9 ; "2 small values")]
+fn ordered_sum(a: u8, b: u8) {
+    SEEN.lock().unwrap().push((a, b));
+    assert_eq!(a as u16 + b as u16, b as u16 + a as u16);
+}
+
+#[proptest(cases = 10)]
+#[test_case("", 0)]
+#[test_case("abc", 3 ; "ascii")]
+fn borrowed(s: &str, #[strategy(0..4usize)] limit: usize) -> Result<(), TestCaseError> {
+    prop_assert!(s.len() >= limit || s.len() < 4);
+    Ok(())
+}
+
+#[proptest(cases = 10)]
+#[test_case::test_case(vec![3, 1, 2])]
+fn sorted(#[map(|mut items: Vec<u32>| { items.sort(); items })] items: Vec<u32>) {
+    assert!(items.windows(2).all(|pair| pair[0] <= pair[1]));
+}
+
+#[proptest(cases = 10)]
+#[test_case(1 ; "failing")]
+fn odd(
+    #[strategy(0..10u8)]
+    #[map(|n| n * 2 + 1)]
+    n: u8,
+) {
+    assert_eq!(n % 2, 1);
+}
+
+fn main() {
+    ordered_sum();
+    let cases = SEEN.lock().unwrap().len();
+    assert_eq!(cases, 10);
+
+    ordered_sum::case_1();
+    ordered_sum::max_value();
+    ordered_sum::_2_small_values();
+    assert_eq!(SEEN.lock().unwrap()[cases..], [(0, 0), (255, 1), (7, 9)]);
+
+    borrowed();
+    borrowed::case_1().unwrap();
+    borrowed::ascii().unwrap();
+
+    sorted();
+    let panic = std::panic::catch_unwind(sorted::case_1).unwrap_err();
+    let message = panic.downcast_ref::<&str>().unwrap();
+    assert!(message.contains("assertion failed"), "{}", message);
+
+    odd();
+    odd::failing();
+}
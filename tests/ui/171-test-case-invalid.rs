@@ -0,0 +1,52 @@
+use proptest_attr::proptest;
+
+#[proptest]
+#[test_case(1)]
+fn wrong_arity(a: u8, b: u8) {
+    let _ = (a, b);
+}
+
+#[proptest]
+#[test_case(1 => 2)]
+fn expected_result(a: u8) {
+    let _ = a;
+}
+
+#[proptest]
+#[test_case(1 ; "one")]
+#[test_case(2 ; "One")]
+fn duplicate_name(a: u8) {
+    let _ = a;
+}
+
+#[proptest]
+#[test_case(1 ; "!")]
+fn no_name(a: u8) {
+    let _ = a;
+}
+
+#[proptest]
+#[test_case(1 ; "one" "two")]
+fn trailing_tokens(a: u8) {
+    let _ = a;
+}
+
+#[proptest(with_rng)]
+#[test_case(1)]
+fn with_rng(a: u8, rng: &mut proptest::test_runner::TestRng) {
+    let _ = (a, rng);
+}
+
+#[proptest(types = (u8, u16))]
+#[test_case(1)]
+fn generic<T: std::fmt::Debug>(a: T) {
+    let _ = a;
+}
+
+#[proptest]
+#[test_case(1)]
+fn wrong_type(a: String) {
+    let _ = a;
+}
+
+fn main() {}
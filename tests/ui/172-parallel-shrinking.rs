@@ -0,0 +1,30 @@
+use proptest_attr::proptest;
+
+#[proptest(
+    parallel,
+    threads = 3,
+    cases = 10,
+    seed = "xs 0123456789abcdef0123456789abcdef"
+)]
+fn failing(#[strategy(0..1_000_000u32)] a: u32) {
+    assert!(a < 1000);
+}
+
+fn main() {
+    if std::env::var("PARALLEL_SHRINKING_TEST").is_ok() {
+        return failing();
+    }
+
+    let output = std::process::Command::new(std::env::current_exe().unwrap())
+        .env("PARALLEL_SHRINKING_TEST", "1")
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    // Every thread has the shrinking budget of the whole run, enough to reach the minimal input
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(
+        stderr.contains("minimal failing input: 1000\n"),
+        "{}",
+        stderr
+    );
+}
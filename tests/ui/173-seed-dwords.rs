@@ -0,0 +1,29 @@
+use proptest::prelude::*;
+use proptest_attr::proptest;
+use std::sync::Mutex;
+
+static HEX: Mutex<Vec<u32>> = Mutex::new(Vec::new());
+static DWORDS: Mutex<Vec<u32>> = Mutex::new(Vec::new());
+
+fn main() {
+    hex_test();
+    dwords_test();
+    assert_eq!(*HEX.lock().unwrap(), *DWORDS.lock().unwrap());
+}
+
+#[proptest(
+    strategy = "any::<u32>()",
+    seed = "xs 000102030405060708090a0b0c0d0e0f"
+)]
+fn hex_test(value: u32) {
+    HEX.lock().unwrap().push(value);
+}
+
+// The same seed as written to the regression files
+#[proptest(
+    strategy = "any::<u32>()",
+    seed = "xs 50462976 117835012 185207048 252579084"
+)]
+fn dwords_test(value: u32) {
+    DWORDS.lock().unwrap().push(value);
+}
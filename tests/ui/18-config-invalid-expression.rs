@@ -0,0 +1,11 @@
+use proptest::prelude::*;
+use proptest_attr::proptest;
+
+fn main() {
+    basic_test();
+}
+
+#[proptest(strategy = "0..10u8", config = "ProptestConfig {")]
+fn basic_test(_value: u8) -> Result<(), TestCaseError> {
+    Ok(())
+}
@@ -0,0 +1,14 @@
+use proptest::prelude::*;
+use proptest_attr::proptest;
+
+#[derive(Debug)]
+struct NotArbitrary;
+
+fn main() {
+    basic_test();
+}
+
+#[proptest]
+fn basic_test(_value: NotArbitrary) -> Result<(), TestCaseError> {
+    Ok(())
+}
@@ -0,0 +1,48 @@
+use proptest::prelude::*;
+use proptest_attr::proptest;
+use std::future::Future;
+use std::pin::pin;
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake};
+use std::thread::{self, Thread};
+
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let waker = Arc::new(ThreadWaker(thread::current())).into();
+    let mut context = Context::from_waker(&waker);
+    loop {
+        match future.as_mut().poll(&mut context) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => thread::park(),
+        }
+    }
+}
+
+async fn double(value: u32) -> u32 {
+    value * 2
+}
+
+fn check_range(value: u32) -> Result<(), TestCaseError> {
+    prop_assert!(value < 10);
+    Ok(())
+}
+
+fn main() {
+    basic_test();
+}
+
+#[proptest(strategy = "0..10u32", async = "block_on")]
+async fn basic_test(value: u32) -> Result<(), TestCaseError> {
+    check_range(value)?;
+    let doubled = double(value).await;
+    prop_assert_eq!(doubled, value + value);
+    Ok(())
+}
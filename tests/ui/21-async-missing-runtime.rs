@@ -0,0 +1,11 @@
+use proptest::prelude::*;
+use proptest_attr::proptest;
+
+fn main() {
+    basic_test();
+}
+
+#[proptest(strategy = "0..10u32")]
+async fn basic_test(_value: u32) -> Result<(), TestCaseError> {
+    Ok(())
+}
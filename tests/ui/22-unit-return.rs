@@ -0,0 +1,17 @@
+use proptest::prelude::*;
+use proptest_attr::proptest;
+
+fn main() {
+    basic_test();
+    explicit_unit_test();
+}
+
+#[proptest(strategy = "0..10u8")]
+fn basic_test(value: u8) {
+    assert!(value < 10);
+}
+
+#[proptest(strategy = "(0..10u8, 10..100u32)")]
+fn explicit_unit_test(a: u8, b: u32) -> () {
+    assert_eq!(a as u32 + b, b + a as u32);
+}
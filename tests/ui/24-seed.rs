@@ -0,0 +1,36 @@
+use proptest::prelude::*;
+use proptest_attr::proptest;
+use std::sync::Mutex;
+
+static FIRST: Mutex<Vec<u32>> = Mutex::new(Vec::new());
+static SECOND: Mutex<Vec<u32>> = Mutex::new(Vec::new());
+static XORSHIFT: Mutex<Vec<u32>> = Mutex::new(Vec::new());
+
+fn main() {
+    first_test();
+    second_test();
+    xorshift_test();
+    assert_eq!(*FIRST.lock().unwrap(), *SECOND.lock().unwrap());
+    assert_eq!(XORSHIFT.lock().unwrap().len(), 256);
+}
+
+#[proptest(
+    strategy = "any::<u32>()",
+    seed = "cc 000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"
+)]
+fn first_test(value: u32) {
+    FIRST.lock().unwrap().push(value);
+}
+
+#[proptest(
+    strategy = "any::<u32>()",
+    seed = "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"
+)]
+fn second_test(value: u32) {
+    SECOND.lock().unwrap().push(value);
+}
+
+#[proptest(strategy = "any::<u32>()", seed = "xs 000102030405060708090a0b0c0d0e0f")]
+fn xorshift_test(value: u32) {
+    XORSHIFT.lock().unwrap().push(value);
+}
@@ -0,0 +1,12 @@
+use proptest_attr::proptest;
+
+fn main() {
+    basic_test();
+    dwords_test();
+}
+
+#[proptest(strategy = "0..10u8", seed = "cc 0102")]
+fn basic_test(_value: u8) {}
+
+#[proptest(strategy = "0..10u8", seed = "xs 1 2 3 4294967296")]
+fn dwords_test(_value: u8) {}
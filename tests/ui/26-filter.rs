@@ -0,0 +1,27 @@
+use proptest::prelude::*;
+use proptest_attr::proptest;
+
+fn main() {
+    argument_filter_test();
+    function_filter_test();
+    tuple_strategy_filter_test();
+}
+
+#[proptest]
+fn argument_filter_test(#[filter("x % 2 == 0")] x: u8, #[filter("!s.is_empty()")] s: String) {
+    assert_eq!(x % 2, 0);
+    assert!(!s.is_empty());
+}
+
+#[proptest(filter = "a < b")]
+fn function_filter_test(#[strategy("0..10u8")] a: u8, b: u8) {
+    assert!(a < b);
+}
+
+#[proptest(strategy = "(0..10u8, 0..10u8)", filter = "a != b")]
+fn tuple_strategy_filter_test(#[filter("*a > 0")] mut a: u8, b: u8) {
+    assert!(a > 0);
+    assert_ne!(a, b);
+    a -= 1;
+    let _ = a;
+}
@@ -0,0 +1,10 @@
+use proptest_attr::proptest;
+
+fn main() {
+    basic_test();
+}
+
+#[proptest(strategy = "0..10u8")]
+fn basic_test(#[filter(42)] x: u8) {
+    assert_eq!(x % 2, 0);
+}
@@ -0,0 +1,8 @@
+use proptest_attr::proptest;
+
+fn main() {
+    basic_test();
+}
+
+#[proptest(strategy = "0..10u8", no_test_attr, test_attr = "tokio::test")]
+fn basic_test(_value: u8) {}
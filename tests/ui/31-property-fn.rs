@@ -0,0 +1,23 @@
+use proptest::prelude::*;
+use proptest_attr::proptest;
+
+fn main() {
+    basic_test();
+    basic_test_property(3, 7);
+
+    named_test();
+    assert!(is_even(4).is_ok());
+    assert!(is_even(5).is_err());
+}
+
+#[proptest(strategy = "(0..10u8, 10..100u32)", property_fn)]
+fn basic_test(a: u8, mut b: u32) {
+    b += a as u32;
+    assert!(b >= 10);
+}
+
+#[proptest(property_fn = "is_even")]
+fn named_test(#[strategy("(0..100u32).prop_map(|x| x * 2)")] value: u32) -> prop::test_runner::TestCaseResult {
+    prop_assert_eq!(value % 2, 0);
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use proptest_attr::proptest_config;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+static DEFAULT_CASES: AtomicU32 = AtomicU32::new(0);
+static OVERRIDDEN_CASES: AtomicU32 = AtomicU32::new(0);
+static NESTED_CASES: AtomicU32 = AtomicU32::new(0);
+
+fn main() {
+    tests::default_cases();
+    tests::overridden_cases();
+    tests::nested::nested_cases();
+    assert_eq!(DEFAULT_CASES.load(Ordering::SeqCst), 10);
+    assert_eq!(OVERRIDDEN_CASES.load(Ordering::SeqCst), 5);
+    assert_eq!(NESTED_CASES.load(Ordering::SeqCst), 10);
+}
+
+#[proptest_config(cases = 10)]
+mod tests {
+    use super::*;
+    use proptest_attr::proptest;
+
+    #[proptest(strategy = "0..10u8")]
+    pub fn default_cases(_value: u8) {
+        DEFAULT_CASES.fetch_add(1, Ordering::SeqCst);
+    }
+
+    #[proptest(strategy = "0..10u8", cases = 5)]
+    pub fn overridden_cases(_value: u8) {
+        OVERRIDDEN_CASES.fetch_add(1, Ordering::SeqCst);
+    }
+
+    pub mod nested {
+        use super::*;
+
+        #[proptest_attr::proptest(strategy = "0..10u8")]
+        pub fn nested_cases(_value: u8) {
+            NESTED_CASES.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+}
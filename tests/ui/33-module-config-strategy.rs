@@ -0,0 +1,11 @@
+use proptest_attr::proptest_config;
+
+fn main() {}
+
+#[proptest_config(strategy = "0..10u8")]
+mod tests {
+    use proptest_attr::proptest;
+
+    #[proptest]
+    fn basic_test(_value: u8) {}
+}
@@ -0,0 +1,30 @@
+use proptest::prelude::*;
+use proptest_attr::proptest;
+
+fn main() {
+    strategy_test();
+    per_argument_test();
+    regex_test();
+}
+
+#[proptest(
+    strategy = (0..10u8, any::<bool>()),
+    filter = a % 2 == 0,
+    config = ProptestConfig { cases: 10, ..ProptestConfig::default() }
+)]
+fn strategy_test(a: u8, _b: bool) {
+    assert!(a < 10);
+    assert_eq!(a % 2, 0);
+}
+
+#[proptest]
+fn per_argument_test(#[strategy(0..10u8)] a: u8, #[strategy(10..20u8)] #[filter(*b != 15)] b: u8) {
+    assert!(a < b);
+    assert_ne!(b, 15);
+}
+
+// String literals are parsed as expressions, parentheses make a regex strategy
+#[proptest(strategy = ("[a-z]+"))]
+fn regex_test(s: String) {
+    assert!(s.chars().all(|c| c.is_ascii_lowercase()));
+}
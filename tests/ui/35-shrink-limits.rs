@@ -0,0 +1,24 @@
+use proptest_attr::proptest;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+static CALLS: AtomicU32 = AtomicU32::new(0);
+
+fn main() {
+    let result = std::panic::catch_unwind(no_shrinking_test);
+    assert!(result.is_err());
+    // The failing case is not shrunk
+    assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+
+    shrink_time_test();
+}
+
+#[proptest(strategy = 1000..2000u32, max_shrink_iters = 0)]
+fn no_shrinking_test(value: u32) {
+    CALLS.fetch_add(1, Ordering::SeqCst);
+    assert!(value < 1000);
+}
+
+#[proptest(strategy = 0..10u8, max_shrink_time = 100)]
+fn shrink_time_test(value: u8) {
+    assert!(value < 10);
+}
@@ -0,0 +1,11 @@
+use proptest_attr::proptest;
+
+fn main() {
+    let result = std::panic::catch_unwind(failing_test);
+    assert!(result.is_err());
+}
+
+#[proptest(strategy = 0..10u8, persistence = "off")]
+fn failing_test(value: u8) {
+    assert!(value < 5);
+}
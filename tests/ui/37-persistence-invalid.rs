@@ -0,0 +1,6 @@
+use proptest_attr::proptest;
+
+fn main() {}
+
+#[proptest(strategy = 0..10u8, persistence = off)]
+fn basic_test(_value: u8) {}
@@ -0,0 +1,23 @@
+use proptest_attr::proptest;
+
+mod test_utils {
+    pub use proptest as reexported_proptest;
+}
+
+fn main() {
+    basic_test();
+    quoted_path_test();
+}
+
+#[proptest(
+    crate = test_utils::reexported_proptest,
+    seed = "cc 0000000000000000000000000000000000000000000000000000000000000000",
+    persistence = "off",
+    filter = *a != 5
+)]
+fn basic_test(#[strategy(0..10u8)] a: u8, _b: bool) {
+    assert_ne!(a, 5);
+}
+
+#[proptest(crate = "crate::test_utils::reexported_proptest")]
+fn quoted_path_test(_value: u8) {}
@@ -0,0 +1,40 @@
+use proptest::prelude::*;
+use proptest_attr::proptest;
+use std::fmt::Debug;
+
+#[derive(Debug, Clone, PartialEq)]
+struct MyStruct(u8);
+
+impl Arbitrary for MyStruct {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        any::<u8>().prop_map(MyStruct).boxed()
+    }
+}
+
+fn main() {
+    roundtrip::u8();
+    roundtrip::string();
+    roundtrip::mystruct();
+    roundtrip::vec_u8();
+    with_strategy::u16();
+
+    // The generic function stays callable
+    roundtrip(5u8);
+}
+
+#[proptest(types = (u8, String, MyStruct, Vec<u8>), cases = 10)]
+fn roundtrip<T: Clone + Debug + PartialEq + Arbitrary>(value: T) {
+    assert_eq!(value.clone(), value);
+}
+
+#[proptest(types = "u16")]
+fn with_strategy<T>(#[strategy(any::<T>())] value: T) -> Result<(), TestCaseError>
+where
+    T: Arbitrary + Into<u32>,
+{
+    prop_assert!(value.into() <= u16::MAX as u32);
+    Ok(())
+}
@@ -0,0 +1,10 @@
+use proptest::prelude::*;
+use proptest_attr::proptest;
+
+fn main() {}
+
+#[proptest]
+fn roundtrip<T: Arbitrary>(_value: T) {}
+
+#[proptest(types = (u8, u16))]
+fn not_generic(_value: u8) {}
@@ -0,0 +1,29 @@
+use proptest::prelude::*;
+use proptest_attr::proptest;
+
+fn main() {
+    divide_by_zero();
+    any_panic();
+    assert!(std::panic::catch_unwind(not_every_case_panics).is_err());
+    assert!(std::panic::catch_unwind(wrong_message).is_err());
+}
+
+#[proptest(strategy = Just(0u8), expect_panic = "divide by zero")]
+fn divide_by_zero(divisor: u8) {
+    let _ = 100 / divisor;
+}
+
+#[proptest(expect_panic)]
+fn any_panic(value: u8) -> Result<(), TestCaseError> {
+    panic!("{}", value);
+}
+
+#[proptest(strategy = 0..10u8, expect_panic)]
+fn not_every_case_panics(value: u8) {
+    assert!(value < 5);
+}
+
+#[proptest(expect_panic = "divide by zero")]
+fn wrong_message(value: u8) {
+    panic!("{}", value);
+}
@@ -0,0 +1,11 @@
+use proptest_attr::proptest;
+
+fn main() {
+    named_property();
+}
+
+#[proptest(cases = 1)]
+fn named_property(_value: u8) {
+    let backtrace = std::backtrace::Backtrace::force_capture().to_string();
+    assert!(backtrace.contains("named_property::property"), "{}", backtrace);
+}
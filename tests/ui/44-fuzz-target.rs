@@ -0,0 +1,27 @@
+use proptest::prelude::*;
+use proptest_attr::proptest;
+
+fn main() {
+    basic_test_fuzz(&[1, 2, 3, 4, 5]);
+    basic_test_fuzz(&[]);
+    for byte in 0..=255 {
+        filtered_fuzz(&[byte, 7]);
+    }
+    failing_fuzz(&[5]);
+    assert!(std::panic::catch_unwind(|| failing_fuzz(&[200])).is_err());
+}
+
+#[proptest(fuzz_target)]
+fn basic_test(_a: u8, _b: u16) {}
+
+#[proptest(fuzz_target = "filtered_fuzz", filter = a != b)]
+fn filtered(#[filter(*a % 2 == 0)] a: u8, b: u8) {
+    assert_eq!(a % 2, 0);
+    assert_ne!(a, b);
+}
+
+#[proptest(fuzz_target = "failing_fuzz", property_fn)]
+fn failing(value: u8) -> Result<(), TestCaseError> {
+    prop_assert!(value < 100);
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use proptest_attr::{proptest, strategy_fn};
+
+#[derive(Debug, Clone)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[strategy_fn]
+fn point(#[param] max: i32, #[strategy(0..max)] x: i32, #[strategy(0..max)] y: i32) -> Point {
+    Point { x, y }
+}
+
+#[strategy_fn(filter = a != b)]
+fn distinct_pair(#[filter(*a < 100)] a: u8, b: u8) -> (u8, u8) {
+    (a, b)
+}
+
+#[strategy_fn]
+fn constant() -> u8 {
+    42
+}
+
+fn main() {
+    points();
+    pairs();
+    constants();
+}
+
+#[proptest(cases = 10)]
+fn points(#[strategy(point(10))] point: Point) {
+    assert!((0..10).contains(&point.x));
+    assert!((0..10).contains(&point.y));
+}
+
+#[proptest(cases = 10)]
+fn pairs(#[strategy(distinct_pair())] pair: (u8, u8)) {
+    assert!(pair.0 < 100);
+    assert_ne!(pair.0, pair.1);
+}
+
+#[proptest(cases = 10)]
+fn constants(#[strategy(constant())] value: u8) {
+    assert_eq!(value, 42);
+}
@@ -0,0 +1,11 @@
+use proptest_attr::strategy_fn;
+
+fn main() {}
+
+#[strategy_fn]
+fn no_value(_x: u8) {}
+
+#[strategy_fn(cases = 10)]
+fn unsupported_argument(x: u8) -> u8 {
+    x
+}
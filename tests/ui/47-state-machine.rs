@@ -0,0 +1,59 @@
+use proptest::prelude::*;
+use proptest_attr::proptest_state_machine;
+use std::collections::VecDeque;
+
+/// The system under test.
+struct Stack {
+    items: VecDeque<u8>,
+}
+
+impl Stack {
+    fn push(&mut self, value: u8) {
+        self.items.push_front(value);
+    }
+
+    fn pop(&mut self) -> Option<u8> {
+        self.items.pop_front()
+    }
+
+    fn len(&self) -> usize {
+        self.items.len()
+    }
+}
+
+/// The reference model.
+#[derive(Clone, Debug)]
+struct StackModel {
+    items: Vec<u8>,
+}
+
+#[proptest_state_machine(transitions = 1..30, cases = 32)]
+impl StackModel {
+    fn init_state() -> Self {
+        StackModel { items: Vec::new() }
+    }
+
+    fn init_test(&self) -> Stack {
+        Stack {
+            items: VecDeque::new(),
+        }
+    }
+
+    fn check_invariants(&self, sut: &Stack) {
+        assert_eq!(self.items.len(), sut.len());
+    }
+
+    #[transition]
+    fn push(&mut self, #[strategy(any::<u8>())] value: u8) {
+        self.items.push(value);
+    }
+
+    #[transition]
+    fn pop(&mut self) -> Option<u8> {
+        self.items.pop()
+    }
+}
+
+fn main() {
+    stack_model_state_machine();
+}
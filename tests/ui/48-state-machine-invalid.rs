@@ -0,0 +1,42 @@
+use proptest_attr::proptest_state_machine;
+
+fn main() {}
+
+#[derive(Clone, Debug)]
+struct NoTransitions;
+
+#[proptest_state_machine]
+impl NoTransitions {
+    fn init_state() -> Self {
+        NoTransitions
+    }
+
+    fn init_test(&self) -> Vec<u8> {
+        Vec::new()
+    }
+}
+
+#[derive(Clone, Debug)]
+struct ImmutableTransition;
+
+#[proptest_state_machine]
+impl ImmutableTransition {
+    fn init_state() -> Self {
+        ImmutableTransition
+    }
+
+    fn init_test(&self) -> Vec<u8> {
+        Vec::new()
+    }
+
+    #[transition]
+    fn len(&self) -> usize {
+        0
+    }
+}
+
+#[derive(Clone, Debug)]
+struct UnsupportedArgument;
+
+#[proptest_state_machine(strategy = 0..10)]
+impl UnsupportedArgument {}
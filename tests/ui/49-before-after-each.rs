@@ -0,0 +1,42 @@
+use proptest_attr::proptest;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static BEFORE: AtomicUsize = AtomicUsize::new(0);
+static AFTER: AtomicUsize = AtomicUsize::new(0);
+
+fn setup() {
+    assert_eq!(
+        BEFORE.fetch_add(1, Ordering::SeqCst),
+        AFTER.load(Ordering::SeqCst)
+    );
+}
+
+mod hooks {
+    pub fn teardown() {
+        super::AFTER.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+    }
+}
+
+fn main() {
+    counted();
+    assert_eq!(BEFORE.load(Ordering::SeqCst), 10);
+    assert_eq!(AFTER.load(Ordering::SeqCst), 10);
+
+    // The teardown also runs for the failing cases
+    std::panic::catch_unwind(failing).unwrap_err();
+    assert!(BEFORE.load(Ordering::SeqCst) > 10);
+    assert_eq!(BEFORE.load(Ordering::SeqCst), AFTER.load(Ordering::SeqCst));
+}
+
+#[proptest(cases = 10, before_each = setup, after_each = "hooks::teardown")]
+fn counted(_value: u8) {
+    assert_eq!(
+        BEFORE.load(Ordering::SeqCst),
+        AFTER.load(Ordering::SeqCst) + 1
+    );
+}
+
+#[proptest(before_each = setup, after_each = hooks::teardown)]
+fn failing(value: u8) {
+    assert!(value < 10);
+}
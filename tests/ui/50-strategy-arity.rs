@@ -0,0 +1,18 @@
+use proptest_attr::proptest;
+
+fn main() {}
+
+#[proptest(strategy = (0..10u8, 0..10u8, 0..10u8))]
+fn too_many_values(a: u8, b: u8) {
+    assert!(a < 10 && b < 10);
+}
+
+#[proptest(strategy = "(0..10u8, 0..10u8)")]
+fn too_few_values(a: u8, b: u8, c: u8) {
+    assert!(a < 10 && b < 10 && c < 10);
+}
+
+#[proptest(strategy = (0..10u8, 0..10u8))]
+fn single_tuple_argument(pair: (u8, u8)) {
+    assert!(pair.0 < 10 && pair.1 < 10);
+}
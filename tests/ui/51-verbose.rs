@@ -0,0 +1,22 @@
+use proptest_attr::proptest;
+
+fn main() {
+    verbose();
+    logged();
+    logged_tuple();
+}
+
+#[proptest(cases = 5, verbose = 2)]
+fn verbose(value: u8) {
+    let _ = value;
+}
+
+#[proptest(cases = 5, log_inputs)]
+fn logged(value: u8) {
+    let _ = value;
+}
+
+#[proptest(cases = 5, log_inputs = "stderr")]
+fn logged_tuple(a: u8, (b, c): (bool, char)) {
+    let _ = (a, b, c);
+}
@@ -0,0 +1,44 @@
+use proptest::prelude::*;
+use proptest_attr::proptest;
+
+#[derive(Debug)]
+enum DomainError {
+    TooLarge(u8),
+}
+
+fn check(value: u8) -> Result<u8, DomainError> {
+    if value < 200 {
+        Ok(value)
+    } else {
+        Err(DomainError::TooLarge(value))
+    }
+}
+
+fn main() {
+    parsed();
+    rejected();
+
+    let err = std::panic::catch_unwind(failing).unwrap_err();
+    let message = err.downcast_ref::<String>().unwrap();
+    assert!(message.contains("TooLarge(200)"), "{}", message);
+}
+
+#[proptest(cases = 10)]
+fn parsed(value: u16) -> Result<(), std::num::ParseIntError> {
+    let parsed: u16 = value.to_string().parse()?;
+    assert_eq!(parsed, value);
+    Ok(())
+}
+
+#[proptest(cases = 10)]
+fn rejected(value: u8) -> Result<(), TestCaseError> {
+    prop_assume!(value < 100);
+    prop_assert!(value < 100);
+    Ok(())
+}
+
+#[proptest(strategy = 0..=255u8)]
+fn failing(value: u8) -> Result<(), DomainError> {
+    check(value)?;
+    Ok(())
+}
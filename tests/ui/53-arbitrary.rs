@@ -0,0 +1,53 @@
+use proptest_attr::{proptest, proptest_arbitrary};
+
+#[proptest_arbitrary(filter = x != y)]
+#[derive(Debug, Clone)]
+struct Point {
+    #[strategy(0..10)]
+    x: i32,
+    /// Documented field
+    #[strategy(0..10)]
+    y: i32,
+}
+
+#[proptest_arbitrary]
+#[derive(Debug)]
+struct Wrapper(#[strategy(("[a-z]+"))] String, #[filter(*field1 < 100)] u8);
+
+#[proptest_arbitrary]
+#[derive(Debug)]
+enum Shape {
+    Empty,
+    Circle {
+        #[strategy(1..10u32)]
+        radius: u32,
+    },
+    Square(#[strategy(1..10u32)] u32),
+}
+
+fn main() {
+    points();
+    wrappers();
+    shapes();
+}
+
+#[proptest(cases = 10)]
+fn points(point: Point) {
+    assert!((0..10).contains(&point.x));
+    assert!((0..10).contains(&point.y));
+    assert_ne!(point.x, point.y);
+}
+
+#[proptest(cases = 10)]
+fn wrappers(wrapper: Wrapper) {
+    assert!(wrapper.0.chars().all(|c| c.is_ascii_lowercase()));
+    assert!(wrapper.1 < 100);
+}
+
+#[proptest(cases = 10)]
+fn shapes(shape: Shape) {
+    match shape {
+        Shape::Empty => {}
+        Shape::Circle { radius } | Shape::Square(radius) => assert!((1..10).contains(&radius)),
+    }
+}
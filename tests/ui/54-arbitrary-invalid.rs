@@ -0,0 +1,20 @@
+use proptest_attr::proptest_arbitrary;
+
+fn main() {}
+
+#[proptest_arbitrary]
+#[derive(Debug)]
+struct Generic<T>(T);
+
+#[proptest_arbitrary(filter = true)]
+#[derive(Debug)]
+enum Filtered {
+    A,
+}
+
+#[proptest_arbitrary(cases = 10)]
+#[derive(Debug)]
+struct UnsupportedArgument;
+
+#[proptest_arbitrary]
+fn not_a_type() {}
@@ -0,0 +1,14 @@
+use proptest::prelude::*;
+use proptest_attr::proptest;
+
+fn main() {}
+
+#[proptest(strategy = prop::collection::vec(any::<u8>(), 0..100))]
+fn whole_strategy(values: Vec<u16>) {
+    assert!(values.len() < 100);
+}
+
+#[proptest]
+fn per_argument_strategy(#[strategy(0..10u8)] a: u8, #[strategy(0..10i64)] b: i32) {
+    assert!(a < 10 && b < 10);
+}
@@ -0,0 +1,27 @@
+use proptest_attr::proptest;
+use std::sync::Mutex;
+
+static VALUES: Mutex<Vec<u64>> = Mutex::new(Vec::new());
+
+fn main() {
+    replayed();
+    replayed();
+    replayed_xorshift();
+
+    // The persisted case is run once and generates the same value every time
+    let values = VALUES.lock().unwrap();
+    assert_eq!(values.len(), 3);
+    assert_eq!(values[0], values[1]);
+}
+
+#[proptest(
+    replay = "cc 0f1e2d3c4b5a69788796a5b4c3d2e1f00f1e2d3c4b5a69788796a5b4c3d2e1f0 # shrinks to value = 0"
+)]
+fn replayed(value: u64) {
+    VALUES.lock().unwrap().push(value);
+}
+
+#[proptest(replay = "xs 1 2 3 4")]
+fn replayed_xorshift(value: u64) {
+    VALUES.lock().unwrap().push(value);
+}
@@ -0,0 +1,18 @@
+use proptest_attr::proptest;
+
+fn main() {}
+
+#[proptest(replay = "0123")]
+fn invalid_seed(_value: u8) {}
+
+#[proptest(replay = "xs 1 2 3 4", persistence = "off")]
+fn with_persistence(_value: u8) {}
+
+#[proptest(replay = "pa 0g")]
+fn invalid_passthrough_bytes(_value: u8) {}
+
+#[proptest(replay = "rr 0123")]
+fn short_recorder_seed(_value: u8) {}
+
+#[proptest(replay = "pa01")]
+fn missing_space(_value: u8) {}
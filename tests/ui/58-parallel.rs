@@ -0,0 +1,34 @@
+use proptest_attr::proptest;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static PARALLEL_CASES: AtomicUsize = AtomicUsize::new(0);
+static THREADS_CASES: AtomicUsize = AtomicUsize::new(0);
+
+fn main() {
+    parallel();
+    assert_eq!(PARALLEL_CASES.load(Ordering::SeqCst), 50);
+    threads();
+    assert_eq!(THREADS_CASES.load(Ordering::SeqCst), 7);
+    seeded();
+}
+
+#[proptest(cases = 50, parallel)]
+fn parallel(value: u8) {
+    let _ = value;
+    PARALLEL_CASES.fetch_add(1, Ordering::SeqCst);
+}
+
+#[proptest(cases = 7, parallel = true, threads = 3)]
+fn threads(a: u8, b: bool) {
+    let _ = (a, b);
+    THREADS_CASES.fetch_add(1, Ordering::SeqCst);
+}
+
+#[proptest(cases = 10, parallel, threads = 2, seed = "cc 0000000000000000000000000000000000000000000000000000000000000001")]
+fn seeded(value: u32) -> Result<(), String> {
+    if value == u32::MAX {
+        Err("unexpected value".to_string())
+    } else {
+        Ok(())
+    }
+}
@@ -0,0 +1,18 @@
+use proptest_attr::proptest;
+
+fn main() {}
+
+#[proptest(threads = 2)]
+fn threads_without_parallel(value: u8) {
+    let _ = value;
+}
+
+#[proptest(parallel, fork)]
+fn parallel_fork(value: u8) {
+    let _ = value;
+}
+
+#[proptest(parallel, threads = 0)]
+fn no_threads(value: u8) {
+    let _ = value;
+}
@@ -0,0 +1,20 @@
+use proptest::prelude::*;
+use proptest_attr::proptest;
+
+fn main() {
+    global_rejects();
+    local_rejects();
+}
+
+// Rejects about 7/8 of the cases, which exceeds the default limit of 1024 global rejects
+#[proptest(cases = 512, max_global_rejects = 100_000)]
+fn global_rejects(value: u8) -> Result<(), TestCaseError> {
+    prop_assume!(value % 8 == 0);
+    Ok(())
+}
+
+// Rejects about 8191/8192 of the values, which exceeds the default limit of 65536 local rejects
+#[proptest(cases = 16, max_local_rejects = 1_000_000)]
+fn local_rejects(#[filter("value % 8192 == 0")] value: u16) {
+    assert_eq!(value % 8192, 0);
+}
@@ -0,0 +1,26 @@
+use proptest::prelude::*;
+use proptest_attr::proptest;
+
+fn main() {
+    classify();
+    classify_tuple();
+    classify_parallel();
+}
+
+#[proptest(cases = 20, classify = if *value < 128 { "small" } else { "large" })]
+fn classify(value: u8) {
+    let _ = value;
+}
+
+#[proptest(cases = 20, classify = "format!(\"{} {}\", mut_value % 2, b)")]
+fn classify_tuple(mut mut_value: u8, (b, c): (bool, char)) -> Result<(), TestCaseError> {
+    mut_value = mut_value.wrapping_add(1);
+    prop_assume!(mut_value > 1 || !b);
+    let _ = c;
+    Ok(())
+}
+
+#[proptest(cases = 20, parallel, threads = 2, classify = value.len())]
+fn classify_parallel(value: Vec<u8>) {
+    let _ = value;
+}
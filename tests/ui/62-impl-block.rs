@@ -0,0 +1,49 @@
+use proptest::prelude::*;
+use proptest_attr::proptest_config;
+
+struct Counter;
+
+#[proptest_config(cases = 10)]
+impl Counter {
+    fn increment(value: u32) -> u32 {
+        value + 1
+    }
+
+    /// Properties stay callable as associated functions
+    #[proptest]
+    pub fn increment_test(#[strategy(0..1000u32)] value: u32) {
+        assert_eq!(Self::increment(value), value + 1);
+    }
+
+    #[proptest(strategy = (0..10u8, 0..10u8), cases = 5)]
+    fn result_test(a: u8, b: u8) -> Result<(), TestCaseError> {
+        prop_assert!(a + b < 20);
+        Ok(())
+    }
+}
+
+#[proptest_config(cases = 3)]
+mod nested {
+    use proptest_attr::proptest_config;
+
+    pub struct Nested;
+
+    pub fn run() {
+        nested_proptest::nested_test();
+    }
+
+    #[proptest_config]
+    impl Nested {
+        #[proptest]
+        fn nested_test(value: bool) {
+            let _ = value;
+        }
+    }
+}
+
+fn main() {
+    Counter::increment_test(5);
+    counter_proptest::increment_test();
+    counter_proptest::result_test();
+    nested::run();
+}
@@ -0,0 +1,46 @@
+use proptest_attr::proptest_config;
+
+fn main() {}
+
+struct Receiver;
+
+#[proptest_config]
+impl Receiver {
+    #[proptest]
+    fn receiver_test(&self, value: u8) {
+        let _ = value;
+    }
+}
+
+struct Generic<T>(T);
+
+#[proptest_config]
+impl<T> Generic<T> {
+    #[proptest]
+    fn generic_test(value: u8) {
+        let _ = value;
+    }
+}
+
+struct PropertyFn;
+
+#[proptest_config]
+impl PropertyFn {
+    #[proptest(property_fn)]
+    fn property_fn_test(value: u8) {
+        let _ = value;
+    }
+}
+
+struct Afl;
+
+#[proptest_config]
+impl Afl {
+    #[proptest(afl)]
+    fn afl_test(value: u32) {
+        let _ = value;
+    }
+}
+
+#[proptest_config]
+fn not_a_module() {}
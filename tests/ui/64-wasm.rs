@@ -0,0 +1,18 @@
+use proptest::prelude::*;
+use proptest_attr::proptest;
+
+fn main() {
+    wasm_test();
+    wasm_result_test();
+}
+
+#[proptest(cases = 10, target = "wasm")]
+fn wasm_test(value: u8) {
+    let _ = value;
+}
+
+#[proptest(strategy = 0..10u8, target = "wasm")]
+fn wasm_result_test(value: u8) -> Result<(), TestCaseError> {
+    prop_assert!(value < 10);
+    Ok(())
+}
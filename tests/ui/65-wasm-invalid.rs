@@ -0,0 +1,18 @@
+use proptest_attr::proptest;
+
+fn main() {}
+
+#[proptest(target = "wasm", fork)]
+fn fork_test(value: u8) {
+    let _ = value;
+}
+
+#[proptest(target = "wasm", persistence = "file")]
+fn persistence_test(value: u8) {
+    let _ = value;
+}
+
+#[proptest(target = "wasi")]
+fn unknown_target_test(value: u8) {
+    let _ = value;
+}
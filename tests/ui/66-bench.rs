@@ -0,0 +1,31 @@
+use criterion::Criterion;
+use proptest::prelude::*;
+use proptest_attr::proptest;
+use std::time::Duration;
+
+fn main() {
+    let mut criterion = Criterion::default()
+        .sample_size(10)
+        .warm_up_time(Duration::from_millis(1))
+        .measurement_time(Duration::from_millis(10));
+    benchmarked();
+    benchmarked_bench(&mut criterion);
+    tuple_bench(&mut criterion);
+    named(&mut criterion);
+}
+
+#[proptest(cases = 10, bench)]
+fn benchmarked(#[strategy(0..1000u32)] value: u32) {
+    assert!(value < 1000);
+}
+
+#[proptest(cases = 10, bench = true, property_fn)]
+fn tuple(a: u8, #[filter("*b > 0")] b: u8) -> Result<(), TestCaseError> {
+    prop_assert!(u16::from(a) / u16::from(b) <= u16::from(a));
+    Ok(())
+}
+
+#[proptest(cases = 10, bench = "named")]
+fn named_test(value: Vec<u8>) {
+    let _ = value.len();
+}
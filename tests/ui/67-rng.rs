@@ -0,0 +1,30 @@
+use proptest_attr::proptest;
+use std::sync::Mutex;
+
+static VALUES: Mutex<Vec<u32>> = Mutex::new(Vec::new());
+
+fn main() {
+    xorshift();
+    recorder();
+    pass_through();
+    pass_through();
+    let values = VALUES.lock().unwrap();
+    assert_eq!(values.len(), 2);
+    assert_eq!(values[0], values[1]);
+}
+
+#[proptest(cases = 10, rng = "XorShift")]
+fn xorshift(value: u8) {
+    let _ = value;
+}
+
+#[proptest(cases = 10, rng = "Recorder")]
+fn recorder(value: u8) {
+    let _ = value;
+}
+
+// The same value is produced from the given bytes every time
+#[proptest(cases = 1, rng = "PassThrough", rng_bytes = [1u8, 2, 3, 4], persistence = "off")]
+fn pass_through(#[strategy(proptest::num::u32::ANY)] value: u32) {
+    VALUES.lock().unwrap().push(value);
+}
@@ -0,0 +1,23 @@
+use proptest_attr::proptest;
+
+fn main() {}
+
+#[proptest(rng = "PassThrough")]
+fn no_bytes(value: u8) {
+    let _ = value;
+}
+
+#[proptest(rng_bytes = [0u8; 4])]
+fn no_rng(value: u8) {
+    let _ = value;
+}
+
+#[proptest(rng = "Pcg")]
+fn unknown_rng(value: u8) {
+    let _ = value;
+}
+
+#[proptest(rng = "ChaCha", seed = "cc 0000000000000000000000000000000000000000000000000000000000000000")]
+fn with_seed(value: u8) {
+    let _ = value;
+}
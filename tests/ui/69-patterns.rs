@@ -0,0 +1,58 @@
+use proptest::prelude::*;
+use proptest_attr::proptest;
+
+#[derive(Debug, Clone)]
+struct Point {
+    x: u8,
+    y: u8,
+}
+
+#[derive(Debug, Clone)]
+struct Wrapper(u8);
+
+fn point() -> impl Strategy<Value = Point> {
+    (any::<u8>(), any::<u8>()).prop_map(|(x, y)| Point { x, y })
+}
+
+fn main() {
+    struct_pattern();
+    nested_patterns();
+    whole_strategy();
+    logged();
+}
+
+#[proptest(filter = x != w)]
+fn struct_pattern(
+    #[strategy(point())]
+    #[filter(x < y)]
+    Point { mut x, y }: Point,
+    #[strategy(any::<u8>().prop_map(Wrapper))] Wrapper(w): Wrapper,
+) {
+    x += 1;
+    assert!(x <= y);
+    let _ = w;
+}
+
+#[proptest(classify = *first == *last)]
+fn nested_patterns(
+    #[filter(*b > 0)] ((mut a, _), ref b): ((u8, u8), u8),
+    [first, .., mut last]: [u8; 4],
+    #[strategy(point())] Point { x: ref mut inner, y }: Point,
+) {
+    a = a.wrapping_div(*b);
+    last = last.wrapping_add(first);
+    *inner = inner.wrapping_add(1);
+    let _ = (a, last, y);
+}
+
+#[proptest(strategy = (point(), (0..10u8, 0..10u8)), filter = x != y)]
+fn whole_strategy(Point { x, y }: Point, (mut a, b): (u8, u8)) {
+    a += b;
+    assert!(a < 20);
+    let _ = (x, y);
+}
+
+#[proptest(cases = 5, log_inputs)]
+fn logged(#[strategy(point())] Point { x, y }: Point, [a, b]: [bool; 2]) {
+    let _ = (x, y, a, b);
+}
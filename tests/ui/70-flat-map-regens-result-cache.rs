@@ -0,0 +1,34 @@
+use proptest::prelude::*;
+use proptest_attr::proptest;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+static RUNS: AtomicU32 = AtomicU32::new(0);
+
+fn main() {
+    flat_map();
+    cached();
+    // Only the cases with new values count, the cached ones are not run again
+    assert_eq!(RUNS.load(Ordering::SeqCst), 4);
+    not_cached();
+}
+
+fn vec_and_index() -> impl Strategy<Value = (Vec<u8>, usize)> {
+    prop::collection::vec(any::<u8>(), 1..10)
+        .prop_flat_map(|vec| (0..vec.len()).prop_map(move |index| (vec.clone(), index)))
+}
+
+#[proptest(cases = 20, max_flat_map_regens = 1000)]
+fn flat_map(#[strategy(vec_and_index())] (vec, index): (Vec<u8>, usize)) {
+    assert!(index < vec.len());
+}
+
+#[proptest(strategy = 0..4u8, cases = 4, result_cache)]
+fn cached(value: u8) {
+    let _ = value;
+    RUNS.fetch_add(1, Ordering::SeqCst);
+}
+
+#[proptest(cases = 5, result_cache = false)]
+fn not_cached(value: u8) {
+    let _ = value;
+}
@@ -0,0 +1,24 @@
+use proptest_attr::proptest;
+
+fn main() {}
+
+#[proptest]
+fn returns_bool(value: u8) -> bool {
+    value < 10
+}
+
+#[proptest]
+fn returns_tuple(value: u8) -> (u8, u8) {
+    (value, value)
+}
+
+#[proptest]
+fn returns_option(value: u8) -> Option<()> {
+    let _ = value;
+    Some(())
+}
+
+#[proptest]
+fn returns_value(value: u8) -> Result<u8, String> {
+    Ok(value)
+}
@@ -0,0 +1,41 @@
+use proptest_attr::proptest;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+static RUNS: AtomicU32 = AtomicU32::new(0);
+
+#[proptest(ignore)]
+fn ignored(value: u8) {
+    let _ = value;
+}
+
+#[proptest(ignore = "too slow")]
+fn ignored_with_reason(value: u8) {
+    let _ = value;
+}
+
+#[proptest(ignore_if = cfg(not(trybuild)))]
+fn ignored_by_cfg(value: u8) {
+    let _ = value;
+}
+
+#[proptest(ignore = "always skipped", ignore_if = "1 + 1 == 2")]
+fn skipped(value: u8) {
+    let _ = value;
+    RUNS.fetch_add(1, Ordering::SeqCst);
+}
+
+#[proptest(ignore_if = RUNS.load(Ordering::SeqCst) > 0, cases = 10)]
+fn not_skipped(value: u8) {
+    let _ = value;
+    RUNS.fetch_add(1, Ordering::SeqCst);
+}
+
+fn main() {
+    ignored();
+    ignored_with_reason();
+    ignored_by_cfg();
+    skipped();
+    assert_eq!(RUNS.load(Ordering::SeqCst), 0);
+    not_skipped();
+    assert_eq!(RUNS.load(Ordering::SeqCst), 10);
+}
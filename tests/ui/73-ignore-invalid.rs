@@ -0,0 +1,23 @@
+use proptest_attr::proptest;
+
+fn main() {}
+
+#[proptest(ignore = 1)]
+fn invalid_reason(value: u8) {
+    let _ = value;
+}
+
+#[proptest(ignore_if = cfg(unix, windows))]
+fn invalid_cfg(value: u8) {
+    let _ = value;
+}
+
+#[proptest(ignore, no_test_attr)]
+fn without_test_attr(value: u8) {
+    let _ = value;
+}
+
+#[proptest(ignore_if = 1)]
+fn not_bool(value: u8) {
+    let _ = value;
+}
@@ -0,0 +1,41 @@
+use proptest_attr::proptest;
+use std::time::Duration;
+
+fn main() {
+    current_thread();
+    multi_thread();
+    paused_clock();
+    paused_clock_per_case();
+}
+
+#[proptest(async = "tokio", cases = 10)]
+async fn current_thread(value: u8) {
+    let metrics = tokio::runtime::Handle::current().metrics();
+    assert_eq!(metrics.num_workers(), 1);
+    tokio::task::yield_now().await;
+    let _ = value;
+}
+
+#[proptest(async = "tokio", worker_threads = 2, cases = 10)]
+async fn multi_thread(value: u8) {
+    let metrics = tokio::runtime::Handle::current().metrics();
+    assert_eq!(metrics.num_workers(), 2);
+    let doubled = tokio::spawn(async move { u16::from(value) * 2 })
+        .await
+        .unwrap();
+    assert_eq!(doubled, u16::from(value) * 2);
+}
+
+#[proptest(async = "tokio", flavor = "current_thread", start_paused, cases = 10)]
+async fn paused_clock(#[strategy(0..3600u64)] secs: u64) {
+    let start = std::time::Instant::now();
+    tokio::time::sleep(Duration::from_secs(secs)).await;
+    assert!(start.elapsed() < Duration::from_secs(60));
+}
+
+#[proptest(async = "tokio", start_paused = true, runtime_per_case, cases = 10)]
+async fn paused_clock_per_case(#[strategy(0..3600u64)] secs: u64) {
+    let start = tokio::time::Instant::now();
+    tokio::time::sleep(Duration::from_secs(secs)).await;
+    assert_eq!(start.elapsed().as_secs(), secs);
+}
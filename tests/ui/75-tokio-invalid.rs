@@ -0,0 +1,33 @@
+use proptest_attr::proptest;
+
+fn main() {}
+
+#[proptest(worker_threads = 2)]
+fn without_tokio(value: u8) {
+    let _ = value;
+}
+
+#[proptest(async = "block_on", start_paused)]
+async fn other_runtime(value: u8) {
+    let _ = value;
+}
+
+#[proptest(async = "tokio", flavor = "multi_thread", start_paused)]
+async fn paused_multi_thread(value: u8) {
+    let _ = value;
+}
+
+#[proptest(async = "tokio", flavor = "current_thread", worker_threads = 2)]
+async fn current_thread_workers(value: u8) {
+    let _ = value;
+}
+
+#[proptest(async = "tokio", worker_threads = 0)]
+async fn no_workers(value: u8) {
+    let _ = value;
+}
+
+#[proptest(async = "tokio", flavor = "local")]
+async fn invalid_flavor(value: u8) {
+    let _ = value;
+}
@@ -0,0 +1,40 @@
+use proptest_attr::{proptest, proptest_strategies};
+
+mod strategies {
+    use proptest::prelude::*;
+    use proptest_attr::proptest_strategies;
+
+    proptest_strategies! {
+        pub even: u32 = (0..100u32).prop_map(|value| value * 2);
+        pub(crate) pair: (u8, bool) = (0..10u8, any::<bool>());
+    }
+}
+
+proptest_strategies! {
+    #[allow(dead_code)]
+    unused: u8 = 0..1u8;
+    small: u8 = 0..10u8;
+}
+
+#[proptest(strategy = "@small")]
+fn whole_strategy(value: u8) {
+    assert!(value < 10);
+}
+
+#[proptest(strategy = " @strategies::pair ")]
+fn tuple_strategy(value: u8, flag: bool) {
+    assert!(value < 10);
+    let _ = flag;
+}
+
+#[proptest]
+fn per_argument(#[strategy("@strategies::even")] even: u32, #[strategy("@small")] small: u8) {
+    assert_eq!(even % 2, 0);
+    assert!(small < 10);
+}
+
+fn main() {
+    whole_strategy();
+    tuple_strategy();
+    per_argument();
+}
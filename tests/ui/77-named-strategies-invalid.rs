@@ -0,0 +1,13 @@
+use proptest_attr::{proptest, proptest_strategies};
+
+proptest_strategies! {
+    small: u8 = 0..10u8;
+    missing_type = 0..10u8;
+}
+
+#[proptest(strategy = "@")]
+fn empty_name(value: u8) {
+    let _ = value;
+}
+
+fn main() {}
@@ -0,0 +1,44 @@
+use proptest_attr::proptest;
+use std::panic;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+static CALLS: AtomicU64 = AtomicU64::new(0);
+static FIRST: AtomicU64 = AtomicU64::new(0);
+static LAST: AtomicU64 = AtomicU64::new(0);
+
+#[proptest(no_shrink, persistence = "off")]
+fn whole_test(#[strategy(1..u64::MAX)] value: u64) {
+    CALLS.fetch_add(1, Ordering::SeqCst);
+    panic!("failing {}", value);
+}
+
+#[proptest(strategy = 1..u64::MAX, no_shrink = true, persistence = "off")]
+fn whole_strategy(value: u64) {
+    CALLS.fetch_add(1, Ordering::SeqCst);
+    panic!("failing {}", value);
+}
+
+#[proptest(persistence = "off")]
+fn per_argument(
+    #[no_shrink]
+    #[strategy(1..u64::MAX)]
+    kept: u64,
+    #[strategy(1..u64::MAX)] shrunk: u64,
+) {
+    if CALLS.fetch_add(1, Ordering::SeqCst) == 0 {
+        FIRST.store(kept, Ordering::SeqCst);
+    }
+    assert_eq!(kept, FIRST.load(Ordering::SeqCst));
+    LAST.store(shrunk, Ordering::SeqCst);
+    panic!("failing {} {}", kept, shrunk);
+}
+
+fn main() {
+    assert!(panic::catch_unwind(whole_test).is_err());
+    assert_eq!(CALLS.swap(0, Ordering::SeqCst), 1);
+    assert!(panic::catch_unwind(whole_strategy).is_err());
+    assert_eq!(CALLS.swap(0, Ordering::SeqCst), 1);
+    assert!(panic::catch_unwind(per_argument).is_err());
+    assert!(CALLS.load(Ordering::SeqCst) > 1);
+    assert_eq!(LAST.load(Ordering::SeqCst), 1);
+}
@@ -0,0 +1,13 @@
+use proptest_attr::proptest;
+
+fn main() {}
+
+#[proptest(strategy = 0..10u8)]
+fn with_strategy(#[no_shrink] value: u8) {
+    let _ = value;
+}
+
+#[proptest]
+fn with_arguments(#[no_shrink(true)] value: u8) {
+    let _ = value;
+}
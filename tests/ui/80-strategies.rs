@@ -0,0 +1,48 @@
+use proptest::prelude::*;
+use proptest_attr::{proptest, proptest_config};
+use std::sync::atomic::{AtomicU32, Ordering};
+
+static RUNS: AtomicU32 = AtomicU32::new(0);
+
+#[proptest(strategies = [0..10u8, "10..20u8", large = 200..=255u8], cases = 10)]
+fn matrix(value: u8) {
+    assert!(value < 20 || value >= 200);
+    RUNS.fetch_add(1, Ordering::SeqCst);
+}
+
+#[proptest(
+    strategies = [small = (0..10u8, "[a-z]"), "(100..200u8, \"[A-Z]\")", "@fixed"],
+    cases = 5,
+)]
+fn tuples(value: u8, letter: String) -> Result<(), TestCaseError> {
+    prop_assert!(value < 10 || value > 99 || value == 42);
+    prop_assert_eq!(letter.len(), 1);
+    Ok(())
+}
+
+fn fixed() -> impl Strategy<Value = (u8, String)> {
+    (Just(42u8), Just("x".to_string()))
+}
+
+struct Parser;
+
+#[proptest_config(cases = 5)]
+impl Parser {
+    #[proptest(strategies = [digits = ("[0-9]{1,5}"), padded = (" [0-9]{1,5} ")])]
+    fn parses(input: String) {
+        assert!(input.trim().parse::<u32>().is_ok());
+    }
+}
+
+fn main() {
+    matrix_0();
+    matrix_1();
+    matrix_large();
+    assert_eq!(RUNS.load(Ordering::SeqCst), 30);
+    matrix(5);
+    tuples_small();
+    tuples_1();
+    tuples_2();
+    parser_proptest::parses_digits();
+    parser_proptest::parses_padded();
+}
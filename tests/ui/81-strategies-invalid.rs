@@ -0,0 +1,38 @@
+use proptest_attr::proptest;
+
+fn main() {}
+
+#[proptest(strategies = 0..10u8)]
+fn not_a_list(value: u8) {
+    let _ = value;
+}
+
+#[proptest(strategies = [])]
+fn empty(value: u8) {
+    let _ = value;
+}
+
+#[proptest(strategies = [a = 0..10u8, a = 10..20u8])]
+fn duplicate(value: u8) {
+    let _ = value;
+}
+
+#[proptest(strategies = [0..10u8], strategy = 0..10u8)]
+fn with_strategy(value: u8) {
+    let _ = value;
+}
+
+#[proptest(strategies = [0..10u8])]
+fn per_argument(#[strategy(0..10u8)] value: u8) {
+    let _ = value;
+}
+
+#[proptest(strategies = [0..10u8], property_fn)]
+fn with_property_fn(value: u8) {
+    let _ = value;
+}
+
+#[proptest(strategies = [a::b = 0..10u8])]
+fn path_name(value: u8) {
+    let _ = value;
+}
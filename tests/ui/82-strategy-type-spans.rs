@@ -0,0 +1,22 @@
+use proptest_attr::{proptest, proptest_strategies};
+
+fn main() {}
+
+proptest_strategies! {
+    small: u8 = 0..10u8;
+}
+
+#[proptest]
+fn quoted_per_argument(#[strategy("0..10i64")] value: i32) {
+    let _ = value;
+}
+
+#[proptest(strategy = "@small")]
+fn named(value: u16) {
+    let _ = value;
+}
+
+#[proptest(strategies = [0..10u8, "0..10u16"])]
+fn matrix(value: u8) {
+    let _ = value;
+}
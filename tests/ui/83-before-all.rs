@@ -0,0 +1,58 @@
+use proptest_attr::proptest;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+static INITS: AtomicU32 = AtomicU32::new(0);
+static CASES: AtomicU32 = AtomicU32::new(0);
+
+fn init() {
+    assert_eq!(CASES.load(Ordering::SeqCst), 0);
+    INITS.fetch_add(1, Ordering::SeqCst);
+}
+
+fn count_case() {
+    assert_eq!(INITS.load(Ordering::SeqCst), 1);
+    CASES.fetch_add(1, Ordering::SeqCst);
+}
+
+#[proptest(before_all = init, before_each = count_case, cases = 20)]
+fn with_before_all(value: u8) {
+    let _ = value;
+}
+
+#[proptest(before_all = "init_parallel", parallel, threads = 4, cases = 20)]
+fn with_parallel(value: u8) {
+    let _ = value;
+}
+
+static PARALLEL_INITS: AtomicU32 = AtomicU32::new(0);
+
+fn init_parallel() {
+    PARALLEL_INITS.fetch_add(1, Ordering::SeqCst);
+}
+
+static FORK_INITS: AtomicU32 = AtomicU32::new(0);
+
+fn init_fork() {
+    // The subprocesses running the cases skip the function
+    assert!(std::env::var_os("_PROPTEST_FORKFILE").is_none());
+    FORK_INITS.fetch_add(1, Ordering::SeqCst);
+}
+
+#[proptest(before_all = init_fork, fork, cases = 20)]
+fn with_fork(value: u8) {
+    assert_eq!(FORK_INITS.load(Ordering::SeqCst), 0);
+    let _ = value;
+}
+
+fn main() {
+    // The subprocess running the cases calls `main` again, so it must reach the forked test first
+    with_fork();
+    with_fork();
+    assert_eq!(FORK_INITS.load(Ordering::SeqCst), 1);
+    with_before_all();
+    with_before_all();
+    assert_eq!(INITS.load(Ordering::SeqCst), 1);
+    assert_eq!(CASES.load(Ordering::SeqCst), 40);
+    with_parallel();
+    assert_eq!(PARALLEL_INITS.load(Ordering::SeqCst), 1);
+}
@@ -0,0 +1,33 @@
+use proptest::test_runner::Config;
+use proptest_attr::proptest;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+static RUNS: AtomicU32 = AtomicU32::new(0);
+
+#[proptest(cases = 5)]
+fn without_overrides(value: u8) {
+    let _ = value;
+    RUNS.fetch_add(1, Ordering::SeqCst);
+}
+
+#[proptest(cases = 5, env_overrides)]
+fn with_overrides(value: u8) {
+    let _ = value;
+    RUNS.fetch_add(1, Ordering::SeqCst);
+}
+
+#[proptest(config = Config::with_cases(5), env_overrides = true)]
+fn with_config(value: u8) {
+    let _ = value;
+    RUNS.fetch_add(1, Ordering::SeqCst);
+}
+
+fn main() {
+    std::env::set_var("PROPTEST_CASES", "12");
+    without_overrides();
+    assert_eq!(RUNS.swap(0, Ordering::SeqCst), 5);
+    with_overrides();
+    assert_eq!(RUNS.swap(0, Ordering::SeqCst), 12);
+    with_config();
+    assert_eq!(RUNS.swap(0, Ordering::SeqCst), 12);
+}
@@ -0,0 +1,38 @@
+use proptest::prelude::*;
+use proptest::test_runner::TestCaseError;
+use proptest_attr::proptest;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+static RUNS: AtomicU32 = AtomicU32::new(0);
+
+#[proptest(engine = "bolero", cases = 20)]
+fn addition_commutes(a: u32, b: u32) {
+    RUNS.fetch_add(1, Ordering::SeqCst);
+    assert_eq!(a.wrapping_add(b), b.wrapping_add(a));
+}
+
+#[proptest(engine = "bolero", cases = 20, strategy = (0..10u8, "[a-z]{1,4}"))]
+fn with_strategy(value: u8, name: String) -> Result<(), TestCaseError> {
+    prop_assert!(value < 10);
+    prop_assert!(!name.is_empty());
+    Ok(())
+}
+
+#[proptest(engine = "bolero", cases = 20, async = "tokio")]
+async fn async_property(#[filter(value % 2 == 0)] value: u8) {
+    assert_eq!(value % 2, 0);
+}
+
+#[proptest(engine = "bolero", cases = 20)]
+fn failing(value: u8) -> Result<(), TestCaseError> {
+    prop_assert!(value < 100);
+    Ok(())
+}
+
+fn main() {
+    addition_commutes();
+    assert!(RUNS.load(Ordering::SeqCst) > 0);
+    with_strategy();
+    async_property();
+    assert!(std::panic::catch_unwind(failing).is_err());
+}
@@ -0,0 +1,23 @@
+use proptest_attr::proptest;
+
+#[proptest(engine = "libfuzzer")]
+fn unknown_engine(value: u8) {
+    let _ = value;
+}
+
+#[proptest(engine = "bolero", fork)]
+fn with_fork(value: u8) {
+    let _ = value;
+}
+
+#[proptest(engine = "bolero", timeout = 100)]
+fn with_timeout(value: u8) {
+    let _ = value;
+}
+
+#[proptest(engine = "bolero", parallel)]
+fn with_parallel(value: u8) {
+    let _ = value;
+}
+
+fn main() {}
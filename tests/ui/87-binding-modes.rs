@@ -0,0 +1,55 @@
+use proptest::prelude::*;
+use proptest_attr::{proptest, strategy_fn};
+
+#[proptest]
+fn mut_with_strategy(#[strategy(prop::collection::vec(any::<u8>(), 0..8))] mut buf: Vec<u8>) {
+    buf.push(0);
+    assert!(!buf.is_empty());
+}
+
+#[proptest]
+fn ref_with_filter(#[filter(*value < 100)] ref value: u8, ref name: String) {
+    let value: &u8 = value;
+    let name: &String = name;
+    assert!(*value < 100);
+    let _ = name;
+}
+
+#[proptest]
+fn attributed_arguments(
+    #[allow(unused_mut)]
+    #[strategy(0..10u8)]
+    mut a: u8,
+    #[allow(unused_variables)] b: u8,
+    #[allow(unused_mut)] mut c: u32,
+) {
+    assert!(a < 10);
+    let _ = c;
+}
+
+#[proptest(strategy = (0..10u8, 0..10u8))]
+fn attributed_with_strategy(#[allow(unused_mut)] mut a: u8, #[filter(*b > 0)] ref b: u8) {
+    assert!(a < 10 && *b > 0);
+}
+
+#[strategy_fn]
+fn small_even(
+    #[allow(unused_mut)]
+    #[strategy(0..50u8)]
+    mut half: u8,
+) -> u8 {
+    half * 2
+}
+
+#[proptest]
+fn with_strategy_fn(#[strategy(small_even())] value: u8) {
+    assert_eq!(value % 2, 0);
+}
+
+fn main() {
+    mut_with_strategy();
+    ref_with_filter();
+    attributed_arguments();
+    attributed_with_strategy();
+    with_strategy_fn();
+}
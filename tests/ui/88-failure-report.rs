@@ -0,0 +1,58 @@
+use proptest::prelude::*;
+use proptest_attr::proptest;
+use std::panic::{catch_unwind, UnwindSafe};
+
+#[proptest]
+fn random_seed(value: u8) {
+    assert!(value < 200);
+}
+
+#[proptest(seed = "xs 000102030405060708090a0b0c0d0e0f")]
+fn fixed_seed(value: u8) {
+    assert!(value < 200);
+}
+
+#[proptest(parallel, threads = 2)]
+fn parallel_seed(value: u8) {
+    assert!(value < 200);
+}
+
+#[proptest(rng = "PassThrough", rng_bytes = [255; 64])]
+fn pass_through(value: u8) {
+    assert!(value < 200);
+}
+
+fn panic_message(test: impl FnOnce() + UnwindSafe) -> String {
+    let payload = catch_unwind(test).unwrap_err();
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(_) => panic!("the panic message is not a string"),
+    }
+}
+
+fn main() {
+    let message = panic_message(random_seed);
+    assert!(
+        message.contains("minimal failing input: 200"),
+        "{}",
+        message
+    );
+    assert!(message.contains("cases executed: "), "{}", message);
+    assert!(message.contains("add `seed = \""), "{}", message);
+    assert!(message.contains("`random_seed`"), "{}", message);
+
+    let message = panic_message(fixed_seed);
+    assert!(
+        message.contains("seed: xs 000102030405060708090a0b0c0d0e0f"),
+        "{}",
+        message
+    );
+
+    let message = panic_message(parallel_seed);
+    assert!(message.contains("cases executed: "), "{}", message);
+    assert!(message.contains("add `seed = \""), "{}", message);
+
+    let message = panic_message(pass_through);
+    assert!(message.contains("cases executed: "), "{}", message);
+    assert!(!message.contains("seed"), "{}", message);
+}
@@ -0,0 +1,42 @@
+use proptest::prelude::*;
+use proptest_attr::proptest;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+static BUILT: AtomicU32 = AtomicU32::new(0);
+
+fn expensive() -> impl Strategy<Value = String> {
+    BUILT.fetch_add(1, Ordering::SeqCst);
+    "[a-z]{1,8}"
+}
+
+#[proptest(cache_strategy, cases = 10)]
+fn cached(#[strategy(expensive())] name: String) {
+    assert!(!name.is_empty());
+}
+
+#[proptest(cache_strategy = true, strategy = (0..10u8, ("[0-9]+")), cases = 10)]
+fn cached_tuple(value: u8, digits: String) {
+    assert!(value < 10);
+    assert!(digits.chars().all(|c| c.is_ascii_digit()));
+}
+
+#[proptest(cache_strategy, parallel, threads = 2, cases = 10)]
+fn cached_parallel(#[filter(*value > 0)] value: u8) {
+    assert!(value > 0);
+}
+
+#[proptest(cache_strategy = false, cases = 10)]
+fn not_cached(#[strategy(expensive())] name: String) {
+    assert!(!name.is_empty());
+}
+
+fn main() {
+    cached();
+    cached();
+    assert_eq!(BUILT.swap(0, Ordering::SeqCst), 1);
+    not_cached();
+    not_cached();
+    assert_eq!(BUILT.load(Ordering::SeqCst), 2);
+    cached_tuple();
+    cached_parallel();
+}
@@ -0,0 +1,71 @@
+use proptest::prelude::*;
+use proptest_attr::proptest;
+use std::sync::Mutex;
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+
+static SPANS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+/// Records the fields of the created spans.
+struct Recorder;
+
+struct Fields(String);
+
+impl tracing::field::Visit for Fields {
+    fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug) {
+        self.0.push_str(&format!("{}={:?} ", field.name(), value));
+    }
+}
+
+impl Subscriber for Recorder {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, span: &Attributes<'_>) -> Id {
+        let mut fields = Fields(format!("{}: ", span.metadata().name()));
+        span.record(&mut fields);
+        let mut spans = SPANS.lock().unwrap();
+        spans.push(fields.0);
+        Id::from_u64(spans.len() as u64)
+    }
+
+    fn record(&self, _: &Id, _: &Record<'_>) {}
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, _: &Event<'_>) {}
+
+    fn enter(&self, _: &Id) {}
+
+    fn exit(&self, _: &Id) {}
+}
+
+#[proptest(trace, cases = 3, strategy = 5..6u8)]
+fn single(value: u8) {
+    tracing::info!(value, "checking");
+    assert_eq!(value, 5);
+}
+
+#[proptest(trace = true, cases = 2, strategy = (Just(1u8), Just("a")))]
+fn multiple(a: u8, b: &'static str) -> Result<(), TestCaseError> {
+    prop_assert_eq!((a, b), (1, "a"));
+    Ok(())
+}
+
+#[proptest(trace, cases = 1, expect_panic)]
+fn panicking(_value: u8) {
+    panic!("expected");
+}
+
+fn main() {
+    tracing::subscriber::set_global_default(Recorder).unwrap();
+    single();
+    multiple();
+    panicking();
+    let spans = SPANS.lock().unwrap();
+    assert_eq!(spans.len(), 6);
+    assert_eq!(spans[0], "proptest_case: test=\"single\" input=5 ");
+    assert_eq!(spans[3], "proptest_case: test=\"multiple\" input=(1, \"a\") ");
+    assert!(spans[5].starts_with("proptest_case: test=\"panicking\" input="));
+}
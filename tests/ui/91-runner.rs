@@ -0,0 +1,43 @@
+use proptest::prelude::*;
+use proptest::test_runner::{Config, TestRunner};
+use proptest_attr::proptest;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+static CASES: AtomicU32 = AtomicU32::new(0);
+
+fn my_runner_factory() -> TestRunner {
+    TestRunner::new(Config {
+        cases: 7,
+        failure_persistence: None,
+        ..Config::default()
+    })
+}
+
+#[proptest(runner = "my_runner_factory()")]
+fn from_factory(value: u8) {
+    CASES.fetch_add(1, Ordering::Relaxed);
+    let _ = value;
+}
+
+#[proptest(runner = TestRunner::deterministic(), strategy = 0..10u8)]
+fn deterministic(value: u8) -> Result<(), TestCaseError> {
+    prop_assert!(value < 10);
+    Ok(())
+}
+
+#[proptest(runner = "my_runner_factory()")]
+fn failing(value: u8) {
+    // Fails for almost any value, since the factory only runs a few cases
+    assert!(value < 8);
+}
+
+fn main() {
+    from_factory();
+    assert_eq!(CASES.load(Ordering::Relaxed), 7);
+    deterministic();
+
+    let payload = std::panic::catch_unwind(failing).unwrap_err();
+    let message = payload.downcast::<String>().unwrap();
+    assert!(message.contains("cases executed: "), "{}", message);
+    assert!(!message.contains("seed"), "{}", message);
+}
@@ -0,0 +1,36 @@
+use proptest_attr::proptest;
+
+#[proptest(runner = "proptest::test_runner::TestRunner::default()", cases = 10)]
+fn with_cases(value: u8) {
+    let _ = value;
+}
+
+#[proptest(
+    runner = "proptest::test_runner::TestRunner::default()",
+    seed = "xs 000102030405060708090a0b0c0d0e0f"
+)]
+fn with_seed(value: u8) {
+    let _ = value;
+}
+
+#[proptest(runner = "proptest::test_runner::TestRunner::default()", loom)]
+fn with_loom(value: u8) {
+    let _ = value;
+}
+
+#[proptest(runner = "proptest::test_runner::TestRunner::default()", dedup)]
+fn with_dedup(value: u8) {
+    let _ = value;
+}
+
+#[proptest(runner = "proptest::test_runner::TestRunner::default()", exhaustive_small)]
+fn with_exhaustive_small(value: u8) {
+    let _ = value;
+}
+
+#[proptest(runner = "proptest::test_runner::Config::default()")]
+fn not_a_runner(value: u8) {
+    let _ = value;
+}
+
+fn main() {}
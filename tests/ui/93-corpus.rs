@@ -0,0 +1,42 @@
+use proptest::prelude::*;
+use proptest_attr::proptest;
+use std::sync::Mutex;
+
+static SEEN: Mutex<Vec<(u8, u8)>> = Mutex::new(Vec::new());
+
+#[proptest(corpus = "corpus/93-corpus", cases = 0)]
+fn corpus_only(a: u8, b: u8) {
+    SEEN.lock().unwrap().push((a, b));
+}
+
+#[proptest(corpus = "corpus/93-missing", cases = 5, strategy = 0..10u32)]
+fn missing_corpus(value: u32) -> Result<(), TestCaseError> {
+    prop_assert!(value < 10);
+    Ok(())
+}
+
+#[proptest(corpus = "corpus/93-corpus", cases = 0, strategy = 0..10u32)]
+fn failing(value: u32) {
+    assert!(value > 100);
+}
+
+fn main() {
+    let dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("corpus/93-corpus");
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join("a"), b"\x01\x02\x03\x04").unwrap();
+    std::fs::write(dir.join("b"), b"").unwrap();
+
+    corpus_only();
+    let seen = SEEN.lock().unwrap().clone();
+    assert_eq!(seen.len(), 2);
+    // The cases are deterministic
+    corpus_only();
+    assert_eq!(SEEN.lock().unwrap()[2..], seen[..]);
+
+    missing_corpus();
+
+    let payload = std::panic::catch_unwind(failing).unwrap_err();
+    let message = payload.downcast::<String>().unwrap();
+    assert!(message.contains("corpus file: "), "{}", message);
+    assert!(message.contains("93-corpus"), "{}", message);
+}
@@ -0,0 +1,18 @@
+use proptest_attr::proptest;
+
+#[proptest(corpus = corpus_dir)]
+fn not_a_string(value: u8) {
+    let _ = value;
+}
+
+#[proptest(corpus = "corpus", parallel)]
+fn with_parallel(value: u8) {
+    let _ = value;
+}
+
+#[proptest(corpus = "corpus", engine = "bolero")]
+fn with_bolero(value: u8) {
+    let _ = value;
+}
+
+fn main() {}
@@ -0,0 +1,43 @@
+#![no_std]
+
+// Only the test harness uses `std`, the expanded code must not refer to it
+extern crate std as host_std;
+
+use proptest::prelude::*;
+use proptest::test_runner::TestCaseError;
+use proptest_attr::proptest;
+
+#[proptest(no_std)]
+fn commutative(a: u8, b: u8) {
+    assert_eq!(u16::from(a) + u16::from(b), u16::from(b) + u16::from(a));
+}
+
+#[proptest(no_std = true, cases = 10, strategy = 0..10u32)]
+fn with_result(value: u32) -> Result<(), TestCaseError> {
+    if value >= 10 {
+        return Err(TestCaseError::fail("out of range"));
+    }
+    Ok(())
+}
+
+#[proptest(no_std, seed = "xs 000102030405060708090a0b0c0d0e0f")]
+fn failing(value: u8) -> Result<(), TestCaseError> {
+    if value >= 200 {
+        return Err(TestCaseError::fail("too big"));
+    }
+    Ok(())
+}
+
+fn main() {
+    commutative();
+    with_result();
+
+    let payload = host_std::panic::catch_unwind(failing).unwrap_err();
+    let message = payload.downcast::<host_std::string::String>().unwrap();
+    assert!(message.contains("minimal failing input: 200"), "{}", message);
+    assert!(
+        message.contains("seed: xs 000102030405060708090a0b0c0d0e0f"),
+        "{}",
+        message
+    );
+}
@@ -0,0 +1,23 @@
+use proptest_attr::proptest;
+
+#[proptest(no_std, fork)]
+fn with_fork(value: u8) {
+    let _ = value;
+}
+
+#[proptest(no_std, timeout = 100)]
+fn with_timeout(value: u8) {
+    let _ = value;
+}
+
+#[proptest(no_std, persistence = "off")]
+fn with_persistence(value: u8) {
+    let _ = value;
+}
+
+#[proptest(no_std, log_inputs)]
+fn with_log_inputs(value: u8) {
+    let _ = value;
+}
+
+fn main() {}
@@ -0,0 +1,47 @@
+use proptest::prelude::*;
+use proptest_attr::proptest;
+use std::sync::Mutex;
+
+static SEEN: Mutex<Vec<u32>> = Mutex::new(Vec::new());
+
+#[proptest(exhaustive_small, small_values = 5, cases = 0, strategy = 0..1000u32)]
+fn smallest_first(value: u32) {
+    SEEN.lock().unwrap().push(value);
+}
+
+#[proptest(exhaustive_small = true, cases = 0)]
+fn zero(value: u32) {
+    assert!(value != 0);
+}
+
+#[proptest(exhaustive_small, cases = 0, strategy = prop::collection::vec(any::<u8>(), 0..10))]
+fn empty(values: Vec<u8>) -> Result<(), TestCaseError> {
+    prop_assert!(!values.is_empty());
+    Ok(())
+}
+
+#[proptest(exhaustive_small, cases = 10, strategy = (0..10u8, "[a-z]{0,3}"))]
+fn with_random_cases(a: u8, b: String) {
+    assert!(a < 10 && b.len() <= 3);
+}
+
+fn panic_message(test: fn()) -> String {
+    let payload = std::panic::catch_unwind(test).unwrap_err();
+    *payload.downcast::<String>().unwrap()
+}
+
+fn main() {
+    smallest_first();
+    let seen = SEEN.lock().unwrap().clone();
+    assert_eq!(seen.len(), 5, "{:?}", seen);
+    assert_eq!(seen[0], 0);
+    smallest_first();
+    assert_eq!(SEEN.lock().unwrap()[5..], seen[..]);
+
+    let message = panic_message(zero);
+    assert!(message.contains("minimal failing input: 0"), "{}", message);
+    let message = panic_message(empty);
+    assert!(message.contains("minimal failing input: []"), "{}", message);
+
+    with_random_cases();
+}
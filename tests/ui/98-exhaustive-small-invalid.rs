@@ -0,0 +1,13 @@
+use proptest_attr::proptest;
+
+#[proptest(small_values = 10)]
+fn without_exhaustive_small(value: u8) {
+    let _ = value;
+}
+
+#[proptest(exhaustive_small, parallel)]
+fn with_parallel(value: u8) {
+    let _ = value;
+}
+
+fn main() {}
@@ -0,0 +1,31 @@
+use proptest::prelude::*;
+use proptest_attr::proptest;
+
+fn encode<const N: usize>(values: [u8; N]) -> Vec<u8> {
+    values.to_vec()
+}
+
+#[proptest(consts = [1, 8, 64], cases = 10)]
+fn roundtrip<const N: usize>(values: [u8; N]) {
+    assert_eq!(encode(values).len(), N);
+}
+
+#[proptest(consts = "[2, 4]")]
+fn with_strategy<const N: usize>(
+    #[strategy(prop::array::uniform(0..10u8))] values: [u8; N],
+) -> Result<(), TestCaseError> {
+    prop_assert!(values.iter().all(|value| *value < 10));
+    prop_assert_eq!(values.len(), N);
+    Ok(())
+}
+
+fn main() {
+    roundtrip::n_1();
+    roundtrip::n_8();
+    roundtrip::n_64();
+    with_strategy::n_2();
+    with_strategy::n_4();
+
+    // The generic function stays callable
+    roundtrip([1, 2, 3]);
+}